@@ -0,0 +1,95 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use prima::{
+    batch::{AabrSoA, BatchQuery, CircleSoA},
+    prelude::*,
+};
+
+const SIZES: [usize; 3] = [64, 1024, 16384];
+
+/// A deterministic scatter of shapes across a 1000 by 1000 area.
+fn scatter(count: usize) -> Vec<(Point<f32>, f32)> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 40) as f32 / (1u64 << 24) as f32
+    };
+    (0..count)
+        .map(|_| {
+            (
+                Point::new(next() * 1000.0, next() * 1000.0),
+                1.0 + next() * 20.0,
+            )
+        })
+        .collect()
+}
+
+fn aabrs(c: &mut Criterion) {
+    let query = Circle::new(Point::new(500.0, 500.0), 150.0);
+    let mut group = c.benchmark_group("circle_vs_aabrs");
+    for size in SIZES {
+        let shapes: Vec<Aabr<f32>> = scatter(size)
+            .into_iter()
+            .map(|(p, r)| Aabr::new(p, p + Vector::new(r, r)))
+            .collect();
+        let soa = AabrSoA::from(shapes.as_slice());
+        group.bench_with_input(BenchmarkId::new("scalar", size), &shapes, |b, shapes| {
+            b.iter(|| {
+                shapes
+                    .iter()
+                    .filter(|s| black_box(&query).intersecting(*s))
+                    .count()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batch", size), &soa, |b, soa| {
+            b.iter(|| black_box(&query).intersecting_many(soa).count_ones())
+        });
+    }
+    group.finish();
+}
+
+fn circles(c: &mut Criterion) {
+    let query = Circle::new(Point::new(500.0, 500.0), 150.0);
+    let mut group = c.benchmark_group("circle_vs_circles");
+    for size in SIZES {
+        let shapes: Vec<Circle<f32>> = scatter(size)
+            .into_iter()
+            .map(|(p, r)| Circle::new(p, r))
+            .collect();
+        let soa = CircleSoA::from(shapes.as_slice());
+        group.bench_with_input(BenchmarkId::new("scalar", size), &shapes, |b, shapes| {
+            b.iter(|| {
+                shapes
+                    .iter()
+                    .filter(|s| black_box(&query).intersecting(*s))
+                    .count()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batch", size), &soa, |b, soa| {
+            b.iter(|| black_box(&query).intersecting_many(soa).count_ones())
+        });
+        group.bench_with_input(
+            BenchmarkId::new("nearest_scalar", size),
+            &shapes,
+            |b, shapes| {
+                b.iter(|| {
+                    shapes
+                        .iter()
+                        .map(|s| {
+                            (black_box(&query).center.distance(&s.center) - s.radius - query.radius)
+                                .max(0.0)
+                        })
+                        .fold(f32::INFINITY, f32::min)
+                })
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("nearest_batch", size), &soa, |b, soa| {
+            b.iter(|| black_box(&query).nearest_of(soa))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, aabrs, circles);
+criterion_main!(benches);
@@ -0,0 +1,249 @@
+//! Benchmarks for the pairwise shape queries and the constructors and transforms they lean on.
+//!
+//! Every group runs for both `f32` and `f64`. Obr pairs whose implementations are still marked
+//! `todo!()` are left out until they land: Obr against Line, the Obr collision and enveloping
+//! tests against Circle and Obr, the Obr nearest point to an Aabr or Obr, the distance between
+//! two Obrs, and the collision from an Aabr to an Obr.
+
+use std::any::type_name;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use prima::prelude::*;
+
+/// A set of shapes that all overlap one another, so that every query runs its full path.
+struct Fixture<N> {
+    point: Point<N>,
+    line: Line<N>,
+    circle: Circle<N>,
+    aabr: Aabr<N>,
+    other_aabr: Aabr<N>,
+    obr: Obr<N>,
+    other_obr: Obr<N>,
+    other_circle: Circle<N>,
+    other_line: Line<N>,
+}
+
+impl<N: PrimaFloat> Fixture<N> {
+    fn new() -> Self {
+        let n = |v: f64| N::from_f64(v).unwrap();
+        let p = |x: f64, y: f64| Point::new(n(x), n(y));
+        Self {
+            point: p(0.5, 3.0),
+            line: Line::new(p(-1.0, 0.5), p(3.0, 2.0)),
+            circle: Circle::new(p(1.5, 1.0), n(1.0)),
+            aabr: Aabr::new(p(0.0, 0.0), p(2.0, 1.0)),
+            other_aabr: Aabr::new(p(1.0, 0.5), p(3.0, 2.5)),
+            obr: Obr::new(
+                p(1.0, 1.0),
+                Extent::new(n(2.0), n(1.0)),
+                Angle::from_degrees(n(30.0)),
+            ),
+            other_obr: Obr::new(
+                p(2.0, 1.5),
+                Extent::new(n(1.5), n(1.0)),
+                Angle::from_degrees(n(75.0)),
+            ),
+            other_circle: Circle::new(p(2.5, 1.5), n(0.75)),
+            other_line: Line::new(p(0.0, 3.0), p(2.0, -1.0)),
+        }
+    }
+}
+
+/// Benchmarks every method of a [Collide] pair.
+macro_rules! collide {
+    ($group:ident, $name:literal, $a:expr, $b:expr) => {
+        $group.bench_function(concat!($name, "/collision"), |b| {
+            b.iter(|| black_box(&$a).collision(black_box(&$b)))
+        });
+        $group.bench_function(concat!($name, "/intersecting"), |b| {
+            b.iter(|| black_box(&$a).intersecting(black_box(&$b)))
+        });
+        $group.bench_function(concat!($name, "/enveloping"), |b| {
+            b.iter(|| black_box(&$a).enveloping(black_box(&$b)))
+        });
+        $group.bench_function(concat!($name, "/enveloped_by"), |b| {
+            b.iter(|| black_box(&$a).enveloped_by(black_box(&$b)))
+        });
+    };
+}
+
+/// Benchmarks a single query method.
+macro_rules! query {
+    ($group:ident, $name:literal, $method:ident, $a:expr, $b:expr) => {
+        $group.bench_function($name, |b| b.iter(|| black_box(&$a).$method(black_box(&$b))));
+    };
+}
+
+fn collide<N: PrimaFloat>(c: &mut Criterion) {
+    let f = Fixture::<N>::new();
+    let mut group = c.benchmark_group(format!("collide/{}", type_name::<N>()));
+    collide!(group, "aabr-line", f.aabr, f.line);
+    collide!(group, "aabr-circle", f.aabr, f.circle);
+    collide!(group, "aabr-aabr", f.aabr, f.other_aabr);
+    group.bench_function("aabr-obr/intersecting", |b| {
+        b.iter(|| black_box(&f.aabr).intersecting(black_box(&f.obr)))
+    });
+    group.bench_function("aabr-obr/enveloping", |b| {
+        b.iter(|| black_box(&f.aabr).enveloping(black_box(&f.obr)))
+    });
+    group.bench_function("aabr-obr/enveloped_by", |b| {
+        b.iter(|| black_box(&f.aabr).enveloped_by(black_box(&f.obr)))
+    });
+    collide!(group, "circle-line", f.circle, f.line);
+    collide!(group, "circle-circle", f.circle, f.other_circle);
+    collide!(group, "circle-aabr", f.circle, f.aabr);
+    collide!(group, "circle-obr", f.circle, f.obr);
+    group.bench_function("obr-aabr/intersecting", |b| {
+        b.iter(|| black_box(&f.obr).intersecting(black_box(&f.aabr)))
+    });
+    group.bench_function("obr-obr/intersecting", |b| {
+        b.iter(|| black_box(&f.obr).intersecting(black_box(&f.other_obr)))
+    });
+    group.finish();
+}
+
+fn distance<N: PrimaFloat>(c: &mut Criterion) {
+    let f = Fixture::<N>::new();
+    let mut group = c.benchmark_group(format!("distance/{}", type_name::<N>()));
+    query!(group, "aabr-point", distance, f.aabr, f.point);
+    query!(group, "aabr-line", distance, f.aabr, f.line);
+    query!(group, "aabr-circle", distance, f.aabr, f.circle);
+    query!(group, "aabr-aabr", distance, f.aabr, f.other_aabr);
+    query!(group, "circle-point", distance, f.circle, f.point);
+    query!(group, "circle-line", distance, f.circle, f.line);
+    query!(group, "circle-circle", distance, f.circle, f.other_circle);
+    query!(group, "aabr-obr", distance, f.aabr, f.obr);
+    query!(group, "circle-aabr", distance, f.circle, f.aabr);
+    query!(group, "circle-obr", distance, f.circle, f.obr);
+    query!(group, "obr-point", distance, f.obr, f.point);
+    query!(group, "obr-circle", distance, f.obr, f.circle);
+    query!(group, "obr-aabr", distance, f.obr, f.aabr);
+    query!(group, "line-point", distance, f.line, f.point);
+    query!(group, "line-line", distance, f.line, f.other_line);
+    group.finish();
+}
+
+fn nearest<N: PrimaFloat>(c: &mut Criterion) {
+    let f = Fixture::<N>::new();
+    let mut group = c.benchmark_group(format!("nearest/{}", type_name::<N>()));
+    query!(group, "aabr-point", nearest_point, f.aabr, f.point);
+    query!(group, "aabr-line", nearest_point, f.aabr, f.line);
+    query!(group, "aabr-circle", nearest_point, f.aabr, f.circle);
+    query!(group, "aabr-aabr", nearest_point, f.aabr, f.other_aabr);
+    query!(group, "aabr-obr", nearest_point, f.aabr, f.obr);
+    query!(group, "circle-point", nearest_point, f.circle, f.point);
+    query!(group, "circle-line", nearest_point, f.circle, f.line);
+    query!(
+        group,
+        "circle-circle",
+        nearest_point,
+        f.circle,
+        f.other_circle
+    );
+    query!(group, "circle-aabr", nearest_point, f.circle, f.aabr);
+    query!(group, "circle-obr", nearest_point, f.circle, f.obr);
+    query!(group, "obr-point", nearest_point, f.obr, f.point);
+    query!(group, "obr-circle", nearest_point, f.obr, f.circle);
+    query!(group, "line-point", nearest_point, f.line, f.point);
+    query!(group, "line-line", nearest_point, f.line, f.other_line);
+    group.finish();
+}
+
+fn construct<N: PrimaFloat>(c: &mut Criterion) {
+    let f = Fixture::<N>::new();
+    let n = |v: f64| N::from_f64(v).unwrap();
+    let mut group = c.benchmark_group(format!("construct/{}", type_name::<N>()));
+    group.bench_function("aabr/new", |b| {
+        b.iter(|| Aabr::new(black_box(f.point), black_box(f.circle.center)))
+    });
+    group.bench_function("aabr/from_point", |b| {
+        b.iter(|| Aabr::from_point(black_box(f.point), black_box(n(2.0)), black_box(n(1.0))))
+    });
+    group.bench_function("circle/new", |b| {
+        b.iter(|| Circle::new(black_box(f.point), black_box(n(1.0))))
+    });
+    group.bench_function("obr/new", |b| {
+        b.iter(|| {
+            Obr::new(
+                black_box(f.point),
+                black_box(f.obr.extent),
+                black_box(f.obr.rotation),
+            )
+        })
+    });
+    group.bench_function("obr/vertices", |b| b.iter(|| black_box(&f.obr).vertices()));
+    group.bench_function("obr/bounding_rect", |b| {
+        b.iter(|| black_box(&f.obr).bounding_rect())
+    });
+    group.bench_function("circle/to_polygon", |b| {
+        b.iter(|| black_box(&f.circle).to_polygon(black_box(32)))
+    });
+    group.finish();
+}
+
+fn transform<N: PrimaFloat>(c: &mut Criterion) {
+    let f = Fixture::<N>::new();
+    let rotation = Rotation::from_degrees(N::from_f64(30.0).unwrap());
+    let offset = f.point - Point::zero();
+    let polygon = Polygon::new(f.obr.vertices());
+    let matrix =
+        Mat3::from_scale_rotation_translation(Vector::new(N::one(), N::one()), rotation, offset);
+    let mut group = c.benchmark_group(format!("transform/{}", type_name::<N>()));
+    group.bench_function("vector/rotate", |b| {
+        b.iter(|| black_box(offset) * black_box(rotation))
+    });
+    group.bench_function("mat3/transform_point", |b| {
+        b.iter(|| black_box(&matrix).transform_point(black_box(f.point)))
+    });
+    group.bench_function("mat3/inverse", |b| b.iter(|| black_box(&matrix).inverse()));
+    group.bench_function("circle/translate", |b| {
+        b.iter(|| {
+            let mut circle = black_box(f.circle);
+            circle.translate(black_box(&offset));
+            circle
+        })
+    });
+    group.bench_function("circle/rotate_around", |b| {
+        b.iter(|| {
+            let mut circle = black_box(f.circle);
+            circle.rotate_around(black_box(f.point), black_box(rotation));
+            circle
+        })
+    });
+    group.bench_function("polygon/rotate_around", |b| {
+        b.iter(|| {
+            let mut polygon = black_box(&polygon).clone();
+            polygon.rotate_around(black_box(f.point), black_box(rotation));
+            polygon
+        })
+    });
+    group.finish();
+}
+
+fn f32_suite(c: &mut Criterion) {
+    collide::<f32>(c);
+    distance::<f32>(c);
+    nearest::<f32>(c);
+    construct::<f32>(c);
+    transform::<f32>(c);
+}
+
+fn f64_suite(c: &mut Criterion) {
+    collide::<f64>(c);
+    distance::<f64>(c);
+    nearest::<f64>(c);
+    construct::<f64>(c);
+    transform::<f64>(c);
+}
+
+/// Differences under two percent are treated as noise when comparing against a baseline.
+fn config() -> Criterion {
+    Criterion::default().noise_threshold(0.02)
+}
+
+criterion_group! {
+    name = benches;
+    config = config();
+    targets = f32_suite, f64_suite
+}
+criterion_main!(benches);
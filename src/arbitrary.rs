@@ -0,0 +1,373 @@
+//! [Arbitrary] implementations for prima's types, so that `any::<Circle<f32>>()` and friends can
+//! be used directly in property tests.
+//!
+//! Generated geometry is kept well behaved: coordinates stay within [COORDINATE_RANGE] of the
+//! origin, sizes and radii are positive and at most [MAX_SIZE], and polygons never cross
+//! themselves. Floats are always finite.
+
+use std::{f64::consts::TAU, fmt::Debug};
+
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::vec,
+    prop_oneof,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use crate::{
+    core::{
+        Angle, AxisLine, Collision, Extent, Line, Mat2, Mat3, Point, Polyline, Ray, Rotation,
+        Vector,
+    },
+    curves::{Bezier, CatmullRom},
+    grid::Grid,
+    nums::{PrimaFloat, PrimaNum},
+    shapes::{Aabr, AnyShape, Capsule, Circle, Obr, Polygon, RoundedPolygon},
+};
+
+/// How far from the origin generated coordinates may lie, on each axis.
+pub const COORDINATE_RANGE: i32 = 1000;
+
+/// The largest generated size, such as a width, height or radius.
+pub const MAX_SIZE: i32 = 100;
+
+/// A number type prima can generate arbitrary geometry over.
+pub trait ArbitraryNum: PrimaNum + Debug + 'static {
+    /// A coordinate within [COORDINATE_RANGE] of the origin.
+    fn coordinate() -> BoxedStrategy<Self>;
+
+    /// A positive size no larger than [MAX_SIZE].
+    fn size() -> BoxedStrategy<Self>;
+}
+
+macro_rules! arbitrary_float {
+    ($($t:ty),*) => {$(
+        impl ArbitraryNum for $t {
+            fn coordinate() -> BoxedStrategy<Self> {
+                let range = COORDINATE_RANGE as $t;
+                (-range..=range).boxed()
+            }
+
+            fn size() -> BoxedStrategy<Self> {
+                // Tiny sizes make for degenerate shapes, so the smallest is a hundredth.
+                (0.01..=MAX_SIZE as $t).boxed()
+            }
+        }
+    )*};
+}
+
+macro_rules! arbitrary_int {
+    ($($t:ty),*) => {$(
+        impl ArbitraryNum for $t {
+            fn coordinate() -> BoxedStrategy<Self> {
+                let range = COORDINATE_RANGE as $t;
+                (-range..=range).boxed()
+            }
+
+            fn size() -> BoxedStrategy<Self> {
+                (1..=MAX_SIZE as $t).boxed()
+            }
+        }
+    )*};
+}
+
+arbitrary_float!(f32, f64);
+arbitrary_int!(i32, i64);
+
+/// Converts a generated `f64` into the target float type.
+fn float<N: PrimaFloat>(value: f64) -> N {
+    N::from_f64(value).unwrap()
+}
+
+/// A radian value in `[0, 2π)`.
+fn radians<N: PrimaFloat>() -> impl Strategy<Value = N> {
+    (0.0..TAU).prop_map(float)
+}
+
+/// Points around a center at increasing angles, giving a simple polygon wound counter-clockwise.
+/// With `uniform` set every point sits at the same distance, making the polygon convex.
+fn star<N>(uniform: bool) -> impl Strategy<Value = Vec<Point<N>>>
+where
+    N: PrimaFloat + ArbitraryNum,
+{
+    (
+        any::<Point<N>>(),
+        N::size(),
+        vec((0.0..0.9_f64, N::size()), 3..12),
+    )
+        .prop_map(move |(center, radius, spokes)| {
+            let step = TAU / spokes.len() as f64;
+            spokes
+                .iter()
+                .enumerate()
+                .map(|(i, (jitter, r))| {
+                    let (s, c) = ((i as f64 + jitter) * step).sin_cos();
+                    let r = if uniform { radius } else { *r };
+                    center + Vector::new(float::<N>(c), float(s)) * r
+                })
+                .collect()
+        })
+}
+
+//=================================================================//
+//============================= CORE ==============================//
+//=================================================================//
+
+impl<N: ArbitraryNum> Arbitrary for Point<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (N::coordinate(), N::coordinate())
+            .prop_map(|(x, y)| Point::new(x, y))
+            .boxed()
+    }
+}
+
+impl<N: ArbitraryNum> Arbitrary for Vector<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (N::coordinate(), N::coordinate())
+            .prop_map(|(x, y)| Vector::new(x, y))
+            .boxed()
+    }
+}
+
+impl<N: ArbitraryNum> Arbitrary for Extent<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (N::size(), N::size())
+            .prop_map(|(w, h)| Extent::new(w, h))
+            .boxed()
+    }
+}
+
+impl<N: ArbitraryNum> Arbitrary for AxisLine<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (N::coordinate(), N::size())
+            .prop_map(|(start, size)| AxisLine::new(start, start + size))
+            .boxed()
+    }
+}
+
+impl<N: ArbitraryNum> Arbitrary for Line<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<Point<N>>(), any::<Point<N>>())
+            .prop_map(|(a, b)| Line::new(a, b))
+            .boxed()
+    }
+}
+
+impl<N: ArbitraryNum> Arbitrary for Polyline<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        vec(any::<Point<N>>(), 2..8).prop_map(Polyline::new).boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Ray<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<Point<N>>(), any::<Angle<N>>())
+            .prop_map(|(origin, angle)| Ray::new(origin, Vector::up() * Rotation::from(angle)))
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Angle<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        radians().prop_map(Angle::from_radians).boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Rotation<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Rotations may turn up to twice in either direction.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (-2.0 * TAU..2.0 * TAU)
+            .prop_map(|r| Rotation::from_radians(float(r)))
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Mat2<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        vec(-10.0..10.0, 4)
+            .prop_map(|m| Mat2::new(float(m[0]), float(m[1]), float(m[2]), float(m[3])))
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Mat3<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates affine transforms, which keep the bottom row as `0, 0, 1`.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<Mat2<N>>(), any::<Vector<N>>())
+            .prop_map(|(m, t)| Mat3::from_translation(t) * Mat3::from_mat2(m))
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Collision<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates contacts with a unit normal.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<Point<N>>(), any::<Angle<N>>(), N::size())
+            .prop_map(|(point, angle, depth)| {
+                Collision::new(point, Vector::up() * Rotation::from(angle), depth)
+            })
+            .boxed()
+    }
+}
+
+//=================================================================//
+//============================ SHAPES =============================//
+//=================================================================//
+
+impl<N: ArbitraryNum> Arbitrary for Aabr<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<Point<N>>(), N::size(), N::size())
+            .prop_map(|(min, w, h)| Aabr::new(min, Point::new(min.x + w, min.y + h)))
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Circle<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<Point<N>>(), N::size())
+            .prop_map(|(c, r)| Circle::new(c, r))
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Obr<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<Point<N>>(), any::<Extent<N>>(), any::<Angle<N>>())
+            .prop_map(|(c, e, a)| Obr::new(c, e, a))
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Capsule<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<Line<N>>(), N::size())
+            .prop_map(|(l, r)| Capsule::new(l, r))
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Polygon<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates simple star-shaped polygons, wound counter-clockwise.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        star(false).prop_map(Polygon::new).boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for RoundedPolygon<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates convex cores wound counter-clockwise, as rounded polygons require.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (star(true), N::size())
+            .prop_map(|(points, r)| RoundedPolygon::new(Polygon::new(points), r))
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for AnyShape<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            any::<Circle<N>>().prop_map(AnyShape::from),
+            any::<Aabr<N>>().prop_map(AnyShape::from),
+            any::<Obr<N>>().prop_map(AnyShape::from),
+            any::<Capsule<N>>().prop_map(AnyShape::from),
+            any::<Polygon<N>>().prop_map(AnyShape::from),
+            any::<RoundedPolygon<N>>().prop_map(AnyShape::from),
+        ]
+        .boxed()
+    }
+}
+
+//=================================================================//
+//============================= OTHER =============================//
+//=================================================================//
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Bezier<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates curves from linear up to quartic.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        vec(any::<Point<N>>(), 2..=5).prop_map(Bezier::new).boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for CatmullRom<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        vec(any::<Point<N>>(), 2..8)
+            .prop_map(CatmullRom::new)
+            .boxed()
+    }
+}
+
+impl<N: PrimaFloat + ArbitraryNum> Arbitrary for Grid<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates the unit grid about half of the time, and an arbitrary origin and cell size otherwise.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            Just(Grid::unit()),
+            (any::<Point<N>>(), any::<Extent<N>>()).prop_map(|(o, e)| Grid::new(o, e)),
+        ]
+        .boxed()
+    }
+}
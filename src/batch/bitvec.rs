@@ -0,0 +1,172 @@
+use std::fmt::Debug;
+
+/// A compact vector of bits, used to return the results of batch queries.
+///
+/// Bits are packed 64 to a word, with bit `i` stored in word `i / 64` at position `i % 64`.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    /// Creates an empty bit vector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a bit vector of the given length with every bit unset.
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Creates a bit vector of the given length, setting each bit from a function of its index.
+    pub fn from_fn<F>(len: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize) -> bool,
+    {
+        let mut words = vec![0; len.div_ceil(64)];
+        for (w, word) in words.iter_mut().enumerate() {
+            let base = w * 64;
+            let lanes = (len - base).min(64);
+            let mut bits = 0;
+            for lane in 0..lanes {
+                bits |= (f(base + lane) as u64) << lane;
+            }
+            *word = bits;
+        }
+        Self { words, len }
+    }
+
+    /// Creates a bit vector from packed words. Bits past `len` are cleared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are too few words to hold `len` bits.
+    pub fn from_words(mut words: Vec<u64>, len: usize) -> Self {
+        assert!(
+            words.len() * 64 >= len,
+            "{} words cannot hold {} bits",
+            words.len(),
+            len
+        );
+        words.truncate(len.div_ceil(64));
+        if !len.is_multiple_of(64) {
+            if let Some(last) = words.last_mut() {
+                *last &= (1 << (len % 64)) - 1;
+            }
+        }
+        Self { words, len }
+    }
+
+    /// The number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at the given index, or None if it is out of range.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.words[index / 64] >> (index % 64) & 1 == 1)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the bit at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range.
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(
+            index < self.len,
+            "index {} is out of range for {} bits",
+            index,
+            self.len
+        );
+        let mask = 1 << (index % 64);
+        if value {
+            self.words[index / 64] |= mask;
+        } else {
+            self.words[index / 64] &= !mask;
+        }
+    }
+
+    /// Adds a bit to the end.
+    pub fn push(&mut self, value: bool) {
+        if self.len.is_multiple_of(64) {
+            self.words.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    /// Returns the packed words holding the bits.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// The number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns true if any bit is set.
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|w| *w != 0)
+    }
+
+    /// Returns true if every bit is set.
+    pub fn all(&self) -> bool {
+        self.count_ones() == self.len
+    }
+
+    /// Iterates every bit in order.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |i| self.words[i / 64] >> (i % 64) & 1 == 1)
+    }
+
+    /// Iterates the indices of the set bits in order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            let mut bits = word;
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let lane = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                Some(w * 64 + lane)
+            })
+        })
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = BitVec::new();
+        for bit in iter {
+            bits.push(bit);
+        }
+        bits
+    }
+}
+
+impl Debug for BitVec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BitVec[")?;
+        for bit in self.iter() {
+            write!(f, "{}", bit as u8)?;
+        }
+        write!(f, "]")
+    }
+}
@@ -0,0 +1,9 @@
+mod bitvec;
+mod query;
+#[cfg(feature = "simd")]
+mod simd;
+mod soa;
+
+pub use bitvec::*;
+pub use query::*;
+pub use soa::*;
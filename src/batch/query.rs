@@ -0,0 +1,189 @@
+use super::{AabrSoA, BitVec, CircleSoA};
+use crate::{
+    core::Point,
+    nums::PrimaFloat,
+    shapes::{Aabr, Circle},
+};
+
+/// Tests one shape against a whole structure-of-arrays collection at once.
+///
+/// The loops run over contiguous columns of coordinates without branching, so the compiler can
+/// vectorise them. With the `simd` feature, intersection tests on `f32` collections use explicit
+/// eight lane SIMD instead.
+pub trait BatchQuery<N, B> {
+    /// Returns a bit for each shape in the batch, set if it intersects self. Touching counts
+    /// wherever it does for the matching [crate::traits::Collide] test.
+    fn intersecting_many(&self, batch: &B) -> BitVec;
+
+    /// Returns the index of the shape in the batch closest to self along with the gap between
+    /// them, which is zero for shapes that overlap. Ties go to the lowest index, and None is
+    /// returned for an empty batch.
+    fn nearest_of(&self, batch: &B) -> Option<(usize, N)>;
+}
+
+/// Finds the lowest distance produced for each index.
+fn nearest<N, F>(len: usize, mut distance: F) -> Option<(usize, N)>
+where
+    N: PrimaFloat,
+    F: FnMut(usize) -> N,
+{
+    let mut best: Option<(usize, N)> = None;
+    for i in 0..len {
+        let d = distance(i);
+        if best.is_none_or(|(_, b)| d < b) {
+            best = Some((i, d));
+        }
+    }
+    best
+}
+
+/// The squared distance from a point to each rectangle, which is zero inside it.
+#[inline]
+fn gap_squared<N: PrimaFloat>(x: N, y: N, min_x: N, min_y: N, max_x: N, max_y: N) -> N {
+    let dx = (min_x - x).max(x - max_x).max(N::zero());
+    let dy = (min_y - y).max(y - max_y).max(N::zero());
+    dx * dx + dy * dy
+}
+
+//=================================================================//
+//============================= AABRS =============================//
+//=================================================================//
+
+impl<N> BatchQuery<N, AabrSoA<N>> for Circle<N>
+where
+    N: PrimaFloat + 'static,
+{
+    fn intersecting_many(&self, batch: &AabrSoA<N>) -> BitVec {
+        #[cfg(feature = "simd")]
+        if let Some(bits) = super::simd::circle_aabrs(self, batch) {
+            return bits;
+        }
+        let (min_x, min_y, max_x, max_y) =
+            (batch.min_x(), batch.min_y(), batch.max_x(), batch.max_y());
+        let (x, y) = (self.center.x, self.center.y);
+        let r_sq = self.radius * self.radius;
+        BitVec::from_fn(batch.len(), |i| {
+            gap_squared(x, y, min_x[i], min_y[i], max_x[i], max_y[i]) <= r_sq
+        })
+    }
+
+    fn nearest_of(&self, batch: &AabrSoA<N>) -> Option<(usize, N)> {
+        let (min_x, min_y, max_x, max_y) =
+            (batch.min_x(), batch.min_y(), batch.max_x(), batch.max_y());
+        let (x, y) = (self.center.x, self.center.y);
+        nearest(batch.len(), |i| {
+            let gap = gap_squared(x, y, min_x[i], min_y[i], max_x[i], max_y[i]).sqrt();
+            (gap - self.radius).max(N::zero())
+        })
+    }
+}
+
+impl<N> BatchQuery<N, AabrSoA<N>> for Point<N>
+where
+    N: PrimaFloat + 'static,
+{
+    fn intersecting_many(&self, batch: &AabrSoA<N>) -> BitVec {
+        Circle::new(*self, N::zero()).intersecting_many(batch)
+    }
+
+    fn nearest_of(&self, batch: &AabrSoA<N>) -> Option<(usize, N)> {
+        Circle::new(*self, N::zero()).nearest_of(batch)
+    }
+}
+
+impl<N> BatchQuery<N, AabrSoA<N>> for Aabr<N>
+where
+    N: PrimaFloat + 'static,
+{
+    fn intersecting_many(&self, batch: &AabrSoA<N>) -> BitVec {
+        #[cfg(feature = "simd")]
+        if let Some(bits) = super::simd::aabr_aabrs(self, batch) {
+            return bits;
+        }
+        let (min_x, min_y, max_x, max_y) =
+            (batch.min_x(), batch.min_y(), batch.max_x(), batch.max_y());
+        let (a_min, a_max) = (self.min, self.max);
+        BitVec::from_fn(batch.len(), |i| {
+            (a_min.x < max_x[i])
+                & (a_max.x > min_x[i])
+                & (a_min.y < max_y[i])
+                & (a_max.y > min_y[i])
+        })
+    }
+
+    fn nearest_of(&self, batch: &AabrSoA<N>) -> Option<(usize, N)> {
+        let (min_x, min_y, max_x, max_y) =
+            (batch.min_x(), batch.min_y(), batch.max_x(), batch.max_y());
+        let (a_min, a_max) = (self.min, self.max);
+        nearest(batch.len(), |i| {
+            let dx = (min_x[i] - a_max.x).max(a_min.x - max_x[i]).max(N::zero());
+            let dy = (min_y[i] - a_max.y).max(a_min.y - max_y[i]).max(N::zero());
+            dx.hypot(dy)
+        })
+    }
+}
+
+//=================================================================//
+//============================ CIRCLES ============================//
+//=================================================================//
+
+impl<N> BatchQuery<N, CircleSoA<N>> for Circle<N>
+where
+    N: PrimaFloat + 'static,
+{
+    fn intersecting_many(&self, batch: &CircleSoA<N>) -> BitVec {
+        #[cfg(feature = "simd")]
+        if let Some(bits) = super::simd::circle_circles(self, batch) {
+            return bits;
+        }
+        let (xs, ys, radii) = (batch.x(), batch.y(), batch.radius());
+        let (x, y, r) = (self.center.x, self.center.y, self.radius);
+        BitVec::from_fn(batch.len(), |i| {
+            let (dx, dy, reach) = (xs[i] - x, ys[i] - y, radii[i] + r);
+            dx * dx + dy * dy <= reach * reach
+        })
+    }
+
+    fn nearest_of(&self, batch: &CircleSoA<N>) -> Option<(usize, N)> {
+        let (xs, ys, radii) = (batch.x(), batch.y(), batch.radius());
+        let (x, y, r) = (self.center.x, self.center.y, self.radius);
+        nearest(batch.len(), |i| {
+            ((xs[i] - x).hypot(ys[i] - y) - radii[i] - r).max(N::zero())
+        })
+    }
+}
+
+impl<N> BatchQuery<N, CircleSoA<N>> for Point<N>
+where
+    N: PrimaFloat + 'static,
+{
+    fn intersecting_many(&self, batch: &CircleSoA<N>) -> BitVec {
+        Circle::new(*self, N::zero()).intersecting_many(batch)
+    }
+
+    fn nearest_of(&self, batch: &CircleSoA<N>) -> Option<(usize, N)> {
+        Circle::new(*self, N::zero()).nearest_of(batch)
+    }
+}
+
+impl<N> BatchQuery<N, CircleSoA<N>> for Aabr<N>
+where
+    N: PrimaFloat + 'static,
+{
+    fn intersecting_many(&self, batch: &CircleSoA<N>) -> BitVec {
+        let (xs, ys, radii) = (batch.x(), batch.y(), batch.radius());
+        let (min, max) = (self.min, self.max);
+        BitVec::from_fn(batch.len(), |i| {
+            gap_squared(xs[i], ys[i], min.x, min.y, max.x, max.y) <= radii[i] * radii[i]
+        })
+    }
+
+    fn nearest_of(&self, batch: &CircleSoA<N>) -> Option<(usize, N)> {
+        let (xs, ys, radii) = (batch.x(), batch.y(), batch.radius());
+        let (min, max) = (self.min, self.max);
+        nearest(batch.len(), |i| {
+            let gap = gap_squared(xs[i], ys[i], min.x, min.y, max.x, max.y).sqrt();
+            (gap - radii[i]).max(N::zero())
+        })
+    }
+}
@@ -0,0 +1,118 @@
+//! Explicit SIMD kernels for `f32` collections, used by [super::BatchQuery] when the `simd`
+//! feature is enabled. Each kernel returns None for other number types, which fall back to
+//! the portable loops.
+
+use std::any::Any;
+
+use wide::{f32x8, CmpGt, CmpLe, CmpLt};
+
+use super::{AabrSoA, BitVec, CircleSoA};
+use crate::{
+    nums::PrimaFloat,
+    shapes::{Aabr, Circle},
+};
+
+const LANES: usize = 8;
+
+/// Views a value as its `f32` form, if that is what it is.
+fn as_f32<T: Any, U: Any>(value: &T) -> Option<&U> {
+    (value as &dyn Any).downcast_ref::<U>()
+}
+
+/// Loads eight lanes starting at the given index.
+#[inline]
+fn load(values: &[f32], index: usize) -> f32x8 {
+    let lanes: [f32; LANES] = values[index..index + LANES].try_into().unwrap();
+    f32x8::from(lanes)
+}
+
+/// Builds a bit vector eight lanes at a time, finishing any remainder with the scalar test.
+fn pack<V, S>(len: usize, mut vector: V, mut scalar: S) -> BitVec
+where
+    V: FnMut(usize) -> u64,
+    S: FnMut(usize) -> bool,
+{
+    let mut words = vec![0u64; len.div_ceil(64)];
+    let whole = len - len % LANES;
+    for start in (0..whole).step_by(LANES) {
+        words[start / 64] |= vector(start) << (start % 64);
+    }
+    for i in whole..len {
+        words[i / 64] |= (scalar(i) as u64) << (i % 64);
+    }
+    BitVec::from_words(words, len)
+}
+
+pub(crate) fn circle_aabrs<N: PrimaFloat + 'static>(
+    circle: &Circle<N>,
+    batch: &AabrSoA<N>,
+) -> Option<BitVec> {
+    let circle: &Circle<f32> = as_f32(circle)?;
+    let batch: &AabrSoA<f32> = as_f32(batch)?;
+    let (min_x, min_y, max_x, max_y) = (batch.min_x(), batch.min_y(), batch.max_x(), batch.max_y());
+    let (x, y, r) = (circle.center.x, circle.center.y, circle.radius);
+    let (vx, vy, r_sq, zero) = (
+        f32x8::splat(x),
+        f32x8::splat(y),
+        f32x8::splat(r * r),
+        f32x8::ZERO,
+    );
+    Some(pack(
+        batch.len(),
+        |i| {
+            let dx = (load(min_x, i) - vx).max(vx - load(max_x, i)).max(zero);
+            let dy = (load(min_y, i) - vy).max(vy - load(max_y, i)).max(zero);
+            (dx * dx + dy * dy).cmp_le(r_sq).move_mask() as u64
+        },
+        |i| {
+            let dx = (min_x[i] - x).max(x - max_x[i]).max(0.0);
+            let dy = (min_y[i] - y).max(y - max_y[i]).max(0.0);
+            dx * dx + dy * dy <= r * r
+        },
+    ))
+}
+
+pub(crate) fn aabr_aabrs<N: PrimaFloat + 'static>(
+    aabr: &Aabr<N>,
+    batch: &AabrSoA<N>,
+) -> Option<BitVec> {
+    let aabr: &Aabr<f32> = as_f32(aabr)?;
+    let batch: &AabrSoA<f32> = as_f32(batch)?;
+    let (min_x, min_y, max_x, max_y) = (batch.min_x(), batch.min_y(), batch.max_x(), batch.max_y());
+    let (a_min, a_max) = (aabr.min, aabr.max);
+    let (lo_x, lo_y) = (f32x8::splat(a_min.x), f32x8::splat(a_min.y));
+    let (hi_x, hi_y) = (f32x8::splat(a_max.x), f32x8::splat(a_max.y));
+    Some(pack(
+        batch.len(),
+        |i| {
+            let overlap = lo_x.cmp_lt(load(max_x, i))
+                & hi_x.cmp_gt(load(min_x, i))
+                & lo_y.cmp_lt(load(max_y, i))
+                & hi_y.cmp_gt(load(min_y, i));
+            overlap.move_mask() as u64
+        },
+        |i| a_min.x < max_x[i] && a_max.x > min_x[i] && a_min.y < max_y[i] && a_max.y > min_y[i],
+    ))
+}
+
+pub(crate) fn circle_circles<N: PrimaFloat + 'static>(
+    circle: &Circle<N>,
+    batch: &CircleSoA<N>,
+) -> Option<BitVec> {
+    let circle: &Circle<f32> = as_f32(circle)?;
+    let batch: &CircleSoA<f32> = as_f32(batch)?;
+    let (xs, ys, radii) = (batch.x(), batch.y(), batch.radius());
+    let (x, y, r) = (circle.center.x, circle.center.y, circle.radius);
+    let (vx, vy, vr) = (f32x8::splat(x), f32x8::splat(y), f32x8::splat(r));
+    Some(pack(
+        batch.len(),
+        |i| {
+            let (dx, dy, reach) = (load(xs, i) - vx, load(ys, i) - vy, load(radii, i) + vr);
+            (dx * dx + dy * dy).cmp_le(reach * reach).move_mask() as u64
+        },
+        |i| {
+            let (dx, dy, reach) = (xs[i] - x, ys[i] - y, radii[i] + r);
+            dx * dx + dy * dy <= reach * reach
+        },
+    ))
+}
@@ -0,0 +1,243 @@
+use crate::{
+    core::Point,
+    shapes::{Aabr, Circle},
+};
+
+/// Axis-aligned rectangles stored as structure-of-arrays, with each coordinate in its own
+/// contiguous column so batch queries can process several rectangles per instruction.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AabrSoA<N> {
+    min_x: Vec<N>,
+    min_y: Vec<N>,
+    max_x: Vec<N>,
+    max_y: Vec<N>,
+}
+
+/// Circles stored as structure-of-arrays, with each coordinate in its own contiguous column so
+/// batch queries can process several circles per instruction.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CircleSoA<N> {
+    x: Vec<N>,
+    y: Vec<N>,
+    radius: Vec<N>,
+}
+
+impl<N> AabrSoA<N>
+where
+    N: Copy,
+{
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self {
+            min_x: Vec::new(),
+            min_y: Vec::new(),
+            max_x: Vec::new(),
+            max_y: Vec::new(),
+        }
+    }
+
+    /// Creates an empty collection with room for the given number of rectangles.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            min_x: Vec::with_capacity(capacity),
+            min_y: Vec::with_capacity(capacity),
+            max_x: Vec::with_capacity(capacity),
+            max_y: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a rectangle to the end.
+    pub fn push(&mut self, aabr: Aabr<N>) {
+        self.min_x.push(aabr.min.x);
+        self.min_y.push(aabr.min.y);
+        self.max_x.push(aabr.max.x);
+        self.max_y.push(aabr.max.y);
+    }
+
+    /// Returns the rectangle at the given index.
+    pub fn get(&self, index: usize) -> Option<Aabr<N>> {
+        Some(Aabr {
+            min: Point {
+                x: *self.min_x.get(index)?,
+                y: self.min_y[index],
+            },
+            max: Point {
+                x: self.max_x[index],
+                y: self.max_y[index],
+            },
+        })
+    }
+
+    /// Replaces the rectangle at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range.
+    pub fn set(&mut self, index: usize, aabr: Aabr<N>) {
+        self.min_x[index] = aabr.min.x;
+        self.min_y[index] = aabr.min.y;
+        self.max_x[index] = aabr.max.x;
+        self.max_y[index] = aabr.max.y;
+    }
+
+    /// The number of rectangles.
+    pub fn len(&self) -> usize {
+        self.min_x.len()
+    }
+
+    /// Returns true if there are no rectangles.
+    pub fn is_empty(&self) -> bool {
+        self.min_x.is_empty()
+    }
+
+    /// Iterates the rectangles in order.
+    pub fn iter(&self) -> impl Iterator<Item = Aabr<N>> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+
+    /// The minimum x coordinate of every rectangle.
+    pub fn min_x(&self) -> &[N] {
+        &self.min_x
+    }
+
+    /// The minimum y coordinate of every rectangle.
+    pub fn min_y(&self) -> &[N] {
+        &self.min_y
+    }
+
+    /// The maximum x coordinate of every rectangle.
+    pub fn max_x(&self) -> &[N] {
+        &self.max_x
+    }
+
+    /// The maximum y coordinate of every rectangle.
+    pub fn max_y(&self) -> &[N] {
+        &self.max_y
+    }
+}
+
+impl<N> CircleSoA<N>
+where
+    N: Copy,
+{
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self {
+            x: Vec::new(),
+            y: Vec::new(),
+            radius: Vec::new(),
+        }
+    }
+
+    /// Creates an empty collection with room for the given number of circles.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            x: Vec::with_capacity(capacity),
+            y: Vec::with_capacity(capacity),
+            radius: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a circle to the end.
+    pub fn push(&mut self, circle: Circle<N>) {
+        self.x.push(circle.center.x);
+        self.y.push(circle.center.y);
+        self.radius.push(circle.radius);
+    }
+
+    /// Returns the circle at the given index.
+    pub fn get(&self, index: usize) -> Option<Circle<N>> {
+        Some(Circle {
+            center: Point {
+                x: *self.x.get(index)?,
+                y: self.y[index],
+            },
+            radius: self.radius[index],
+        })
+    }
+
+    /// Replaces the circle at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range.
+    pub fn set(&mut self, index: usize, circle: Circle<N>) {
+        self.x[index] = circle.center.x;
+        self.y[index] = circle.center.y;
+        self.radius[index] = circle.radius;
+    }
+
+    /// The number of circles.
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Returns true if there are no circles.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Iterates the circles in order.
+    pub fn iter(&self) -> impl Iterator<Item = Circle<N>> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+
+    /// The x coordinate of every center.
+    pub fn x(&self) -> &[N] {
+        &self.x
+    }
+
+    /// The y coordinate of every center.
+    pub fn y(&self) -> &[N] {
+        &self.y
+    }
+
+    /// The radius of every circle.
+    pub fn radius(&self) -> &[N] {
+        &self.radius
+    }
+}
+
+impl<N> FromIterator<Aabr<N>> for AabrSoA<N>
+where
+    N: Copy,
+{
+    fn from_iter<I: IntoIterator<Item = Aabr<N>>>(iter: I) -> Self {
+        let mut soa = Self::new();
+        for aabr in iter {
+            soa.push(aabr);
+        }
+        soa
+    }
+}
+
+impl<N> FromIterator<Circle<N>> for CircleSoA<N>
+where
+    N: Copy,
+{
+    fn from_iter<I: IntoIterator<Item = Circle<N>>>(iter: I) -> Self {
+        let mut soa = Self::new();
+        for circle in iter {
+            soa.push(circle);
+        }
+        soa
+    }
+}
+
+impl<N> From<&[Aabr<N>]> for AabrSoA<N>
+where
+    N: Copy,
+{
+    fn from(aabrs: &[Aabr<N>]) -> Self {
+        aabrs.iter().copied().collect()
+    }
+}
+
+impl<N> From<&[Circle<N>]> for CircleSoA<N>
+where
+    N: Copy,
+{
+    fn from(circles: &[Circle<N>]) -> Self {
+        circles.iter().copied().collect()
+    }
+}
@@ -9,6 +9,7 @@ use super::{Line, Vector};
 
 /// A single axis line between two values. Useful for collision detection, especially when using seperating axis theorem.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AxisLine<N> {
     /// The start value of the axis.
     pub start: N,
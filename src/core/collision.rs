@@ -1,7 +1,8 @@
 use super::*;
 
 /// Represents a collision between two shapes.
-#[derive(Clone, Debug, Copy)]
+#[derive(Clone, Debug, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Collision<N> {
     /// The point of contact.
     pub point: Point<N>,
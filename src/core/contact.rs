@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+
+use super::Collision;
+use crate::nums::PrimaFloat;
+
+/// Where a pair of shapes is in its contact with one another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContactPhase {
+    /// The pair started touching this frame.
+    Enter,
+    /// The pair was already touching and still is.
+    Stay,
+    /// The pair stopped touching this frame.
+    Exit,
+}
+
+/// A change in contact between two shapes, identified by their ids.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactEvent<K, N> {
+    /// The lower of the two ids.
+    pub a: K,
+    /// The higher of the two ids.
+    pub b: K,
+    /// What happened to the pair.
+    pub phase: ContactPhase,
+    /// The most recent collision reported for the pair, with its normal pointing from `a` to `b`.
+    pub collision: Option<Collision<N>>,
+}
+
+/// The persistent state of a pair of shapes that are touching.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contact<N> {
+    /// The most recent collision reported for the pair, with its normal pointing from the lower id to the higher.
+    pub collision: Option<Collision<N>>,
+    /// The frame the contact began on.
+    pub since: u64,
+    /// How many frames the pair has been touching for, including the current one.
+    pub frames: u64,
+}
+
+/// Tracks which pairs of shapes are touching from one frame to the next, turning the pairs found
+/// by [crate::traits::Collide] or a broad phase into enter, stay and exit events.
+///
+/// Each frame, report every intersecting pair with [ContactTracker::report] and then call
+/// [ContactTracker::end_frame], or pass them all at once to [ContactTracker::update]. Pairs are
+/// unordered, so `(a, b)` and `(b, a)` are the same contact, and are always given back with the
+/// lower id first.
+#[derive(Clone, Debug)]
+pub struct ContactTracker<K, N> {
+    contacts: BTreeMap<(K, K), Contact<N>>,
+    pending: BTreeMap<(K, K), Option<Collision<N>>>,
+    frame: u64,
+}
+
+impl<K, N> Default for ContactTracker<K, N> {
+    fn default() -> Self {
+        Self {
+            contacts: BTreeMap::new(),
+            pending: BTreeMap::new(),
+            frame: 0,
+        }
+    }
+}
+
+impl<K, N> ContactTracker<K, N>
+where
+    K: Ord + Clone,
+    N: PrimaFloat,
+{
+    /// Creates a tracker with no contacts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of frames that have ended so far.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Reports that two shapes are intersecting this frame, along with their collision if known.
+    /// The collision's normal should point from `a` to `b`. A pair of an id with itself is ignored.
+    ///
+    /// A pair reported more than once in a frame keeps the last collision given for it.
+    pub fn report(&mut self, a: K, b: K, collision: Option<Collision<N>>) {
+        let (key, collision) = match a.cmp(&b) {
+            std::cmp::Ordering::Less => ((a, b), collision),
+            std::cmp::Ordering::Greater => ((b, a), collision.map(flip)),
+            std::cmp::Ordering::Equal => return,
+        };
+        let entry = self.pending.entry(key).or_insert(None);
+        if collision.is_some() {
+            *entry = collision;
+        }
+    }
+
+    /// Ends the frame, comparing the pairs reported during it against those of the last frame.
+    ///
+    /// Exit events come first, followed by enter and stay events, each ordered by id. Contacts
+    /// that stay keep their previous collision if none was reported for them this frame.
+    pub fn end_frame(&mut self) -> Vec<ContactEvent<K, N>> {
+        let pending = std::mem::take(&mut self.pending);
+        let mut events = Vec::with_capacity(self.contacts.len().max(pending.len()));
+
+        let ended: Vec<(K, K)> = self
+            .contacts
+            .keys()
+            .filter(|key| !pending.contains_key(*key))
+            .cloned()
+            .collect();
+        for key in ended {
+            let contact = self.contacts.remove(&key).unwrap();
+            events.push(event(key, ContactPhase::Exit, contact.collision));
+        }
+
+        for (key, collision) in pending {
+            let phase = match self.contacts.get_mut(&key) {
+                Some(contact) => {
+                    contact.frames += 1;
+                    if collision.is_some() {
+                        contact.collision = collision;
+                    }
+                    ContactPhase::Stay
+                }
+                None => {
+                    self.contacts.insert(
+                        key.clone(),
+                        Contact {
+                            collision,
+                            since: self.frame,
+                            frames: 1,
+                        },
+                    );
+                    ContactPhase::Enter
+                }
+            };
+            let collision = self.contacts[&key].collision;
+            events.push(event(key, phase, collision));
+        }
+
+        self.frame += 1;
+        events
+    }
+
+    /// Reports every pair that is intersecting this frame, then ends the frame.
+    pub fn update<I>(&mut self, pairs: I) -> Vec<ContactEvent<K, N>>
+    where
+        I: IntoIterator<Item = (K, K)>,
+    {
+        for (a, b) in pairs {
+            self.report(a, b, None);
+        }
+        self.end_frame()
+    }
+
+    /// Reports every pair that is colliding this frame along with its collision, then ends the frame.
+    pub fn update_collisions<I>(&mut self, collisions: I) -> Vec<ContactEvent<K, N>>
+    where
+        I: IntoIterator<Item = (K, K, Collision<N>)>,
+    {
+        for (a, b, collision) in collisions {
+            self.report(a, b, Some(collision));
+        }
+        self.end_frame()
+    }
+
+    /// Returns the contact between two shapes, if they are touching.
+    pub fn contact(&self, a: &K, b: &K) -> Option<&Contact<N>> {
+        if a <= b {
+            self.contacts.get(&(a.clone(), b.clone()))
+        } else {
+            self.contacts.get(&(b.clone(), a.clone()))
+        }
+    }
+
+    /// Returns true if the two shapes are touching.
+    pub fn touching(&self, a: &K, b: &K) -> bool {
+        self.contact(a, b).is_some()
+    }
+
+    /// Iterates over every pair that is touching, ordered by id.
+    pub fn contacts(&self) -> impl Iterator<Item = (&K, &K, &Contact<N>)> + '_ {
+        self.contacts.iter().map(|((a, b), c)| (a, b, c))
+    }
+
+    /// The number of pairs that are touching.
+    pub fn len(&self) -> usize {
+        self.contacts.len()
+    }
+
+    /// Returns true if no pairs are touching.
+    pub fn is_empty(&self) -> bool {
+        self.contacts.is_empty()
+    }
+
+    /// Forgets a shape, such as one that has been despawned, returning exit events for each of
+    /// its contacts. Anything reported for it in the current frame is discarded.
+    pub fn remove(&mut self, id: &K) -> Vec<ContactEvent<K, N>> {
+        self.pending.retain(|(a, b), _| a != id && b != id);
+        let ended: Vec<(K, K)> = self
+            .contacts
+            .keys()
+            .filter(|(a, b)| a == id || b == id)
+            .cloned()
+            .collect();
+        ended
+            .into_iter()
+            .map(|key| {
+                let contact = self.contacts.remove(&key).unwrap();
+                event(key, ContactPhase::Exit, contact.collision)
+            })
+            .collect()
+    }
+
+    /// Forgets every contact without producing any events.
+    pub fn clear(&mut self) {
+        self.contacts.clear();
+        self.pending.clear();
+    }
+}
+
+fn event<K, N>(
+    key: (K, K),
+    phase: ContactPhase,
+    collision: Option<Collision<N>>,
+) -> ContactEvent<K, N> {
+    ContactEvent {
+        a: key.0,
+        b: key.1,
+        phase,
+        collision,
+    }
+}
+
+/// Turns a collision around so that its normal points the other way.
+fn flip<N: PrimaFloat>(collision: Collision<N>) -> Collision<N> {
+    Collision::new(collision.point, -collision.normal, collision.depth)
+}
@@ -1,13 +1,20 @@
 use super::{AngleMat, Rotation, Point, Vector};
 use crate::{xy_impl, nums::{PrimaNum, PrimaFloat}, traits::Cross};
-use std::{fmt::Display, ops::Neg};
+use std::{
+    fmt::Display,
+    iter::Sum,
+    ops::{AddAssign, Neg, Sub, SubAssign},
+};
 
 /// Width and height extent of a shape.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Extent<N> {
     /// Width
+    #[cfg_attr(feature = "serde", serde(rename = "width"))]
     x: N,
     /// Height
+    #[cfg_attr(feature = "serde", serde(rename = "height"))]
     y: N,
 }
 
@@ -82,3 +89,54 @@ where
 }
 
 xy_impl!(Extent);
+
+impl<N> Add for Extent<N>
+where
+    N: PrimaNum,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<N> AddAssign for Extent<N>
+where
+    N: PrimaNum,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<N> Sub for Extent<N>
+where
+    N: PrimaNum,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<N> SubAssign for Extent<N>
+where
+    N: PrimaNum,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<N> Sum for Extent<N>
+where
+    N: PrimaNum,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
@@ -0,0 +1,74 @@
+/// Decides which shapes are allowed to interact, using collision groups and an optional predicate.
+///
+/// Each filter names the groups its shape belongs to and a mask of the groups it interacts with.
+/// Two shapes interact only if each one's groups overlap the other's mask. A predicate can
+/// narrow this further by comparing the user data of both shapes, such as to stop a projectile
+/// hitting whoever fired it.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollisionFilter<T = ()> {
+    /// The groups this shape belongs to, one per bit.
+    pub groups: u32,
+    /// The groups this shape interacts with, one per bit.
+    pub mask: u32,
+    /// Given this shape's data and then the other shape's, returns true if they may interact.
+    /// Predicates cannot be serialized, so are dropped.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub predicate: Option<fn(&T, &T) -> bool>,
+}
+
+impl<T> CollisionFilter<T> {
+    /// Belongs to and interacts with every group.
+    pub const ALL: Self = Self::new(u32::MAX, u32::MAX);
+
+    /// Belongs to and interacts with no groups, so never interacts with anything.
+    pub const NONE: Self = Self::new(0, 0);
+
+    /// Creates a filter from group and mask bits, with no predicate.
+    pub const fn new(groups: u32, mask: u32) -> Self {
+        Self {
+            groups,
+            mask,
+            predicate: None,
+        }
+    }
+
+    /// Returns the filter with its predicate replaced.
+    pub fn with_predicate(mut self, predicate: fn(&T, &T) -> bool) -> Self {
+        self.predicate = Some(predicate);
+        self
+    }
+
+    /// Returns true if the group and mask bits of both filters let them interact.
+    /// Predicates are not consulted.
+    pub fn interacts(&self, other: &Self) -> bool {
+        self.groups & other.mask != 0 && other.groups & self.mask != 0
+    }
+
+    /// Returns true if this filter's predicate accepts a shape carrying `other_data`, given that
+    /// its own shape carries `data`, or if there is no predicate.
+    pub fn accepts(&self, data: &T, other_data: &T) -> bool {
+        self.predicate
+            .is_none_or(|predicate| predicate(data, other_data))
+    }
+
+    /// Returns true if two shapes carrying these filters and data may interact.
+    /// Both the bits and the predicates of each side must agree.
+    pub fn allows(&self, data: &T, other: &Self, other_data: &T) -> bool {
+        self.interacts(other) && self.accepts(data, other_data) && other.accepts(other_data, data)
+    }
+}
+
+impl<T> Clone for CollisionFilter<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CollisionFilter<T> {}
+
+impl<T> Default for CollisionFilter<T> {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
@@ -1,11 +1,13 @@
 use super::{Point, Vector};
 use crate::{
     nums::{PrimaFloat, PrimaNum},
-    traits::{Cross, Distance, Magnitude, Nearest},
+    shapes::Capsule,
+    traits::{Cross, Distance, Intersections, Magnitude, Nearest, SignedDistance},
 };
 
 /// A line between two points.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line<N = f32> {
     /// The starting point of the line.
     pub start: Point<N>,
@@ -51,7 +53,7 @@ where
         if t < N::zero() || t > N::one() || u < N::zero() || u > N::one() {
             return None;
         }
-        return Some(self.start + self.vector() * t);
+        Some(self.start + self.vector() * t)
     }
 
     /// Returns the line's vector.
@@ -69,11 +71,29 @@ where
         (*p - self.start).dot(&self.vector()) / self.magnitude_squared()
     }
 
+    /// Grows the line outward by the given radius, returning a capsule.
+    pub fn inflate(&self, radius: N) -> Capsule<N> {
+        Capsule::new(*self, radius)
+    }
+
     /// Projects the given point onto an unbound self.
     pub fn project_point(&self, p: &Point<N>) -> Point<N> {
         let v = self.relative_dot(p);
         self.start + self.vector() * v
     }
+
+    /// Every point where the line crosses one of the edges, ordered from the start of the line.
+    pub(crate) fn crossings(&self, edges: &[Line<N>]) -> Vec<Point<N>> {
+        let mut hits: Vec<(N, Point<N>)> = edges
+            .iter()
+            .filter_map(|edge| self.collision(edge))
+            .map(|p| (self.relative_dot(&p), p))
+            .collect();
+        hits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let mut points = hits.into_iter().map(|(_, p)| p).collect();
+        dedup_points(&mut points, false);
+        points
+    }
 }
 
 impl<N> Magnitude<N> for Line<N>
@@ -91,6 +111,17 @@ where
 //========================= POINT =================================//
 //=================================================================//
 
+/// A line has no inside, so its distance is never negative. Offset it with
+/// [SignedDistance::offset] to give it a thickness, which gives the same field as a [Capsule].
+impl<N> SignedDistance<N> for Line<N>
+where
+    N: PrimaFloat,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        self.distance(point)
+    }
+}
+
 impl<N> Distance<N, Point<N>> for Line<N>
 where
     N: PrimaFloat,
@@ -119,6 +150,32 @@ where
     }
 }
 
+/// Every point where an edge of `a` crosses an edge of `b`, following `a` edge by edge.
+pub(crate) fn edge_crossings<N>(a: &[Line<N>], b: &[Line<N>]) -> Vec<Point<N>>
+where
+    N: PrimaFloat,
+{
+    let mut points = Vec::new();
+    for edge in a {
+        points.extend(edge.crossings(b));
+    }
+    dedup_points(&mut points, true);
+    points
+}
+
+/// Removes repeats of a point, such as a crossing through a corner shared by two edges.
+/// A `closed` list also has its last point compared against its first.
+fn dedup_points<N>(points: &mut Vec<Point<N>>, closed: bool)
+where
+    N: PrimaFloat,
+{
+    let tolerance = N::epsilon().sqrt();
+    points.dedup_by(|a, b| a.distance(b) <= tolerance);
+    if closed && points.len() > 1 && points[0].distance(&points[points.len() - 1]) <= tolerance {
+        points.pop();
+    }
+}
+
 //=================================================================//
 //============================= LINE ==============================//
 //=================================================================//
@@ -128,7 +185,7 @@ where
     N: PrimaFloat,
 {
     fn distance_squared(&self, other: &Line<N>) -> N {
-        if self.collision(&other).is_some() {
+        if self.collision(other).is_some() {
             return N::zero();
         }
         let a = self.nearest_point(other);
@@ -189,3 +246,12 @@ where
         }
     }
 }
+
+impl<N> Intersections<N, Line<N>> for Line<N>
+where
+    N: PrimaFloat,
+{
+    fn intersections(&self, other: &Line<N>) -> Vec<Point<N>> {
+        self.collision(other).into_iter().collect()
+    }
+}
@@ -0,0 +1,437 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    core::{AngleMat, Point, Rotation, Vector},
+    nums::{PrimaFloat, PrimaNum},
+};
+
+/// A 2x2 matrix, for linear transformations such as rotation, scale and shear.
+/// Fields are named by column then row, matching [AngleMat].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mat2<N = f32> {
+    /// Top left
+    pub m00: N,
+    /// Top right
+    pub m10: N,
+    /// Bottom left
+    pub m01: N,
+    /// Bottom right
+    pub m11: N,
+}
+
+/// A 3x3 homogeneous matrix, which adds translation to the transformations of a [Mat2].
+/// Fields are named by column then row, so the translation lives in `m20` and `m21`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mat3<N = f32> {
+    /// Row 0, column 0
+    pub m00: N,
+    /// Row 0, column 1
+    pub m10: N,
+    /// Row 0, column 2
+    pub m20: N,
+    /// Row 1, column 0
+    pub m01: N,
+    /// Row 1, column 1
+    pub m11: N,
+    /// Row 1, column 2
+    pub m21: N,
+    /// Row 2, column 0
+    pub m02: N,
+    /// Row 2, column 1
+    pub m12: N,
+    /// Row 2, column 2
+    pub m22: N,
+}
+
+//=============================================================//
+//=========================== MAT2 ============================//
+//=============================================================//
+
+impl<N> Mat2<N>
+where
+    N: PrimaNum,
+{
+    /// Creates a new matrix, with values given row by row.
+    pub fn new(m00: N, m10: N, m01: N, m11: N) -> Self {
+        Self { m00, m10, m01, m11 }
+    }
+
+    /// The identity matrix, which leaves everything unchanged.
+    pub fn identity() -> Self {
+        Self::new(N::one(), N::zero(), N::zero(), N::one())
+    }
+
+    /// A matrix of zeros.
+    pub fn zero() -> Self {
+        Self::new(N::zero(), N::zero(), N::zero(), N::zero())
+    }
+
+    /// Creates a matrix that scales each axis independently.
+    pub fn from_scale(scale: Vector<N>) -> Self {
+        Self::new(scale.x, N::zero(), N::zero(), scale.y)
+    }
+
+    /// Creates a matrix that shears x by `x` times y, and y by `y` times x.
+    pub fn from_shear(x: N, y: N) -> Self {
+        Self::new(N::one(), x, y, N::one())
+    }
+
+    /// Returns the determinant of the matrix.
+    pub fn determinant(&self) -> N {
+        self.m00 * self.m11 - self.m10 * self.m01
+    }
+
+    /// Returns the matrix flipped along its diagonal.
+    pub fn transpose(&self) -> Self {
+        Self::new(self.m00, self.m01, self.m10, self.m11)
+    }
+
+    /// Applies the matrix to a vector.
+    pub fn transform_vector(&self, v: Vector<N>) -> Vector<N> {
+        Vector::new(
+            self.m00 * v.x + self.m10 * v.y,
+            self.m01 * v.x + self.m11 * v.y,
+        )
+    }
+
+    /// Applies the matrix to a point, relative to the origin.
+    pub fn transform_point(&self, p: Point<N>) -> Point<N> {
+        Point::new(
+            self.m00 * p.x + self.m10 * p.y,
+            self.m01 * p.x + self.m11 * p.y,
+        )
+    }
+}
+
+impl<N> Mat2<N>
+where
+    N: PrimaFloat,
+{
+    /// Creates a matrix that rotates clockwise, in the same way as multiplying by a [Rotation].
+    pub fn from_rotation(rotation: Rotation<N>) -> Self {
+        rotation.to_matrix().into()
+    }
+
+    /// Returns the inverse of the matrix, or `None` if it cannot be inverted.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == N::zero() || !det.is_finite() {
+            return None;
+        }
+        Some(Self::new(
+            self.m11 / det,
+            -self.m10 / det,
+            -self.m01 / det,
+            self.m00 / det,
+        ))
+    }
+}
+
+impl<N> Default for Mat2<N>
+where
+    N: PrimaNum,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<N> Mul for Mat2<N>
+where
+    N: PrimaNum,
+{
+    type Output = Self;
+
+    /// Combines two matrices, so that `(a * b) * v` is the same as `a * (b * v)`.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.m00 * rhs.m00 + self.m10 * rhs.m01,
+            self.m00 * rhs.m10 + self.m10 * rhs.m11,
+            self.m01 * rhs.m00 + self.m11 * rhs.m01,
+            self.m01 * rhs.m10 + self.m11 * rhs.m11,
+        )
+    }
+}
+
+impl<N> MulAssign for Mat2<N>
+where
+    N: PrimaNum,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<N> Mul<Vector<N>> for Mat2<N>
+where
+    N: PrimaNum,
+{
+    type Output = Vector<N>;
+
+    fn mul(self, rhs: Vector<N>) -> Vector<N> {
+        self.transform_vector(rhs)
+    }
+}
+
+impl<N> Mul<Point<N>> for Mat2<N>
+where
+    N: PrimaNum,
+{
+    type Output = Point<N>;
+
+    fn mul(self, rhs: Point<N>) -> Point<N> {
+        self.transform_point(rhs)
+    }
+}
+
+impl<N> Mul<N> for Mat2<N>
+where
+    N: PrimaFloat,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: N) -> Self {
+        Self::new(self.m00 * rhs, self.m10 * rhs, self.m01 * rhs, self.m11 * rhs)
+    }
+}
+
+//=============================================================//
+//=========================== MAT3 ============================//
+//=============================================================//
+
+impl<N> Mat3<N>
+where
+    N: PrimaNum,
+{
+    /// Creates a new matrix, with values given row by row.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(m00: N, m10: N, m20: N, m01: N, m11: N, m21: N, m02: N, m12: N, m22: N) -> Self {
+        Self {
+            m00,
+            m10,
+            m20,
+            m01,
+            m11,
+            m21,
+            m02,
+            m12,
+            m22,
+        }
+    }
+
+    /// The identity matrix, which leaves everything unchanged.
+    pub fn identity() -> Self {
+        Self::from_mat2(Mat2::identity())
+    }
+
+    /// A matrix of zeros.
+    pub fn zero() -> Self {
+        let z = N::zero();
+        Self::new(z, z, z, z, z, z, z, z, z)
+    }
+
+    /// Creates a matrix that applies a linear transformation without translating.
+    pub fn from_mat2(m: Mat2<N>) -> Self {
+        let (z, o) = (N::zero(), N::one());
+        Self::new(m.m00, m.m10, z, m.m01, m.m11, z, z, z, o)
+    }
+
+    /// Creates a matrix that moves points by the given offset. Vectors are unaffected.
+    pub fn from_translation(offset: Vector<N>) -> Self {
+        let mut m = Self::identity();
+        m.m20 = offset.x;
+        m.m21 = offset.y;
+        m
+    }
+
+    /// Creates a matrix that scales each axis independently.
+    pub fn from_scale(scale: Vector<N>) -> Self {
+        Self::from_mat2(Mat2::from_scale(scale))
+    }
+
+    /// Creates a matrix that shears x by `x` times y, and y by `y` times x.
+    pub fn from_shear(x: N, y: N) -> Self {
+        Self::from_mat2(Mat2::from_shear(x, y))
+    }
+
+    /// Returns the linear part of the matrix, without translation.
+    pub fn mat2(&self) -> Mat2<N> {
+        Mat2::new(self.m00, self.m10, self.m01, self.m11)
+    }
+
+    /// Returns the translation part of the matrix.
+    pub fn translation(&self) -> Vector<N> {
+        Vector::new(self.m20, self.m21)
+    }
+
+    /// Returns the determinant of the matrix.
+    pub fn determinant(&self) -> N {
+        self.m00 * (self.m11 * self.m22 - self.m21 * self.m12)
+            - self.m10 * (self.m01 * self.m22 - self.m21 * self.m02)
+            + self.m20 * (self.m01 * self.m12 - self.m11 * self.m02)
+    }
+
+    /// Returns the matrix flipped along its diagonal.
+    pub fn transpose(&self) -> Self {
+        Self::new(
+            self.m00, self.m01, self.m02, self.m10, self.m11, self.m12, self.m20, self.m21, self.m22,
+        )
+    }
+
+    /// Applies the matrix to a point, including translation.
+    pub fn transform_point(&self, p: Point<N>) -> Point<N> {
+        Point::new(
+            self.m00 * p.x + self.m10 * p.y + self.m20,
+            self.m01 * p.x + self.m11 * p.y + self.m21,
+        )
+    }
+
+    /// Applies the matrix to a vector, ignoring translation.
+    pub fn transform_vector(&self, v: Vector<N>) -> Vector<N> {
+        self.mat2().transform_vector(v)
+    }
+}
+
+impl<N> Mat3<N>
+where
+    N: PrimaFloat,
+{
+    /// Creates a matrix that rotates clockwise around the origin, in the same way as multiplying by a [Rotation].
+    pub fn from_rotation(rotation: Rotation<N>) -> Self {
+        Self::from_mat2(Mat2::from_rotation(rotation))
+    }
+
+    /// Creates a matrix that scales, then rotates, then translates.
+    pub fn from_scale_rotation_translation(scale: Vector<N>, rotation: Rotation<N>, translation: Vector<N>) -> Self {
+        Self::from_translation(translation) * Self::from_rotation(rotation) * Self::from_scale(scale)
+    }
+
+    /// Returns the inverse of the matrix, or `None` if it cannot be inverted.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == N::zero() || !det.is_finite() {
+            return None;
+        }
+        // The transposed matrix of cofactors, divided through by the determinant.
+        let cofactor = |a: N, b: N, c: N, d: N| (a * d - b * c) / det;
+        Some(Self::new(
+            cofactor(self.m11, self.m21, self.m12, self.m22),
+            -cofactor(self.m10, self.m20, self.m12, self.m22),
+            cofactor(self.m10, self.m20, self.m11, self.m21),
+            -cofactor(self.m01, self.m21, self.m02, self.m22),
+            cofactor(self.m00, self.m20, self.m02, self.m22),
+            -cofactor(self.m00, self.m20, self.m01, self.m21),
+            cofactor(self.m01, self.m11, self.m02, self.m12),
+            -cofactor(self.m00, self.m10, self.m02, self.m12),
+            cofactor(self.m00, self.m10, self.m01, self.m11),
+        ))
+    }
+}
+
+impl<N> Default for Mat3<N>
+where
+    N: PrimaNum,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<N> Mul for Mat3<N>
+where
+    N: PrimaNum,
+{
+    type Output = Self;
+
+    /// Combines two matrices, so that `(a * b) * p` is the same as `a * (b * p)`.
+    fn mul(self, rhs: Self) -> Self {
+        let a = [
+            [self.m00, self.m10, self.m20],
+            [self.m01, self.m11, self.m21],
+            [self.m02, self.m12, self.m22],
+        ];
+        let b = [
+            [rhs.m00, rhs.m10, rhs.m20],
+            [rhs.m01, rhs.m11, rhs.m21],
+            [rhs.m02, rhs.m12, rhs.m22],
+        ];
+        let at = |row: usize, col: usize| a[row][0] * b[0][col] + a[row][1] * b[1][col] + a[row][2] * b[2][col];
+        Self::new(
+            at(0, 0),
+            at(0, 1),
+            at(0, 2),
+            at(1, 0),
+            at(1, 1),
+            at(1, 2),
+            at(2, 0),
+            at(2, 1),
+            at(2, 2),
+        )
+    }
+}
+
+impl<N> MulAssign for Mat3<N>
+where
+    N: PrimaNum,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<N> Mul<Point<N>> for Mat3<N>
+where
+    N: PrimaNum,
+{
+    type Output = Point<N>;
+
+    fn mul(self, rhs: Point<N>) -> Point<N> {
+        self.transform_point(rhs)
+    }
+}
+
+impl<N> Mul<Vector<N>> for Mat3<N>
+where
+    N: PrimaNum,
+{
+    type Output = Vector<N>;
+
+    fn mul(self, rhs: Vector<N>) -> Vector<N> {
+        self.transform_vector(rhs)
+    }
+}
+
+//=============================================================//
+//========================= CONVERSIONS =======================//
+//=============================================================//
+
+impl<N> From<AngleMat<N>> for Mat2<N> {
+    fn from(m: AngleMat<N>) -> Self {
+        Self {
+            m00: m.m00,
+            m10: m.m10,
+            m01: m.m01,
+            m11: m.m11,
+        }
+    }
+}
+
+impl<N> From<AngleMat<N>> for Mat3<N>
+where
+    N: PrimaNum,
+{
+    fn from(m: AngleMat<N>) -> Self {
+        Self::from_mat2(m.into())
+    }
+}
+
+impl<N> From<Mat2<N>> for Mat3<N>
+where
+    N: PrimaNum,
+{
+    fn from(m: Mat2<N>) -> Self {
+        Self::from_mat2(m)
+    }
+}
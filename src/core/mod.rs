@@ -1,17 +1,25 @@
 mod axis;
 mod collision;
+mod contact;
 mod extent;
+mod filter;
 mod line;
+mod matrix;
 mod point;
+mod polyline;
 mod ray;
 mod rotation;
 mod vector;
 
 pub use axis::*;
 pub use collision::*;
+pub use contact::*;
 pub use extent::*;
+pub use filter::*;
 pub use line::*;
+pub use matrix::*;
 pub use point::*;
+pub use polyline::*;
 pub use ray::*;
 pub use rotation::*;
 pub use vector::*;
@@ -1,5 +1,3 @@
-use serde::{Serialize, Deserialize};
-
 use crate::{
     core::{AngleMat, Line, Rotation, Vector},
     nums::{PrimaFloat, PrimaNum},
@@ -11,7 +9,8 @@ use std::ops::{AddAssign, Neg, Sub, SubAssign};
 use super::Extent;
 
 /// A point in 2D space.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point<N> {
     /// The x coordinate of the point.
     pub x: N,
@@ -70,6 +69,9 @@ where
 //=============================== OPPERATIONS ==================================//
 //==============================================================================//
 
+// Points are positions and vectors are offsets: a point moves by a vector, and the
+// difference of two points is the vector between them. Points cannot be added together.
+
 impl<N> Add<Vector<N>> for Point<N>
 where
     N: PrimaNum,
@@ -129,6 +131,20 @@ where
     }
 }
 
+impl<N> Sub<Vector<N>> for Point<N>
+where
+    N: PrimaNum,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Vector<N>) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
 impl<N> SubAssign<Vector<N>> for Point<N>
 where
     N: PrimaNum + SubAssign,
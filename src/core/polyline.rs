@@ -0,0 +1,280 @@
+use super::{Line, Point, Vector};
+use crate::{
+    nums::{PrimaFloat, PrimaNum},
+    shapes::Polygon,
+    traits::{Cross, Distance, Magnitude, Nearest},
+};
+
+/// An open path made up of connected line segments.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyline<N> {
+    /// The points along the path, in order.
+    pub points: Vec<Point<N>>,
+}
+
+impl<N> Polyline<N>
+where
+    N: PrimaNum,
+{
+    /// Creates a new polyline from a list of points.
+    pub fn new(points: Vec<Point<N>>) -> Self {
+        Self { points }
+    }
+
+    /// Adds a point to the end of the path.
+    pub fn push(&mut self, point: Point<N>) {
+        self.points.push(point);
+    }
+
+    /// The number of points in the path.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns true if the path has no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the line segments that make up the path.
+    pub fn segments(&self) -> Vec<Line<N>> {
+        self.points
+            .windows(2)
+            .map(|w| Line::new(w[0], w[1]))
+            .collect()
+    }
+}
+
+impl<N> Polyline<N>
+where
+    N: PrimaFloat,
+{
+    /// Finds the segment that lies at the given distance along the path,
+    /// returning the segment and the relative position along it.
+    fn locate(&self, distance: N) -> Option<(Line<N>, N)> {
+        let mut travelled = N::zero();
+        let mut last = None;
+        for segment in self.segments() {
+            let length = segment.magnitude();
+            if length == N::zero() {
+                continue;
+            }
+            if travelled + length >= distance {
+                let t = ((distance - travelled) / length).clamp_01();
+                return Some((segment, t));
+            }
+            travelled += length;
+            last = Some(segment);
+        }
+        last.map(|s| (s, N::one()))
+    }
+
+    /// Returns the point at the given distance along the path.
+    /// Distances outside of the path are clamped to the start and end.
+    pub fn point_at(&self, distance: N) -> Point<N> {
+        match self.locate(distance) {
+            Some((segment, t)) => segment.start + segment.vector() * t,
+            None => self.points.first().copied().unwrap_or_else(Point::zero),
+        }
+    }
+
+    /// Returns the normalized direction of travel at the given distance along the path.
+    pub fn tangent_at(&self, distance: N) -> Vector<N> {
+        match self.locate(distance) {
+            Some((segment, _)) => segment.vector().normalize(),
+            None => Vector::zero(),
+        }
+    }
+
+    /// Returns the normal at the given distance along the path. This faces 90 degrees to the left, matching [Line::normal].
+    pub fn normal_at(&self, distance: N) -> Vector<N> {
+        self.tangent_at(distance).perpendicular_cc()
+    }
+
+    /// Simplifies the path using the Ramer-Douglas-Peucker algorithm.
+    /// Points that lie within `epsilon` of the simplified path are discarded.
+    pub fn simplify(&self, epsilon: N) -> Self {
+        let n = self.points.len();
+        if n < 3 {
+            return self.clone();
+        }
+        let mut keep = vec![false; n];
+        keep[0] = true;
+        keep[n - 1] = true;
+        let mut stack = vec![(0, n - 1)];
+
+        while let Some((first, last)) = stack.pop() {
+            let line = Line::new(self.points[first], self.points[last]);
+            let mut max_dist = N::zero();
+            let mut index = first;
+            for i in (first + 1)..last {
+                let d = if line.magnitude_squared() == N::zero() {
+                    line.start.distance(&self.points[i])
+                } else {
+                    line.distance(&self.points[i])
+                };
+                if d > max_dist {
+                    max_dist = d;
+                    index = i;
+                }
+            }
+            if max_dist > epsilon {
+                keep[index] = true;
+                stack.push((first, index));
+                stack.push((index, last));
+            }
+        }
+
+        let points = self
+            .points
+            .iter()
+            .zip(keep.iter())
+            .filter(|(_, k)| **k)
+            .map(|(p, _)| *p)
+            .collect();
+        Self::new(points)
+    }
+
+    /// Offsets the path sideways by the given distance. Positive values move the path to the left
+    /// (in the direction of the normal), negative values move it to the right. Corners use mitered joins,
+    /// squared off where the miter would be too long.
+    pub fn offset(&self, distance: N) -> Self {
+        Self::new(offset_points(&self.points, distance, false))
+    }
+
+    /// Strokes the path with the given width, returning the outline as a polygon.
+    pub fn stroke(&self, width: N) -> Polygon<N> {
+        let half = width / (N::one() + N::one());
+        let mut points = self.offset(half).points;
+        let mut right = self.offset(-half).points;
+        right.reverse();
+        points.append(&mut right);
+        Polygon::new(points)
+    }
+}
+
+/// Offsets a chain of points, joining neighbouring segments with miters. Outer corners whose
+/// miter would grow too long are squared off instead, so that the offset never comes closer
+/// than `distance` to the original points.
+/// If `closed` is true, the first and last points are treated as joined.
+pub(crate) fn offset_points<N>(points: &[Point<N>], distance: N, closed: bool) -> Vec<Point<N>>
+where
+    N: PrimaFloat,
+{
+    // Drop repeated points, as they have no direction.
+    let mut pts: Vec<Point<N>> = Vec::with_capacity(points.len());
+    for p in points.iter() {
+        if pts.last() != Some(p) {
+            pts.push(*p);
+        }
+    }
+    if closed && pts.len() > 1 && pts.first() == pts.last() {
+        pts.pop();
+    }
+    let n = pts.len();
+    if n < 2 {
+        return pts;
+    }
+
+    // The longest a miter may grow to, relative to the offset distance.
+    let miter_limit = N::from_u8(4).unwrap();
+    let direction = |a: Point<N>, b: Point<N>| (b - a).normalize();
+
+    let mut out = Vec::with_capacity(n);
+    for i in 0..n {
+        let prev = if i > 0 {
+            Some(direction(pts[i - 1], pts[i]))
+        } else if closed {
+            Some(direction(pts[n - 1], pts[0]))
+        } else {
+            None
+        };
+        let next = if i + 1 < n {
+            Some(direction(pts[i], pts[i + 1]))
+        } else if closed {
+            Some(direction(pts[n - 1], pts[0]))
+        } else {
+            None
+        };
+
+        match (prev, next) {
+            (Some(da), Some(db)) => {
+                let (a, b) = (da.perpendicular_cc(), db.perpendicular_cc());
+                let sum = a + b;
+                // The corner turns away from the offset side, so the offset edges pull apart.
+                let outer = da.cross(&db) * distance <= N::zero();
+                let scale = if sum.is_zero() {
+                    N::infinity()
+                } else {
+                    N::one() / sum.normalize().dot(&b)
+                };
+                if outer && scale > miter_limit {
+                    // Extend both edges by the offset distance and join their ends.
+                    let reach = distance.abs();
+                    out.push(pts[i] + a * distance + da * reach);
+                    out.push(pts[i] + b * distance - db * reach);
+                } else {
+                    out.push(pts[i] + sum.normalize() * (distance * scale));
+                }
+            }
+            (Some(da), None) => out.push(pts[i] + da.perpendicular_cc() * distance),
+            (None, Some(db)) => out.push(pts[i] + db.perpendicular_cc() * distance),
+            (None, None) => out.push(pts[i]),
+        }
+    }
+    out
+}
+
+impl<N> Magnitude<N> for Polyline<N>
+where
+    N: PrimaFloat,
+{
+    /// The total length of the path.
+    fn magnitude(&self) -> N {
+        let mut total = N::zero();
+        for segment in self.segments() {
+            total += segment.magnitude();
+        }
+        total
+    }
+
+    fn magnitude_squared(&self) -> N {
+        self.magnitude().powi(2)
+    }
+}
+
+//=================================================================//
+//========================= POINT =================================//
+//=================================================================//
+
+impl<N> Distance<N, Point<N>> for Polyline<N>
+where
+    N: PrimaFloat,
+{
+    fn distance_squared(&self, point: &Point<N>) -> N {
+        self.nearest_point(point).distance_squared(point)
+    }
+}
+
+impl<N> Nearest<N, Point<N>> for Polyline<N>
+where
+    N: PrimaFloat,
+{
+    fn nearest_point(&self, point: &Point<N>) -> Point<N> {
+        let mut nearest = self.points.first().copied().unwrap_or(*point);
+        let mut best = nearest.distance_squared(point);
+        for segment in self.segments() {
+            if segment.magnitude_squared() == N::zero() {
+                continue;
+            }
+            let p = segment.nearest_point(point);
+            let d = p.distance_squared(point);
+            if d < best {
+                best = d;
+                nearest = p;
+            }
+        }
+        nearest
+    }
+}
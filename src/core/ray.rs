@@ -1,8 +1,13 @@
-use crate::nums::PrimaNum;
+use crate::{
+    nums::{PrimaFloat, PrimaNum},
+    traits::Cross,
+};
 
-use super::{Point, Vector};
+use super::{Line, Point, Vector};
 
 /// A 2D ray with an origin and a direction.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ray<N> {
     /// The origin of the ray.
     pub origin: Point<N>,
@@ -18,4 +23,31 @@ impl<N> Ray<N> where N : PrimaNum {
             direction,
         }
     }
-}
\ No newline at end of file
+}
+
+impl<N> Ray<N>
+where
+    N: PrimaFloat,
+{
+    /// Returns the point along the ray at `t` lengths of its direction.
+    pub fn point_at(&self, t: N) -> Point<N> {
+        self.origin + self.direction * t
+    }
+
+    /// Returns how far along the ray (in lengths of its direction) it crosses the given line, if it does.
+    pub fn line_intersection(&self, line: &Line<N>) -> Option<N> {
+        let edge = line.vector();
+        let denom = self.direction.cross(&edge);
+        if denom == N::zero() {
+            return None;
+        }
+        let offset = line.start - self.origin;
+        let t = offset.cross(&edge) / denom;
+        let u = offset.cross(&self.direction) / denom;
+        if t >= N::zero() && u.is_decimal() {
+            Some(t)
+        } else {
+            None
+        }
+    }
+}
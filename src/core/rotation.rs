@@ -3,19 +3,20 @@ use crate::{
     rotation_impl,
     core::Vector,
 };
-use serde::{Deserialize, Serialize};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-/// A rotation is the equivalent to an unbound [Angle].
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// An unbound rotation in radians, which may turn any number of times in either direction.
+/// Positive rotations run clockwise, so a rotation of π/2 turns up into right.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 pub struct Rotation<N>(N);
 
-/// An angle is bound to the range of [0..2].
+/// A direction in radians, measured clockwise from up and always wrapped to the range [0..2π).
 #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 pub struct Angle<N>(N);
 
 /// A 2x2 angular matrix.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AngleMat<N = f32> {
     /// Top left
     pub m00: N,
@@ -35,11 +36,6 @@ impl<N> Rotation<N>
 where
     N: PrimaNum,
 {
-    /// Creates a new angle from a radian value.
-    pub fn new(a: N) -> Self {
-        Rotation(a)
-    }
-
     /// Returns true if clockwise.
     pub fn clockwise(&self) -> bool {
         self.0 > N::zero()
@@ -51,10 +47,44 @@ where
     }
 }
 
-impl<N> Angle<N> {
-    /// Creates a new angle from a radian value.
-    pub fn new(a: N) -> Self {
-        Angle(a)
+impl<N> Rotation<N>
+where
+    N: PrimaFloat,
+{
+    /// Creates a new rotation from radians.
+    pub fn from_radians(rads: N) -> Self {
+        Rotation(rads)
+    }
+
+    /// Returns the same rotation wrapped to the range [0..2π).
+    pub fn normalized(&self) -> Self {
+        Rotation(normalize_radians(self.0))
+    }
+
+    /// Returns the same rotation wrapped to the range (-π..π].
+    pub fn normalized_signed(&self) -> Self {
+        Rotation(normalize_radians_signed(self.0))
+    }
+
+    /// Interpolates linearly from self to other. Unlike [Angle::lerp], this does not take
+    /// the shortest arc, so lerping from 0 to 4π spins around twice.
+    pub fn lerp(&self, other: &Self, t: N) -> Self {
+        Rotation(self.0.lerp(other.0, t))
+    }
+}
+
+impl<N> Angle<N>
+where
+    N: PrimaFloat,
+{
+    /// Creates a new angle from radians, wrapping it to the range [0..2π).
+    pub fn from_radians(rads: N) -> Self {
+        Angle(normalize_radians(rads))
+    }
+
+    /// Interpolates from self to other along the shortest arc between them.
+    pub fn lerp(&self, other: &Self, t: N) -> Self {
+        self.lerp_shortest(other, t)
     }
 }
 
@@ -72,13 +102,51 @@ impl<N> AngleMat<N> {
 //========================= CONVERSIONS =======================//
 //=============================================================//
 
-/// Clamps a value to the range of [0..2], applying overflow.
-pub fn clamp_radians<N>(a: N) -> N
+/// Wraps radians to the range [0..2π).
+pub fn normalize_radians<N>(a: N) -> N
+where
+    N: PrimaFloat,
+{
+    let tau = N::pi() + N::pi();
+    let wrapped = ((a % tau) + tau) % tau;
+    // Tiny negative inputs can round up to exactly 2π.
+    if wrapped >= tau {
+        N::zero()
+    } else {
+        wrapped
+    }
+}
+
+/// Wraps radians to the range (-π..π].
+pub fn normalize_radians_signed<N>(a: N) -> N
+where
+    N: PrimaFloat,
+{
+    let wrapped = normalize_radians(a);
+    if wrapped > N::pi() {
+        wrapped - (N::pi() + N::pi())
+    } else {
+        wrapped
+    }
+}
+
+/// Returns the sine and co-sine of an angle, with results at the quarter turns snapped to exact values.
+fn snapped_sin_cos<N>(rads: N) -> (N, N)
 where
     N: PrimaFloat,
 {
-    let b = N::one() + N::one();
-    ((a % b) + b) % b
+    let snap = |v: N| {
+        let tolerance = N::epsilon() * N::from_u8(4).unwrap();
+        if v.abs() <= tolerance {
+            N::zero()
+        } else if (v.abs() - N::one()).abs() <= tolerance {
+            N::one().copysign(v)
+        } else {
+            v
+        }
+    };
+    let (s, c) = rads.sin_cos();
+    (snap(s), snap(c))
 }
 
 impl<N> From<Rotation<N>> for Angle<N>
@@ -86,7 +154,7 @@ where
     N: PrimaFloat,
 {
     fn from(r: Rotation<N>) -> Self {
-        Angle(clamp_radians(r.0))
+        Angle::from_radians(r.0)
     }
 }
 
@@ -104,7 +172,7 @@ where
     N: PrimaFloat,
 {
     fn add_assign(&mut self, rhs: Rotation<N>) {
-        *self = Angle(clamp_radians(self.0 + rhs.0));
+        *self = Angle::from_radians(self.0 + rhs.0);
     }
 }
 
@@ -115,7 +183,7 @@ where
     type Output = Self;
 
     fn add(self, rhs: Rotation<N>) -> Self {
-        Angle(clamp_radians(self.0 + rhs.0))
+        Angle::from_radians(self.0 + rhs.0)
     }
 }
 
@@ -124,7 +192,7 @@ where
     N: PrimaFloat,
 {
     fn sub_assign(&mut self, rhs: Rotation<N>) {
-        *self = Angle(clamp_radians(self.0 - rhs.0));
+        *self = Angle::from_radians(self.0 - rhs.0);
     }
 }
 
@@ -135,6 +203,52 @@ where
     type Output = Self;
 
     fn sub(self, rhs: Rotation<N>) -> Self {
-        Angle(clamp_radians(self.0 - rhs.0))
+        Angle::from_radians(self.0 - rhs.0)
+    }
+}
+
+//=============================================================//
+//=========================== SERDE ===========================//
+//=============================================================//
+
+/// Rotations are stored on disk as a single number of degrees. See [crate::serde_angle] for other units.
+#[cfg(feature = "serde")]
+impl<N> serde::Serialize for Rotation<N>
+where
+    N: PrimaFloat + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde_angle::degrees::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, N> serde::Deserialize<'de> for Rotation<N>
+where
+    N: PrimaFloat + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::serde_angle::degrees::deserialize(deserializer)
+    }
+}
+
+/// Angles are stored on disk as a single number of degrees, and are clamped to [0..360) when read back.
+#[cfg(feature = "serde")]
+impl<N> serde::Serialize for Angle<N>
+where
+    N: PrimaFloat + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde_angle::degrees::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, N> serde::Deserialize<'de> for Angle<N>
+where
+    N: PrimaFloat + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::serde_angle::degrees::deserialize(deserializer)
     }
 }
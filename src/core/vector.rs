@@ -1,15 +1,17 @@
-use serde::{Deserialize, Serialize};
-
 use crate::{
     core::{Angle, AngleMat, Point, Rotation},
     nums::{PrimaFloat, PrimaNum},
     traits::{Cross, Magnitude},
     xy_impl,
 };
-use std::ops::{AddAssign, Neg, Sub, SubAssign};
+use std::{
+    iter::Sum,
+    ops::{AddAssign, Neg, Sub, SubAssign},
+};
 
 /// A vector in 2 dimensions.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector<N> {
     /// The vector's x component.
     pub x: N,
@@ -83,6 +85,22 @@ where
             y: N::zero(),
         }
     }
+
+    /// Returns the smaller of each component.
+    pub fn min(&self, other: &Self) -> Self {
+        Vector {
+            x: if other.x < self.x { other.x } else { self.x },
+            y: if other.y < self.y { other.y } else { self.y },
+        }
+    }
+
+    /// Returns the larger of each component.
+    pub fn max(&self, other: &Self) -> Self {
+        Vector {
+            x: if other.x > self.x { other.x } else { self.x },
+            y: if other.y > self.y { other.y } else { self.y },
+        }
+    }
 }
 
 impl<N> Vector<N>
@@ -90,6 +108,9 @@ where
     N: PrimaFloat,
 {
     /// Normalize the vector.
+    ///
+    /// A zero length vector has no direction, so normalizing one produces NaN components.
+    /// Use [Vector::try_normalize] when the input may be zero.
     pub fn normalize(self) -> Self {
         let mag = self.magnitude();
         Self {
@@ -98,52 +119,112 @@ where
         }
     }
 
-    /// Lerps from self to other.
-    pub fn lerp(&self, other: Self, t: N) -> Self {
+    /// Normalize the vector, returning None if it has no length.
+    pub fn try_normalize(self) -> Option<Self> {
         let mag = self.magnitude();
-        let mag_other = other.magnitude();
-        let mag_lerp = mag.lerp(mag_other, t);
-        let mag_lerp_inv = N::one() / mag_lerp;
+        if mag > N::zero() && mag.is_finite() {
+            Some(self / mag)
+        } else {
+            None
+        }
+    }
+
+    /// Lerps each component from self to other.
+    pub fn lerp(&self, other: Self, t: N) -> Self {
         Self {
-            x: self.x * mag_lerp_inv,
-            y: self.y * mag_lerp_inv,
+            x: self.x.lerp(other.x, t),
+            y: self.y.lerp(other.y, t),
         }
     }
 
-    /// Converts the vector to a rotation.
-    pub fn as_rotation(&self) -> Rotation<N> {
-        if self.x == N::zero() {
-            if self.y >= N::zero() {
-                Rotation::new(N::zero())
-            } else {
-                Rotation::new(N::one())
+    /// Spherically interpolates from self to other, turning the direction along the shortest
+    /// arc while lerping the length. Falls back to [Vector::lerp] if either vector is zero.
+    pub fn slerp(&self, other: Self, t: N) -> Self {
+        match (self.try_normalize(), other.try_normalize()) {
+            (Some(from), Some(_)) => {
+                let rotation = self.angle_between(&other) * t;
+                let mag = self.magnitude().lerp(other.magnitude(), t);
+                from * rotation * mag
             }
+            _ => self.lerp(other, t),
+        }
+    }
+
+    /// Returns the distance between the tips of two vectors.
+    pub fn distance_to(&self, other: &Self) -> N {
+        (*other - *self).magnitude()
+    }
+
+    /// Returns the part of self that points along other. Projecting onto a zero vector
+    /// returns zero.
+    pub fn project_onto(&self, other: &Self) -> Self {
+        let mag_sq = other.magnitude_squared();
+        if mag_sq == N::zero() {
+            return Self::zero();
+        }
+        *other * (self.dot(other) / mag_sq)
+    }
+
+    /// Returns the part of self perpendicular to other, so that the projection and rejection
+    /// add back up to self.
+    pub fn reject_from(&self, other: &Self) -> Self {
+        *self - self.project_onto(other)
+    }
+
+    /// Reflects the vector off a surface with the given normal, which should be normalized.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        let two = N::one() + N::one();
+        *self - *normal * (two * self.dot(normal))
+    }
+
+    /// Refracts the vector through a surface with the given normal, where `eta` is the ratio of
+    /// the refractive indices being left and entered. Both self and the normal should be
+    /// normalized. Returns None on total internal reflection.
+    pub fn refract(&self, normal: &Self, eta: N) -> Option<Self> {
+        let d = self.dot(normal);
+        let k = N::one() - eta * eta * (N::one() - d * d);
+        if k < N::zero() {
+            return None;
+        }
+        Some(*self * eta - *normal * (eta * d + k.sqrt()))
+    }
+
+    /// Scales the vector so its length lies between min and max, keeping its direction.
+    /// A zero vector is returned unchanged.
+    pub fn clamp_length(&self, min: N, max: N) -> Self {
+        let mag = self.magnitude();
+        if mag == N::zero() {
+            *self
+        } else if mag < min {
+            *self * (min / mag)
+        } else if mag > max {
+            *self * (max / mag)
         } else {
-            if self.y == N::zero() {
-                let half = N::one() / (N::one() + N::one());
-                if self.x > N::zero() {
-                    Rotation::new(half)
-                } else {
-                    Rotation::new(N::one() + half)
-                }
-            } else {
-                let mut ret = (self.x / self.y).atan();
-
-                if self.y < N::zero() && self.x < N::zero() {
-                    // Quadrant 3
-                    ret += N::one() * N::pi();
-                } else if self.x < N::zero() {
-                    // Quadrant 2
-                    ret += (N::one() + N::one()) * N::pi();
-                } else if self.y < N::zero() {
-                    // Quadrant 4
-                    ret += N::one() * N::pi();
-                }
-                Rotation::from_radians_pi(ret)
-            }
+            *self
         }
     }
 
+    /// Returns the vector with each component made positive.
+    pub fn abs(&self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Returns the vector with each component rounded down.
+    pub fn floor(&self) -> Self {
+        Self::new(self.x.floor(), self.y.floor())
+    }
+
+    /// Returns the signed rotation from self to other, in (-π, π], positive when other lies
+    /// clockwise of self.
+    pub fn angle_between(&self, other: &Self) -> Rotation<N> {
+        Rotation::from_radians((N::zero() - self.cross(other)).atan2(self.dot(other)))
+    }
+
+    /// Converts the vector to a rotation, measured clockwise from up.
+    pub fn as_rotation(&self) -> Rotation<N> {
+        Rotation::from_vector(*self)
+    }
+
     /// Converts the vector to an angle.
     pub fn as_angle(&self) -> Angle<N> {
         self.as_rotation().into()
@@ -154,7 +235,7 @@ where
         let d = self.dot(other);
         let cos_theta = d / (self.magnitude() * other.magnitude());
         let theta = cos_theta.acos();
-        Angle::from_radians_pi(theta)
+        Angle::from_radians(theta)
     }
 
     /// Gets the rotation from self to other.
@@ -219,3 +300,37 @@ where
         self.y -= rhs.y;
     }
 }
+
+impl<N> Sum for Vector<N>
+where
+    N: PrimaNum,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<'a, N> Sum<&'a Vector<N>> for Vector<N>
+where
+    N: PrimaNum,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + *b)
+    }
+}
+
+macro_rules! scalar_mul_impl {
+    ($($N: ty),*) => {
+        $(
+            impl Mul<Vector<$N>> for $N {
+                type Output = Vector<$N>;
+
+                fn mul(self, rhs: Vector<$N>) -> Vector<$N> {
+                    rhs * self
+                }
+            }
+        )*
+    };
+}
+
+scalar_mul_impl!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
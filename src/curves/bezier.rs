@@ -0,0 +1,296 @@
+use crate::{
+    core::{Line, Point, Polyline, Vector},
+    nums::{PrimaFloat, PrimaNum},
+    shapes::Aabr,
+    traits::{Curve, Distance, Magnitude, Nearest},
+};
+
+/// A bezier curve defined by its control points. Quadratic curves have three points and cubic curves have four,
+/// although any number of points (and so any degree of curve) is supported.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bezier<N> {
+    /// The control points of the curve, from start to end.
+    pub points: Vec<Point<N>>,
+}
+
+impl<N> Bezier<N>
+where
+    N: PrimaNum,
+{
+    /// Creates a new bezier curve from a list of control points.
+    pub fn new(points: Vec<Point<N>>) -> Self {
+        Self { points }
+    }
+
+    /// Creates a quadratic bezier curve.
+    pub fn quadratic(start: Point<N>, control: Point<N>, end: Point<N>) -> Self {
+        Self::new(vec![start, control, end])
+    }
+
+    /// Creates a cubic bezier curve.
+    pub fn cubic(start: Point<N>, control_a: Point<N>, control_b: Point<N>, end: Point<N>) -> Self {
+        Self::new(vec![start, control_a, control_b, end])
+    }
+
+    /// The degree of the curve. Quadratic curves are degree 2, and cubic curves are degree 3.
+    pub fn degree(&self) -> usize {
+        self.points.len().saturating_sub(1)
+    }
+
+    /// The first control point, or the origin if the curve has no points.
+    pub fn start(&self) -> Point<N> {
+        self.points.first().copied().unwrap_or_else(Point::zero)
+    }
+
+    /// The last control point, or the origin if the curve has no points.
+    pub fn end(&self) -> Point<N> {
+        self.points.last().copied().unwrap_or_else(Point::zero)
+    }
+}
+
+impl<N> Bezier<N>
+where
+    N: PrimaFloat,
+{
+    /// Returns the derivative curve (hodograph), which is a bezier curve of one lower degree.
+    pub fn hodograph(&self) -> Self {
+        let degree = N::from_usize(self.degree()).unwrap();
+        let points = self
+            .points
+            .windows(2)
+            .map(|w| ((w[1] - w[0]) * degree).as_point())
+            .collect();
+        Self::new(points)
+    }
+
+    /// Splits the curve at `t`, returning the two halves.
+    pub fn split(&self, t: N) -> (Self, Self) {
+        let mut left = Vec::with_capacity(self.points.len());
+        let mut right = Vec::with_capacity(self.points.len());
+        let mut working = self.points.clone();
+        while !working.is_empty() {
+            left.push(working[0]);
+            right.push(working[working.len() - 1]);
+            working = working
+                .windows(2)
+                .map(|w| w[0] + (w[1] - w[0]) * t)
+                .collect();
+        }
+        right.reverse();
+        (Self::new(left), Self::new(right))
+    }
+
+    /// Returns the tight bounding rectangle of the curve. Curves above cubic
+    /// fall back to the bounds of their control points.
+    pub fn bounding_rect(&self) -> Aabr<N> {
+        let mut ts = vec![N::zero(), N::one()];
+        if self.degree() <= 3 {
+            let d = self.hodograph().points;
+            ts.extend(derivative_roots(&d.iter().map(|p| p.x).collect::<Vec<_>>()));
+            ts.extend(derivative_roots(&d.iter().map(|p| p.y).collect::<Vec<_>>()));
+        }
+        let candidates: Vec<Point<N>> = if self.degree() <= 3 {
+            ts.into_iter().map(|t| self.point_at(t)).collect()
+        } else {
+            self.points.clone()
+        };
+        bounds_of(&candidates)
+    }
+
+    /// Returns true if every control point lies within `tolerance` of the chord.
+    fn is_flat(&self, tolerance: N) -> bool {
+        if self.points.len() < 3 {
+            return true;
+        }
+        let chord = Line::new(self.start(), self.end());
+        let inner = &self.points[1..self.points.len() - 1];
+        if chord.magnitude_squared() == N::zero() {
+            return inner.iter().all(|p| p.distance(&chord.start) <= tolerance);
+        }
+        inner.iter().all(|p| chord.distance(p) <= tolerance)
+    }
+
+    /// Returns the `t` value of the point on the curve nearest to the given point.
+    pub fn nearest_t(&self, point: &Point<N>) -> N {
+        if self.points.len() < 2 {
+            return N::zero();
+        }
+        // Coarse sampling to find the right neighbourhood, then refine with Newton's method.
+        let samples = 16 * self.degree();
+        let step = N::one() / N::from_usize(samples).unwrap();
+        let mut best_t = N::zero();
+        let mut best = N::infinity();
+        for i in 0..=samples {
+            let t = step * N::from_usize(i).unwrap();
+            let d = self.point_at(t).distance_squared(point);
+            if d < best {
+                best = d;
+                best_t = t;
+            }
+        }
+
+        let first = self.hodograph();
+        let second = first.hodograph();
+        let mut t = best_t;
+        for _ in 0..8 {
+            let offset = self.point_at(t) - *point;
+            let d1 = first.point_at(t).as_vector();
+            let d2 = if second.points.is_empty() {
+                Vector::zero()
+            } else {
+                second.point_at(t).as_vector()
+            };
+            let numerator = offset.dot(&d1);
+            let denominator = d1.dot(&d1) + offset.dot(&d2);
+            if denominator == N::zero() {
+                break;
+            }
+            t = (t - numerator / denominator).clamp_01();
+        }
+        if self.point_at(t).distance_squared(point) <= best {
+            t
+        } else {
+            best_t
+        }
+    }
+}
+
+impl<N> Curve<N> for Bezier<N>
+where
+    N: PrimaFloat,
+{
+    fn point_at(&self, t: N) -> Point<N> {
+        // De Casteljau's algorithm.
+        let mut working = self.points.clone();
+        while working.len() > 1 {
+            working = working
+                .windows(2)
+                .map(|w| w[0] + (w[1] - w[0]) * t)
+                .collect();
+        }
+        working.first().copied().unwrap_or_else(Point::zero)
+    }
+
+    fn derivative_at(&self, t: N) -> Vector<N> {
+        if self.points.len() < 2 {
+            return Vector::zero();
+        }
+        self.hodograph().point_at(t).as_vector()
+    }
+
+    fn flatten(&self, tolerance: N) -> Polyline<N> {
+        if self.points.len() < 2 {
+            return Polyline::new(self.points.clone());
+        }
+        let mut line = Polyline::new(vec![self.start()]);
+        flatten_into(self, tolerance, 0, &mut line);
+        line
+    }
+}
+
+/// Recursively subdivides the curve until each piece is flat enough to be a single segment.
+fn flatten_into<N>(curve: &Bezier<N>, tolerance: N, depth: usize, out: &mut Polyline<N>)
+where
+    N: PrimaFloat,
+{
+    if depth >= 16 || curve.is_flat(tolerance) {
+        out.push(curve.end());
+        return;
+    }
+    let (a, b) = curve.split(N::one() / (N::one() + N::one()));
+    flatten_into(&a, tolerance, depth + 1, out);
+    flatten_into(&b, tolerance, depth + 1, out);
+}
+
+/// Finds the roots (within [0..1]) of a one dimensional bezier of degree 2 or lower.
+fn derivative_roots<N>(q: &[N]) -> Vec<N>
+where
+    N: PrimaFloat,
+{
+    let two = N::one() + N::one();
+    let roots = match q.len() {
+        2 => {
+            let denom = q[0] - q[1];
+            if denom == N::zero() {
+                vec![]
+            } else {
+                vec![q[0] / denom]
+            }
+        }
+        3 => {
+            let a = q[0] - two * q[1] + q[2];
+            let b = two * (q[1] - q[0]);
+            let c = q[0];
+            if a == N::zero() {
+                if b == N::zero() {
+                    vec![]
+                } else {
+                    vec![-c / b]
+                }
+            } else {
+                let disc = b * b - two * two * a * c;
+                if disc < N::zero() {
+                    vec![]
+                } else {
+                    let root = disc.sqrt();
+                    vec![(-b + root) / (two * a), (-b - root) / (two * a)]
+                }
+            }
+        }
+        _ => vec![],
+    };
+    roots.into_iter().filter(|t| t.is_decimal()).collect()
+}
+
+/// Returns the bounding rectangle of a set of points.
+pub(crate) fn bounds_of<N>(points: &[Point<N>]) -> Aabr<N>
+where
+    N: PrimaFloat,
+{
+    let mut min = Point::new(N::infinity(), N::infinity());
+    let mut max = Point::new(N::neg_infinity(), N::neg_infinity());
+    for p in points.iter() {
+        min.x = min.x.min(p.x);
+        min.y = min.y.min(p.y);
+        max.x = max.x.max(p.x);
+        max.y = max.y.max(p.y);
+    }
+    Aabr::new(min, max)
+}
+
+impl<N> Magnitude<N> for Bezier<N>
+where
+    N: PrimaFloat,
+{
+    /// The arc length of the curve.
+    fn magnitude(&self) -> N {
+        self.length_to(N::one())
+    }
+
+    fn magnitude_squared(&self) -> N {
+        self.magnitude().powi(2)
+    }
+}
+
+//=================================================================//
+//========================= POINT =================================//
+//=================================================================//
+
+impl<N> Distance<N, Point<N>> for Bezier<N>
+where
+    N: PrimaFloat,
+{
+    fn distance_squared(&self, point: &Point<N>) -> N {
+        self.nearest_point(point).distance_squared(point)
+    }
+}
+
+impl<N> Nearest<N, Point<N>> for Bezier<N>
+where
+    N: PrimaFloat,
+{
+    fn nearest_point(&self, point: &Point<N>) -> Point<N> {
+        self.point_at(self.nearest_t(point))
+    }
+}
@@ -0,0 +1,203 @@
+use super::{bounds_of, Bezier};
+use crate::{
+    core::{Point, Polyline, Vector},
+    nums::{PrimaFloat, PrimaNum},
+    shapes::Aabr,
+    traits::{Curve, Distance, Magnitude, Nearest},
+};
+
+/// A uniform Catmull-Rom spline that passes through each of its points.
+/// The whole spline is evaluated over `t` in the range of [0..1], with each span taking an equal share.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatmullRom<N> {
+    /// The points the spline passes through.
+    pub points: Vec<Point<N>>,
+    /// A control point before the first point, which shapes the first span without being
+    /// passed through. If `None`, the second point is mirrored through the first instead.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub before: Option<Point<N>>,
+    /// A control point after the last point, which shapes the last span without being passed
+    /// through. If `None`, the second to last point is mirrored through the last instead.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub after: Option<Point<N>>,
+}
+
+impl<N> CatmullRom<N>
+where
+    N: PrimaNum,
+{
+    /// Creates a new spline through the given points.
+    pub fn new(points: Vec<Point<N>>) -> Self {
+        Self {
+            points,
+            before: None,
+            after: None,
+        }
+    }
+
+    /// The number of spans between points.
+    pub fn span_count(&self) -> usize {
+        self.points.len().saturating_sub(1)
+    }
+}
+
+impl<N> CatmullRom<N>
+where
+    N: PrimaFloat,
+{
+    /// Converts the spline into a chain of cubic bezier curves, one per span.
+    /// Without [CatmullRom::before] and [CatmullRom::after], the end points are mirrored so that
+    /// the spline reaches both ends.
+    pub fn to_beziers(&self) -> Vec<Bezier<N>> {
+        let n = self.points.len();
+        let six = N::from_u8(6).unwrap();
+        (0..self.span_count())
+            .map(|i| {
+                let p1 = self.points[i];
+                let p2 = self.points[i + 1];
+                let p0 = if i > 0 {
+                    self.points[i - 1]
+                } else {
+                    self.before.unwrap_or_else(|| p1 + (p1 - p2))
+                };
+                let p3 = if i + 2 < n {
+                    self.points[i + 2]
+                } else {
+                    self.after.unwrap_or_else(|| p2 + (p2 - p1))
+                };
+                Bezier::cubic(p1, p1 + (p2 - p0) / six, p2 + (p1 - p3) / six, p2)
+            })
+            .collect()
+    }
+
+    /// Splits a global `t` into a span index and the local `t` within that span.
+    fn span_at(&self, t: N) -> (usize, N) {
+        let spans = self.span_count();
+        let scaled = t.clamp_01() * N::from_usize(spans).unwrap();
+        let index = scaled.floor().to_usize().unwrap_or(0).min(spans - 1);
+        (index, scaled - N::from_usize(index).unwrap())
+    }
+
+    /// Splits the spline at the given point index, returning two splines that share that point.
+    /// Each half keeps the neighbouring point of the other as a control point, so both still
+    /// follow the original curve. Splitting an empty spline gives two empty splines.
+    pub fn split(&self, index: usize) -> (Self, Self) {
+        if self.points.is_empty() {
+            return (Self::new(Vec::new()), Self::new(Vec::new()));
+        }
+        let index = index.min(self.points.len() - 1);
+        let left = Self {
+            points: self.points[..=index].to_vec(),
+            before: self.before,
+            after: self.points.get(index + 1).copied().or(self.after),
+        };
+        let right = Self {
+            points: self.points[index..].to_vec(),
+            before: index.checked_sub(1).map(|i| self.points[i]).or(self.before),
+            after: self.after,
+        };
+        (left, right)
+    }
+
+    /// Returns the tight bounding rectangle of the spline.
+    pub fn bounding_rect(&self) -> Aabr<N> {
+        let mut corners = Vec::new();
+        for bezier in self.to_beziers() {
+            let bb = bezier.bounding_rect();
+            corners.push(bb.min);
+            corners.push(bb.max);
+        }
+        if corners.is_empty() {
+            corners = self.points.clone();
+        }
+        bounds_of(&corners)
+    }
+}
+
+impl<N> Curve<N> for CatmullRom<N>
+where
+    N: PrimaFloat,
+{
+    fn point_at(&self, t: N) -> Point<N> {
+        if self.points.len() < 2 {
+            return self.points.first().copied().unwrap_or_else(Point::zero);
+        }
+        let (index, local) = self.span_at(t);
+        self.to_beziers()[index].point_at(local)
+    }
+
+    fn derivative_at(&self, t: N) -> Vector<N> {
+        if self.points.len() < 2 {
+            return Vector::zero();
+        }
+        let (index, local) = self.span_at(t);
+        // Scale by the span count, as each span only covers a fraction of the global `t`.
+        self.to_beziers()[index].derivative_at(local) * N::from_usize(self.span_count()).unwrap()
+    }
+
+    fn flatten(&self, tolerance: N) -> Polyline<N> {
+        let mut line = Polyline::new(self.points.first().copied().into_iter().collect());
+        for bezier in self.to_beziers() {
+            let mut points = bezier.flatten(tolerance).points;
+            line.points.extend(points.drain(1..));
+        }
+        line
+    }
+}
+
+impl<N> Magnitude<N> for CatmullRom<N>
+where
+    N: PrimaFloat,
+{
+    /// The arc length of the spline.
+    fn magnitude(&self) -> N {
+        let mut total = N::zero();
+        for bezier in self.to_beziers() {
+            total += bezier.magnitude();
+        }
+        total
+    }
+
+    fn magnitude_squared(&self) -> N {
+        self.magnitude().powi(2)
+    }
+}
+
+//=================================================================//
+//========================= POINT =================================//
+//=================================================================//
+
+impl<N> Distance<N, Point<N>> for CatmullRom<N>
+where
+    N: PrimaFloat,
+{
+    fn distance_squared(&self, point: &Point<N>) -> N {
+        self.nearest_point(point).distance_squared(point)
+    }
+}
+
+impl<N> Nearest<N, Point<N>> for CatmullRom<N>
+where
+    N: PrimaFloat,
+{
+    fn nearest_point(&self, point: &Point<N>) -> Point<N> {
+        let mut nearest = self.points.first().copied().unwrap_or(*point);
+        let mut best = nearest.distance_squared(point);
+        for bezier in self.to_beziers() {
+            let p = bezier.nearest_point(point);
+            let d = p.distance_squared(point);
+            if d < best {
+                best = d;
+                nearest = p;
+            }
+        }
+        nearest
+    }
+}
@@ -0,0 +1,5 @@
+mod bezier;
+mod catmull_rom;
+
+pub use bezier::*;
+pub use catmull_rom::*;
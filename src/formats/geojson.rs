@@ -0,0 +1,181 @@
+use serde_json::{json, Map, Number, Value};
+
+use super::{GeoError, Geometry, Region};
+use crate::{
+    core::{Point, Polyline},
+    nums::PrimaFloat,
+};
+
+/// A GeoJSON feature, which pairs a geometry with arbitrary properties.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Feature<N> {
+    /// The shape of the feature.
+    pub geometry: Geometry<N>,
+    /// Any properties attached to the feature.
+    pub properties: Map<String, Value>,
+}
+
+impl<N> Feature<N> {
+    /// Creates a feature without any properties.
+    pub fn new<G: Into<Geometry<N>>>(geometry: G) -> Self {
+        Self {
+            geometry: geometry.into(),
+            properties: Map::new(),
+        }
+    }
+
+    /// Attaches a property to the feature.
+    pub fn with_property<S: Into<String>, V: Into<Value>>(mut self, name: S, value: V) -> Self {
+        self.properties.insert(name.into(), value.into());
+        self
+    }
+}
+
+impl<N> Geometry<N>
+where
+    N: PrimaFloat,
+{
+    /// Converts the geometry into a GeoJSON geometry object.
+    pub fn to_geojson(&self) -> Value {
+        let (kind, coordinates) = match self {
+            Geometry::Point(point) => ("Point", position(point)),
+            Geometry::LineString(line) => ("LineString", positions(&line.points)),
+            Geometry::Polygon(region) => ("Polygon", rings(region)),
+            Geometry::MultiPolygon(regions) => ("MultiPolygon", Value::Array(regions.iter().map(rings).collect())),
+        };
+        json!({ "type": kind, "coordinates": coordinates })
+    }
+
+    /// Reads a geometry from a GeoJSON geometry object.
+    pub fn from_geojson(json: &str) -> Result<Self, GeoError> {
+        Self::from_geojson_value(&serde_json::from_str(json)?)
+    }
+
+    /// Reads a geometry from an already parsed GeoJSON geometry object.
+    pub fn from_geojson_value(value: &Value) -> Result<Self, GeoError> {
+        let kind = value.get("type").and_then(Value::as_str).unwrap_or_default();
+        let coordinates = value
+            .get("coordinates")
+            .ok_or_else(|| invalid(&format!("{} has no coordinates", kind)))?;
+        match kind {
+            "Point" => Ok(Geometry::Point(read_position(coordinates)?)),
+            "LineString" => Ok(Geometry::LineString(Polyline::new(read_positions(coordinates)?))),
+            "Polygon" => Ok(Geometry::Polygon(read_region(coordinates)?)),
+            "MultiPolygon" => Ok(Geometry::MultiPolygon(
+                array(coordinates)?.iter().map(read_region).collect::<Result<_, _>>()?,
+            )),
+            "" => Err(invalid("geometry has no type")),
+            other => Err(invalid(&format!("unsupported geometry type `{}`", other))),
+        }
+    }
+}
+
+impl<N> Feature<N>
+where
+    N: PrimaFloat,
+{
+    /// Converts the feature into a GeoJSON feature object.
+    pub fn to_geojson(&self) -> Value {
+        json!({
+            "type": "Feature",
+            "geometry": self.geometry.to_geojson(),
+            "properties": self.properties,
+        })
+    }
+
+    /// Reads a feature from an already parsed GeoJSON feature object.
+    pub fn from_geojson_value(value: &Value) -> Result<Self, GeoError> {
+        let geometry = value.get("geometry").ok_or_else(|| invalid("feature has no geometry"))?;
+        Ok(Self {
+            geometry: Geometry::from_geojson_value(geometry)?,
+            properties: value
+                .get("properties")
+                .and_then(Value::as_object)
+                .cloned()
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Reads every feature from a GeoJSON document. A bare feature or geometry is read as a single feature.
+pub fn read_features<N>(json: &str) -> Result<Vec<Feature<N>>, GeoError>
+where
+    N: PrimaFloat,
+{
+    let value: Value = serde_json::from_str(json)?;
+    match value.get("type").and_then(Value::as_str) {
+        Some("FeatureCollection") => value
+            .get("features")
+            .map(array)
+            .transpose()?
+            .unwrap_or(&Vec::new())
+            .iter()
+            .map(Feature::from_geojson_value)
+            .collect(),
+        Some("Feature") => Ok(vec![Feature::from_geojson_value(&value)?]),
+        _ => Ok(vec![Feature::new(Geometry::from_geojson_value(&value)?)]),
+    }
+}
+
+/// Writes a list of features as a GeoJSON feature collection.
+pub fn write_features<N>(features: &[Feature<N>]) -> String
+where
+    N: PrimaFloat,
+{
+    let features: Vec<Value> = features.iter().map(Feature::to_geojson).collect();
+    json!({ "type": "FeatureCollection", "features": features }).to_string()
+}
+
+//=============================================================//
+//========================== HELPERS ==========================//
+//=============================================================//
+
+/// Goes through the number's display form, so that f32 values are written without widening noise.
+fn number<N: PrimaFloat>(n: N) -> Value {
+    n.to_string()
+        .parse::<f64>()
+        .ok()
+        .and_then(Number::from_f64)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+fn position<N: PrimaFloat>(point: &Point<N>) -> Value {
+    json!([number(point.x), number(point.y)])
+}
+
+fn positions<N: PrimaFloat>(points: &[Point<N>]) -> Value {
+    Value::Array(points.iter().map(position).collect())
+}
+
+fn rings<N: PrimaFloat>(region: &Region<N>) -> Value {
+    Value::Array(region.rings().iter().map(|r| positions(r)).collect())
+}
+
+fn invalid(message: &str) -> GeoError {
+    GeoError::Invalid(message.to_string())
+}
+
+fn array(value: &Value) -> Result<&Vec<Value>, GeoError> {
+    value.as_array().ok_or_else(|| invalid(&format!("expected an array, found `{}`", value)))
+}
+
+fn read_position<N: PrimaFloat>(value: &Value) -> Result<Point<N>, GeoError> {
+    let coordinate = |v: &Value| v.as_f64().and_then(N::from_f64);
+    match array(value)?.as_slice() {
+        [x, y] | [x, y, _] => match (coordinate(x), coordinate(y)) {
+            (Some(x), Some(y)) => Ok(Point::new(x, y)),
+            _ => Err(invalid(&format!("invalid position `{}`", value))),
+        },
+        _ => Err(invalid(&format!("invalid position `{}`", value))),
+    }
+}
+
+fn read_positions<N: PrimaFloat>(value: &Value) -> Result<Vec<Point<N>>, GeoError> {
+    array(value)?.iter().map(read_position).collect()
+}
+
+fn read_region<N: PrimaFloat>(value: &Value) -> Result<Region<N>, GeoError> {
+    let rings = array(value)?.iter().map(read_positions).collect::<Result<Vec<_>, _>>()?;
+    Region::from_rings(rings).ok_or_else(|| invalid("polygon has no rings"))
+}
@@ -0,0 +1,204 @@
+use std::fmt::Display;
+
+use crate::{
+    core::{Line, Point, Polyline},
+    nums::PrimaFloat,
+    shapes::{Aabr, Obr, Polygon},
+    traits::Flat,
+};
+
+/// A geometry that can be exchanged with GIS tooling through WKT or GeoJSON.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Geometry<N> {
+    /// A single position.
+    Point(Point<N>),
+    /// An open chain of line segments.
+    LineString(Polyline<N>),
+    /// An area, which may have holes.
+    Polygon(Region<N>),
+    /// Several separate areas.
+    MultiPolygon(Vec<Region<N>>),
+}
+
+/// A polygon with optional holes cut out of it.
+///
+/// Rings are stored without repeating the first point at the end; the closing point is added
+/// when writing and removed when reading. When written, the exterior is wound counter-clockwise
+/// and holes clockwise, as GeoJSON recommends.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Region<N> {
+    /// The outer boundary of the region.
+    pub exterior: Polygon<N>,
+    /// Areas cut out of the exterior.
+    pub holes: Vec<Polygon<N>>,
+}
+
+/// An error raised while reading WKT or GeoJSON.
+#[derive(Debug)]
+pub enum GeoError {
+    /// The WKT text could not be parsed.
+    Wkt {
+        /// A description of the problem.
+        message: String,
+        /// The character the problem was found at, starting at 1.
+        column: usize,
+    },
+    /// The GeoJSON text is not valid JSON.
+    Json(serde_json::Error),
+    /// The GeoJSON is valid JSON, but not a supported geometry.
+    Invalid(String),
+}
+
+//=============================================================//
+//======================= IMPLEMENTATIONS =====================//
+//=============================================================//
+
+impl<N> Region<N> {
+    /// Creates a region without holes.
+    pub fn new(exterior: Polygon<N>) -> Self {
+        Self {
+            exterior,
+            holes: Vec::new(),
+        }
+    }
+
+    /// Creates a region with holes.
+    pub fn with_holes(exterior: Polygon<N>, holes: Vec<Polygon<N>>) -> Self {
+        Self { exterior, holes }
+    }
+}
+
+impl<N> Region<N>
+where
+    N: PrimaFloat,
+{
+    /// Returns every ring of the region, closed and wound as GIS formats expect.
+    pub(crate) fn rings(&self) -> Vec<Vec<Point<N>>> {
+        let mut rings = vec![ring(&self.exterior, false)];
+        rings.extend(self.holes.iter().map(|h| ring(h, true)));
+        rings
+    }
+
+    /// Builds a region from closed rings, the first of which is the exterior.
+    pub(crate) fn from_rings(rings: Vec<Vec<Point<N>>>) -> Option<Self> {
+        let mut polygons = rings.into_iter().map(|mut points| {
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+            Polygon::new(points)
+        });
+        let exterior = polygons.next()?;
+        Some(Self::with_holes(exterior, polygons.collect()))
+    }
+}
+
+/// Closes a polygon's ring, winding it clockwise if `clockwise` is set or counter-clockwise otherwise.
+fn ring<N>(polygon: &Polygon<N>, clockwise: bool) -> Vec<Point<N>>
+where
+    N: PrimaFloat,
+{
+    let mut points = polygon.points.clone();
+    if polygon.len() > 2 && polygon.is_clockwise() != clockwise {
+        points.reverse();
+    }
+    if let Some(first) = points.first().copied() {
+        points.push(first);
+    }
+    points
+}
+
+//=============================================================//
+//========================= CONVERSIONS =======================//
+//=============================================================//
+
+impl<N> From<Point<N>> for Geometry<N> {
+    fn from(point: Point<N>) -> Self {
+        Geometry::Point(point)
+    }
+}
+
+impl<N> From<Line<N>> for Geometry<N>
+where
+    N: PrimaFloat,
+{
+    fn from(line: Line<N>) -> Self {
+        Geometry::LineString(Polyline::new(vec![line.start, line.end]))
+    }
+}
+
+impl<N> From<Polyline<N>> for Geometry<N> {
+    fn from(polyline: Polyline<N>) -> Self {
+        Geometry::LineString(polyline)
+    }
+}
+
+impl<N> From<Polygon<N>> for Geometry<N> {
+    fn from(polygon: Polygon<N>) -> Self {
+        Geometry::Polygon(Region::new(polygon))
+    }
+}
+
+impl<N> From<Aabr<N>> for Geometry<N>
+where
+    N: PrimaFloat,
+{
+    fn from(aabr: Aabr<N>) -> Self {
+        Geometry::Polygon(Region::new(Polygon::new(aabr.vertices())))
+    }
+}
+
+impl<N> From<Obr<N>> for Geometry<N>
+where
+    N: PrimaFloat,
+{
+    fn from(obr: Obr<N>) -> Self {
+        Geometry::Polygon(Region::new(Polygon::new(obr.vertices())))
+    }
+}
+
+impl<N> From<Region<N>> for Geometry<N> {
+    fn from(region: Region<N>) -> Self {
+        Geometry::Polygon(region)
+    }
+}
+
+impl<N> From<Vec<Region<N>>> for Geometry<N> {
+    fn from(regions: Vec<Region<N>>) -> Self {
+        Geometry::MultiPolygon(regions)
+    }
+}
+
+impl<N> From<Polygon<N>> for Region<N> {
+    fn from(polygon: Polygon<N>) -> Self {
+        Region::new(polygon)
+    }
+}
+
+//=============================================================//
+//=========================== ERRORS ==========================//
+//=============================================================//
+
+impl From<serde_json::Error> for GeoError {
+    fn from(error: serde_json::Error) -> Self {
+        GeoError::Json(error)
+    }
+}
+
+impl Display for GeoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeoError::Wkt { message, column } => write!(f, "{} at column {}", message, column),
+            GeoError::Json(error) => write!(f, "{}", error),
+            GeoError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for GeoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GeoError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+#[cfg(feature = "gis")]
+mod geojson;
+#[cfg(feature = "gis")]
+mod geometry;
+#[cfg(feature = "scene")]
+mod scene;
+#[cfg(feature = "tiled")]
+mod tiled;
+#[cfg(feature = "gis")]
+mod wkt;
+
+#[cfg(feature = "gis")]
+pub use geojson::*;
+#[cfg(feature = "gis")]
+pub use geometry::*;
+#[cfg(feature = "scene")]
+pub use scene::*;
+#[cfg(feature = "tiled")]
+pub use tiled::*;
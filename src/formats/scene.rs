@@ -0,0 +1,558 @@
+use std::{collections::HashSet, fmt::Display, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{Angle, Line, Point, Polyline, Rotation, Vector},
+    nums::PrimaFloat,
+    shapes::{Aabr, Circle, Obr, Polygon},
+    traits::LocalPosition,
+};
+
+/// The version written by [Scene::to_json]. Files with a newer version are rejected when loading.
+pub const SCENE_VERSION: u32 = 1;
+
+/// A collection of named layers of shapes, which can be saved to and loaded from JSON.
+///
+/// The on-disk layout mirrors these structs directly. Shapes are tagged with a `"type"` field,
+/// transforms may be left out for the identity, and rotations are written in degrees:
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "layers": [{
+///     "name": "walls",
+///     "tags": ["static"],
+///     "items": [{
+///       "name": "door",
+///       "transform": { "translation": { "x": 4.0, "y": 0.0 }, "rotation": 90.0, "scale": 1.0 },
+///       "shape": { "type": "aabr", "min": { "x": 0.0, "y": 0.0 }, "max": { "x": 1.0, "y": 2.0 } }
+///     }]
+///   }]
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "SceneData<N>")]
+#[serde(bound(
+    serialize = "N: PrimaFloat + Serialize",
+    deserialize = "N: PrimaFloat + Deserialize<'de>"
+))]
+pub struct Scene<N> {
+    /// The format version of the scene.
+    pub version: u32,
+    /// The layers of the scene, with unique names.
+    pub layers: Vec<Layer<N>>,
+}
+
+/// A named group of shapes within a [Scene].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "N: PrimaFloat + Serialize",
+    deserialize = "N: PrimaFloat + Deserialize<'de>"
+))]
+pub struct Layer<N> {
+    /// The name of the layer.
+    pub name: String,
+    /// Free-form tags for the whole layer.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The shapes in this layer.
+    #[serde(default)]
+    pub items: Vec<SceneItem<N>>,
+}
+
+/// A single shape within a [Layer], placed in the world by a [Transform].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "ItemData<N>")]
+#[serde(bound(
+    serialize = "N: PrimaFloat + Serialize",
+    deserialize = "N: PrimaFloat + Deserialize<'de>"
+))]
+pub struct SceneItem<N> {
+    /// An optional name for the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Free-form tags for the item.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Places the shape in the world.
+    pub transform: Transform<N>,
+    /// The shape, in local space.
+    pub shape: SceneShape<N>,
+}
+
+/// The shapes that can be stored in a [Scene].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(bound(
+    serialize = "N: PrimaFloat + Serialize",
+    deserialize = "N: PrimaFloat + Deserialize<'de>"
+))]
+pub enum SceneShape<N> {
+    /// An axis-aligned rectangle.
+    Aabr(Aabr<N>),
+    /// A circle.
+    Circle(Circle<N>),
+    /// An orientated rectangle.
+    Obr(Obr<N>),
+    /// A line segment.
+    Line(Line<N>),
+    /// An open chain of line segments.
+    Polyline(Polyline<N>),
+    /// A closed polygon.
+    Polygon(Polygon<N>),
+}
+
+/// Scales, then rotates, then translates a shape from local space into the world.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "N: PrimaFloat + Serialize",
+    deserialize = "N: PrimaFloat + Deserialize<'de>"
+))]
+pub struct Transform<N> {
+    /// The offset applied last.
+    pub translation: Vector<N>,
+    /// The clockwise rotation around the local origin.
+    pub rotation: Rotation<N>,
+    /// The uniform scale around the local origin.
+    pub scale: N,
+}
+
+/// An error raised while loading or saving a [Scene].
+#[derive(Debug)]
+pub enum SceneError {
+    /// The file could not be read or written.
+    Io(io::Error),
+    /// The file is not valid JSON.
+    Syntax {
+        /// A description of the problem.
+        message: String,
+        /// The line the problem was found on, starting at 1.
+        line: usize,
+        /// The column the problem was found at, starting at 1.
+        column: usize,
+    },
+    /// The file is valid JSON, but does not describe a valid scene.
+    Invalid {
+        /// A description of the problem.
+        message: String,
+        /// The line the problem was found on, starting at 1.
+        line: usize,
+        /// The column the problem was found at, starting at 1.
+        column: usize,
+    },
+}
+
+//=============================================================//
+//======================= IMPLEMENTATIONS =====================//
+//=============================================================//
+
+impl<N> Scene<N>
+where
+    N: PrimaFloat + Serialize + for<'de> Deserialize<'de>,
+{
+    /// Creates an empty scene.
+    pub fn new() -> Self {
+        Self {
+            version: SCENE_VERSION,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Adds a layer to the scene, replacing any existing layer with the same name.
+    pub fn add_layer(&mut self, layer: Layer<N>) {
+        match self.layer_mut(&layer.name) {
+            Some(existing) => *existing = layer,
+            None => self.layers.push(layer),
+        }
+    }
+
+    /// Returns the layer with the given name.
+    pub fn layer(&self, name: &str) -> Option<&Layer<N>> {
+        self.layers.iter().find(|l| l.name == name)
+    }
+
+    /// Returns the layer with the given name, mutably.
+    pub fn layer_mut(&mut self, name: &str) -> Option<&mut Layer<N>> {
+        self.layers.iter_mut().find(|l| l.name == name)
+    }
+
+    /// Returns every item across all layers that carries the given tag, either directly or through its layer.
+    pub fn tagged<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a SceneItem<N>> + 'a {
+        self.layers.iter().flat_map(move |layer| {
+            let whole = layer.tags.iter().any(|t| t == tag);
+            layer
+                .items
+                .iter()
+                .filter(move |item| whole || item.tags.iter().any(|t| t == tag))
+        })
+    }
+
+    /// Parses a scene from a JSON string.
+    pub fn from_json(json: &str) -> Result<Self, SceneError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Writes the scene as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("scenes always serialize")
+    }
+
+    /// Loads a scene from a JSON file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SceneError> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Saves the scene to a JSON file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SceneError> {
+        Ok(fs::write(path, self.to_json())?)
+    }
+}
+
+impl<N> Default for Scene<N>
+where
+    N: PrimaFloat + Serialize + for<'de> Deserialize<'de>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> Layer<N> {
+    /// Creates an empty layer.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            tags: Vec::new(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Adds a tag to the layer.
+    pub fn with_tag<S: Into<String>>(mut self, tag: S) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Adds an item to the layer.
+    pub fn push(&mut self, item: SceneItem<N>) {
+        self.items.push(item);
+    }
+
+    /// Returns the first item with the given name.
+    pub fn item(&self, name: &str) -> Option<&SceneItem<N>> {
+        self.items.iter().find(|i| i.name.as_deref() == Some(name))
+    }
+}
+
+impl<N> SceneItem<N>
+where
+    N: PrimaFloat,
+{
+    /// Creates an unnamed item with no transform.
+    pub fn new<S: Into<SceneShape<N>>>(shape: S) -> Self {
+        Self {
+            name: None,
+            tags: Vec::new(),
+            transform: Transform::identity(),
+            shape: shape.into(),
+        }
+    }
+
+    /// Names the item.
+    pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adds a tag to the item.
+    pub fn with_tag<S: Into<String>>(mut self, tag: S) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the item's transform.
+    pub fn with_transform(mut self, transform: Transform<N>) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Returns the shape with its transform applied. Rotated rectangles become [Obr]s.
+    pub fn world_shape(&self) -> SceneShape<N> {
+        let t = &self.transform;
+        let points = |points: &[Point<N>]| points.iter().map(|p| t.apply(*p)).collect();
+        match &self.shape {
+            SceneShape::Aabr(aabr) if t.rotation.is_none() => {
+                SceneShape::Aabr(Aabr::new(t.apply(aabr.min), t.apply(aabr.max)))
+            }
+            SceneShape::Aabr(aabr) => SceneShape::Obr(Obr::new(
+                t.apply(aabr.position()),
+                aabr.extent() * t.scale,
+                Angle::from(t.rotation),
+            )),
+            SceneShape::Circle(circle) => {
+                SceneShape::Circle(Circle::new(t.apply(circle.center), circle.radius * t.scale))
+            }
+            SceneShape::Obr(obr) => SceneShape::Obr(Obr::new(
+                t.apply(obr.center),
+                obr.extent * t.scale,
+                obr.rotation + t.rotation,
+            )),
+            SceneShape::Line(line) => SceneShape::Line(Line::new(t.apply(line.start), t.apply(line.end))),
+            SceneShape::Polyline(polyline) => SceneShape::Polyline(Polyline::new(points(&polyline.points))),
+            SceneShape::Polygon(polygon) => SceneShape::Polygon(Polygon::new(points(&polygon.points))),
+        }
+    }
+}
+
+impl<N> SceneShape<N>
+where
+    N: PrimaFloat,
+{
+    /// Checks that the shape is well formed, returning a description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        let finite = |points: &[Point<N>]| points.iter().all(|p| p.x.is_finite() && p.y.is_finite());
+        match self {
+            SceneShape::Aabr(aabr) => {
+                check(finite(&[aabr.min, aabr.max]), "aabr has a non-finite corner")?;
+                check(aabr.min.x <= aabr.max.x && aabr.min.y <= aabr.max.y, "aabr min is greater than max")
+            }
+            SceneShape::Circle(circle) => {
+                check(finite(&[circle.center]), "circle has a non-finite center")?;
+                check(circle.radius >= N::zero(), "circle radius is negative")
+            }
+            SceneShape::Obr(obr) => {
+                check(finite(&[obr.center]), "obr has a non-finite center")?;
+                check(
+                    obr.extent.width() >= N::zero() && obr.extent.height() >= N::zero(),
+                    "obr extent is negative",
+                )
+            }
+            SceneShape::Line(line) => check(finite(&[line.start, line.end]), "line has a non-finite end"),
+            SceneShape::Polyline(polyline) => {
+                check(finite(&polyline.points), "polyline has a non-finite point")?;
+                check(polyline.len() >= 2, "polyline needs at least 2 points")
+            }
+            SceneShape::Polygon(polygon) => {
+                check(finite(&polygon.points), "polygon has a non-finite point")?;
+                check(polygon.len() >= 3, "polygon needs at least 3 points")
+            }
+        }
+    }
+}
+
+impl<N> Transform<N>
+where
+    N: PrimaFloat,
+{
+    /// Creates a new transform.
+    pub fn new(translation: Vector<N>, rotation: Rotation<N>, scale: N) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// A transform that leaves shapes where they are.
+    pub fn identity() -> Self {
+        Self::new(Vector::zero(), Rotation::zero(), N::one())
+    }
+
+    /// A transform that only moves shapes.
+    pub fn translation(translation: Vector<N>) -> Self {
+        Self::new(translation, Rotation::zero(), N::one())
+    }
+
+    /// Moves a point from local space into the world.
+    pub fn apply(&self, point: Point<N>) -> Point<N> {
+        ((point * self.scale) * self.rotation) + self.translation
+    }
+}
+
+impl<N> Default for Transform<N>
+where
+    N: PrimaFloat,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+fn check(condition: bool, message: &str) -> Result<(), String> {
+    if condition {
+        Ok(())
+    } else {
+        Err(message.to_string())
+    }
+}
+
+//=============================================================//
+//========================= CONVERSIONS =======================//
+//=============================================================//
+
+impl<N> From<Aabr<N>> for SceneShape<N> {
+    fn from(aabr: Aabr<N>) -> Self {
+        SceneShape::Aabr(aabr)
+    }
+}
+
+impl<N> From<Circle<N>> for SceneShape<N> {
+    fn from(circle: Circle<N>) -> Self {
+        SceneShape::Circle(circle)
+    }
+}
+
+impl<N> From<Obr<N>> for SceneShape<N> {
+    fn from(obr: Obr<N>) -> Self {
+        SceneShape::Obr(obr)
+    }
+}
+
+impl<N> From<Line<N>> for SceneShape<N> {
+    fn from(line: Line<N>) -> Self {
+        SceneShape::Line(line)
+    }
+}
+
+impl<N> From<Polyline<N>> for SceneShape<N> {
+    fn from(polyline: Polyline<N>) -> Self {
+        SceneShape::Polyline(polyline)
+    }
+}
+
+impl<N> From<Polygon<N>> for SceneShape<N> {
+    fn from(polygon: Polygon<N>) -> Self {
+        SceneShape::Polygon(polygon)
+    }
+}
+
+impl From<io::Error> for SceneError {
+    fn from(error: io::Error) -> Self {
+        SceneError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for SceneError {
+    fn from(error: serde_json::Error) -> Self {
+        let (line, column) = (error.line(), error.column());
+        match error.classify() {
+            serde_json::error::Category::Io => SceneError::Io(error.into()),
+            serde_json::error::Category::Data => SceneError::Invalid {
+                message: strip_position(&error),
+                line,
+                column,
+            },
+            _ => SceneError::Syntax {
+                message: strip_position(&error),
+                line,
+                column,
+            },
+        }
+    }
+}
+
+/// serde_json appends the position to its messages, which [SceneError] already stores separately.
+fn strip_position(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    match message.rfind(" at line ") {
+        Some(index) => message[..index].to_string(),
+        None => message,
+    }
+}
+
+impl Display for SceneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SceneError::Io(error) => write!(f, "{}", error),
+            SceneError::Syntax { message, line, column } => {
+                write!(f, "syntax error at {}:{}: {}", line, column, message)
+            }
+            SceneError::Invalid { message, line, column } => {
+                write!(f, "invalid scene at {}:{}: {}", line, column, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SceneError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SceneError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+//=============================================================//
+//========================= VALIDATION ========================//
+//=============================================================//
+
+// Validation happens while deserializing, so that serde_json can attach the position of the
+// offending value to the error.
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "N: PrimaFloat + Deserialize<'de>"))]
+struct SceneData<N> {
+    version: u32,
+    #[serde(default)]
+    layers: Vec<Layer<N>>,
+}
+
+impl<N> TryFrom<SceneData<N>> for Scene<N> {
+    type Error = String;
+
+    fn try_from(data: SceneData<N>) -> Result<Self, String> {
+        if data.version > SCENE_VERSION {
+            return Err(format!(
+                "scene version {} is newer than the supported version {}",
+                data.version, SCENE_VERSION
+            ));
+        }
+        let mut names = HashSet::new();
+        for layer in data.layers.iter() {
+            if !names.insert(layer.name.as_str()) {
+                return Err(format!("duplicate layer name `{}`", layer.name));
+            }
+        }
+        Ok(Scene {
+            version: data.version,
+            layers: data.layers,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "N: PrimaFloat + Deserialize<'de>"))]
+struct ItemData<N> {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    transform: Transform<N>,
+    shape: SceneShape<N>,
+}
+
+impl<N> TryFrom<ItemData<N>> for SceneItem<N>
+where
+    N: PrimaFloat,
+{
+    type Error = String;
+
+    fn try_from(data: ItemData<N>) -> Result<Self, String> {
+        let t = &data.transform;
+        check(
+            t.translation.x.is_finite() && t.translation.y.is_finite(),
+            "transform has a non-finite translation",
+        )?;
+        check(t.scale > N::zero(), "transform scale must be positive")?;
+        data.shape.validate()?;
+        Ok(SceneItem {
+            name: data.name,
+            tags: data.tags,
+            transform: data.transform,
+            shape: data.shape,
+        })
+    }
+}
@@ -0,0 +1,527 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::{
+    core::{Angle, Extent, Point, Polyline},
+    nums::PrimaFloat,
+    shapes::{Aabr, Circle, Obr, Polygon},
+};
+
+/// The number of sides used when approximating non-circular ellipses.
+pub const ELLIPSE_SIDES: usize = 32;
+
+/// The object layers of an orthogonal Tiled map, converted into prima shapes.
+///
+/// Tiled places its origin at the top-left of the map with y pointing down. Shapes are flipped
+/// on import so that y points up and the bottom-left of the map sits at the origin. Tiled
+/// rotates objects clockwise in degrees, which after the flip still reads as clockwise, so a
+/// rotation of 90 in Tiled is an [Angle] of 90 degrees.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TiledMap<N> {
+    /// The width of the map in tiles.
+    pub width: u32,
+    /// The height of the map in tiles.
+    pub height: u32,
+    /// The width of a tile in pixels.
+    pub tile_width: u32,
+    /// The height of a tile in pixels.
+    pub tile_height: u32,
+    /// Every object layer in the map, with group layers flattened in document order.
+    pub layers: Vec<ObjectLayer<N>>,
+}
+
+/// A single object layer of a [TiledMap].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectLayer<N> {
+    /// The name of the layer.
+    pub name: String,
+    /// The objects in this layer.
+    pub objects: Vec<TiledObject<N>>,
+}
+
+/// A single object from a Tiled object layer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TiledObject<N> {
+    /// The unique id of the object.
+    pub id: u32,
+    /// The name of the object.
+    pub name: String,
+    /// The class (or type) of the object.
+    pub class: String,
+    /// Whether the object is shown in the editor.
+    pub visible: bool,
+    /// Custom properties of the object, with every value written as a string.
+    pub properties: BTreeMap<String, String>,
+    /// The shape of the object.
+    pub shape: TiledShape<N>,
+}
+
+/// The shape of a [TiledObject].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TiledShape<N> {
+    /// A point object.
+    Point(Point<N>),
+    /// An unrotated rectangle or tile object.
+    Aabr(Aabr<N>),
+    /// A rotated rectangle or tile object.
+    Obr(Obr<N>),
+    /// An ellipse with equal width and height.
+    Circle(Circle<N>),
+    /// A polygon, or an ellipse approximated with [ELLIPSE_SIDES] sides.
+    Polygon(Polygon<N>),
+    /// A polyline.
+    Polyline(Polyline<N>),
+}
+
+/// An error raised while importing a Tiled map.
+#[derive(Debug)]
+pub enum TiledError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file is not a valid Tiled JSON map.
+    Json(serde_json::Error),
+    /// The file is not valid XML.
+    Xml(roxmltree::Error),
+    /// The map is well formed, but cannot be imported.
+    Invalid(String),
+}
+
+//=============================================================//
+//======================= IMPLEMENTATIONS =====================//
+//=============================================================//
+
+impl<N> TiledMap<N>
+where
+    N: PrimaFloat,
+{
+    /// Reads a map from Tiled's JSON format (.tmj).
+    pub fn from_tmj(json: &str) -> Result<Self, TiledError> {
+        let map: RawMap = serde_json::from_str(json)?;
+        map.convert()
+    }
+
+    /// Reads a map from Tiled's XML format (.tmx).
+    pub fn from_tmx(xml: &str) -> Result<Self, TiledError> {
+        let doc = roxmltree::Document::parse(xml)?;
+        RawMap::from_xml(&doc)?.convert()
+    }
+
+    /// Loads a map from a file, picking the format from its extension.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, TiledError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("tmx") | Some("xml") => Self::from_tmx(&text),
+            _ => Self::from_tmj(&text),
+        }
+    }
+
+    /// Returns the first object layer with the given name.
+    pub fn layer(&self, name: &str) -> Option<&ObjectLayer<N>> {
+        self.layers.iter().find(|l| l.name == name)
+    }
+
+    /// Returns every object across all layers.
+    pub fn objects(&self) -> impl Iterator<Item = &TiledObject<N>> {
+        self.layers.iter().flat_map(|l| l.objects.iter())
+    }
+
+    /// Returns the size of the map in pixels.
+    pub fn pixel_size(&self) -> Extent<N> {
+        // Widened first, as the product of two u32 values may not fit in a u32.
+        Extent::new(
+            N::from_u64(self.width as u64 * self.tile_width as u64).unwrap(),
+            N::from_u64(self.height as u64 * self.tile_height as u64).unwrap(),
+        )
+    }
+}
+
+impl<N> ObjectLayer<N> {
+    /// Returns the first object with the given name.
+    pub fn object(&self, name: &str) -> Option<&TiledObject<N>> {
+        self.objects.iter().find(|o| o.name == name)
+    }
+}
+
+//=============================================================//
+//========================= CONVERSION ========================//
+//=============================================================//
+
+// Both formats are read into these raw structs, which mirror Tiled's JSON layout, before
+// being converted into prima shapes.
+
+#[derive(Deserialize)]
+struct RawMap {
+    #[serde(default = "orthogonal")]
+    orientation: String,
+    width: u32,
+    height: u32,
+    tilewidth: u32,
+    tileheight: u32,
+    #[serde(default)]
+    layers: Vec<RawLayer>,
+}
+
+#[derive(Deserialize)]
+struct RawLayer {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    offsetx: f64,
+    #[serde(default)]
+    offsety: f64,
+    #[serde(default)]
+    objects: Vec<RawObject>,
+    #[serde(default)]
+    layers: Vec<RawLayer>,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct RawObject {
+    id: u32,
+    name: String,
+    #[serde(rename = "type", alias = "class")]
+    class: String,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    rotation: f64,
+    visible: bool,
+    ellipse: bool,
+    point: bool,
+    polygon: Option<Vec<RawPoint>>,
+    polyline: Option<Vec<RawPoint>>,
+    gid: Option<u32>,
+    text: Option<serde::de::IgnoredAny>,
+    properties: Vec<RawProperty>,
+}
+
+#[derive(Deserialize)]
+struct RawPoint {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Deserialize)]
+struct RawProperty {
+    name: String,
+    #[serde(default, deserialize_with = "property_value")]
+    value: String,
+}
+
+/// Reads a property value of any type as a string. The value is converted as it is read, so
+/// that no JSON value is kept in the raw types, which nest deeply enough to overflow the
+/// recursion limit when checking their auto traits.
+fn property_value<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => s,
+        other => other.to_string(),
+    })
+}
+
+fn orthogonal() -> String {
+    "orthogonal".to_string()
+}
+
+impl Default for RawObject {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            name: String::new(),
+            class: String::new(),
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+            rotation: 0.0,
+            visible: true,
+            ellipse: false,
+            point: false,
+            polygon: None,
+            polyline: None,
+            gid: None,
+            text: None,
+            properties: Vec::new(),
+        }
+    }
+}
+
+impl RawMap {
+    fn convert<N: PrimaFloat>(self) -> Result<TiledMap<N>, TiledError> {
+        if self.orientation != "orthogonal" {
+            return Err(TiledError::Invalid(format!(
+                "{} maps are not supported, only orthogonal maps can be imported",
+                self.orientation
+            )));
+        }
+        let map_height = self.height as f64 * self.tileheight as f64;
+        let mut layers = Vec::new();
+        flatten(&self.layers, (0.0, 0.0), map_height, &mut layers);
+        Ok(TiledMap {
+            width: self.width,
+            height: self.height,
+            tile_width: self.tilewidth,
+            tile_height: self.tileheight,
+            layers,
+        })
+    }
+}
+
+/// Collects object layers out of a tree of group layers, accumulating their offsets.
+fn flatten<N: PrimaFloat>(raw: &[RawLayer], offset: (f64, f64), map_height: f64, layers: &mut Vec<ObjectLayer<N>>) {
+    for layer in raw.iter() {
+        let offset = (offset.0 + layer.offsetx, offset.1 + layer.offsety);
+        match layer.kind.as_str() {
+            "objectgroup" => layers.push(ObjectLayer {
+                name: layer.name.clone(),
+                objects: layer
+                    .objects
+                    .iter()
+                    .filter_map(|o| o.convert(offset, map_height))
+                    .collect(),
+            }),
+            "group" => flatten(&layer.layers, offset, map_height, layers),
+            _ => {}
+        }
+    }
+}
+
+impl RawObject {
+    /// Converts the object into prima space. Text objects have no shape, so are skipped.
+    fn convert<N: PrimaFloat>(&self, offset: (f64, f64), map_height: f64) -> Option<TiledObject<N>> {
+        if self.text.is_some() {
+            return None;
+        }
+        let (x, y) = (self.x + offset.0, self.y + offset.1);
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        let num = |v: f64| N::from_f64(v).unwrap();
+        // Rotates a local offset around the object's origin, then flips it into prima space.
+        let place = |dx: f64, dy: f64| -> Point<N> {
+            let px = x + dx * cos - dy * sin;
+            let py = y + dx * sin + dy * cos;
+            Point::new(num(px), num(map_height - py))
+        };
+        let (w, h) = (self.width, self.height);
+        // Tile objects hang upwards from their origin, while everything else hangs downwards.
+        let top = if self.gid.is_some() { -h } else { 0.0 };
+
+        let shape = if self.point {
+            TiledShape::Point(place(0.0, 0.0))
+        } else if let Some(points) = &self.polygon {
+            TiledShape::Polygon(Polygon::new(points.iter().map(|p| place(p.x, p.y)).collect()))
+        } else if let Some(points) = &self.polyline {
+            TiledShape::Polyline(Polyline::new(points.iter().map(|p| place(p.x, p.y)).collect()))
+        } else if self.ellipse && w == h {
+            TiledShape::Circle(Circle::new(place(w / 2.0, h / 2.0), num(w / 2.0)))
+        } else if self.ellipse {
+            let step = std::f64::consts::TAU / ELLIPSE_SIDES as f64;
+            let mut polygon = Polygon::new(
+                (0..ELLIPSE_SIDES)
+                    .map(|i| {
+                        let (s, c) = (step * i as f64).sin_cos();
+                        place(w / 2.0 * (1.0 + c), h / 2.0 * (1.0 + s))
+                    })
+                    .collect(),
+            );
+            if polygon.is_clockwise() {
+                polygon.reverse();
+            }
+            TiledShape::Polygon(polygon)
+        } else if self.rotation == 0.0 {
+            TiledShape::Aabr(Aabr::new(place(0.0, top + h), place(w, top)))
+        } else {
+            TiledShape::Obr(Obr::new(
+                place(w / 2.0, top + h / 2.0),
+                Extent::new(num(w), num(h)),
+                Angle::from_degrees(num(self.rotation)),
+            ))
+        };
+
+        let properties = self
+            .properties
+            .iter()
+            .map(|p| (p.name.clone(), p.value.clone()))
+            .collect();
+
+        Some(TiledObject {
+            id: self.id,
+            name: self.name.clone(),
+            class: self.class.clone(),
+            visible: self.visible,
+            properties,
+            shape,
+        })
+    }
+}
+
+//=============================================================//
+//============================ XML ============================//
+//=============================================================//
+
+impl RawMap {
+    fn from_xml(doc: &roxmltree::Document) -> Result<Self, TiledError> {
+        let root = doc.root_element();
+        if root.tag_name().name() != "map" {
+            return Err(TiledError::Invalid("expected a <map> element".to_string()));
+        }
+        Ok(RawMap {
+            orientation: root.attribute("orientation").map(String::from).unwrap_or_else(orthogonal),
+            width: required(doc, root, "width")?,
+            height: required(doc, root, "height")?,
+            tilewidth: required(doc, root, "tilewidth")?,
+            tileheight: required(doc, root, "tileheight")?,
+            layers: xml_layers(doc, root)?,
+        })
+    }
+}
+
+fn xml_layers(doc: &roxmltree::Document, parent: roxmltree::Node) -> Result<Vec<RawLayer>, TiledError> {
+    let mut layers = Vec::new();
+    for node in parent.children().filter(|n| n.is_element()) {
+        let kind = node.tag_name().name();
+        if kind != "objectgroup" && kind != "group" {
+            continue;
+        }
+        let mut objects = Vec::new();
+        for object in node.children().filter(|n| n.has_tag_name("object")) {
+            objects.push(xml_object(doc, object)?);
+        }
+        layers.push(RawLayer {
+            kind: kind.to_string(),
+            name: node.attribute("name").unwrap_or_default().to_string(),
+            offsetx: optional(doc, node, "offsetx")?.unwrap_or(0.0),
+            offsety: optional(doc, node, "offsety")?.unwrap_or(0.0),
+            objects,
+            layers: if kind == "group" { xml_layers(doc, node)? } else { Vec::new() },
+        });
+    }
+    Ok(layers)
+}
+
+fn xml_object(doc: &roxmltree::Document, node: roxmltree::Node) -> Result<RawObject, TiledError> {
+    let mut object = RawObject {
+        id: optional(doc, node, "id")?.unwrap_or(0),
+        name: node.attribute("name").unwrap_or_default().to_string(),
+        class: node
+            .attribute("type")
+            .or_else(|| node.attribute("class"))
+            .unwrap_or_default()
+            .to_string(),
+        x: optional(doc, node, "x")?.unwrap_or(0.0),
+        y: optional(doc, node, "y")?.unwrap_or(0.0),
+        width: optional(doc, node, "width")?.unwrap_or(0.0),
+        height: optional(doc, node, "height")?.unwrap_or(0.0),
+        rotation: optional(doc, node, "rotation")?.unwrap_or(0.0),
+        visible: node.attribute("visible") != Some("0"),
+        gid: optional(doc, node, "gid")?,
+        ..Default::default()
+    };
+    for child in node.children().filter(|n| n.is_element()) {
+        match child.tag_name().name() {
+            "ellipse" => object.ellipse = true,
+            "point" => object.point = true,
+            "polygon" => object.polygon = Some(xml_points(doc, child)?),
+            "polyline" => object.polyline = Some(xml_points(doc, child)?),
+            "text" => object.text = Some(serde::de::IgnoredAny),
+            "properties" => {
+                for property in child.children().filter(|n| n.has_tag_name("property")) {
+                    let value = property
+                        .attribute("value")
+                        .or_else(|| property.text())
+                        .unwrap_or_default();
+                    object.properties.push(RawProperty {
+                        name: property.attribute("name").unwrap_or_default().to_string(),
+                        value: value.to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(object)
+}
+
+/// Parses a list of points in the form `x,y x,y ...`.
+fn xml_points(doc: &roxmltree::Document, node: roxmltree::Node) -> Result<Vec<RawPoint>, TiledError> {
+    let text = node.attribute("points").unwrap_or_default();
+    text.split_whitespace()
+        .map(|pair| {
+            let mut parts = pair.split(',').map(|v| v.parse::<f64>());
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(Ok(x)), Some(Ok(y)), None) => Ok(RawPoint { x, y }),
+                _ => Err(invalid(doc, node, &format!("invalid point `{}`", pair))),
+            }
+        })
+        .collect()
+}
+
+fn optional<T: FromStr>(doc: &roxmltree::Document, node: roxmltree::Node, name: &str) -> Result<Option<T>, TiledError> {
+    match node.attribute(name) {
+        Some(value) => value
+            .parse()
+            .map(Some)
+            .map_err(|_| invalid(doc, node, &format!("invalid value `{}` for `{}`", value, name))),
+        None => Ok(None),
+    }
+}
+
+fn required<T: FromStr>(doc: &roxmltree::Document, node: roxmltree::Node, name: &str) -> Result<T, TiledError> {
+    optional(doc, node, name)?.ok_or_else(|| invalid(doc, node, &format!("missing attribute `{}`", name)))
+}
+
+fn invalid(doc: &roxmltree::Document, node: roxmltree::Node, message: &str) -> TiledError {
+    let pos = doc.text_pos_at(node.range().start);
+    TiledError::Invalid(format!("{} at {}:{}", message, pos.row, pos.col))
+}
+
+//=============================================================//
+//=========================== ERRORS ==========================//
+//=============================================================//
+
+impl From<io::Error> for TiledError {
+    fn from(error: io::Error) -> Self {
+        TiledError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for TiledError {
+    fn from(error: serde_json::Error) -> Self {
+        TiledError::Json(error)
+    }
+}
+
+impl From<roxmltree::Error> for TiledError {
+    fn from(error: roxmltree::Error) -> Self {
+        TiledError::Xml(error)
+    }
+}
+
+impl Display for TiledError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TiledError::Io(error) => write!(f, "{}", error),
+            TiledError::Json(error) => write!(f, "{}", error),
+            TiledError::Xml(error) => write!(f, "{}", error),
+            TiledError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for TiledError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TiledError::Io(error) => Some(error),
+            TiledError::Json(error) => Some(error),
+            TiledError::Xml(error) => Some(error),
+            TiledError::Invalid(_) => None,
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    iter::{Enumerate, Peekable},
+    str::Chars,
+};
+
+use super::{GeoError, Geometry, Region};
+use crate::{
+    core::{Point, Polyline},
+    nums::PrimaFloat,
+};
+
+impl<N> Geometry<N>
+where
+    N: PrimaFloat,
+{
+    /// Writes the geometry as Well-Known Text, such as `POINT (1 2)`.
+    pub fn to_wkt(&self) -> String {
+        match self {
+            Geometry::Point(point) => format!("POINT ({})", coord(point)),
+            Geometry::LineString(line) if line.is_empty() => "LINESTRING EMPTY".to_string(),
+            Geometry::LineString(line) => format!("LINESTRING {}", coords(&line.points)),
+            Geometry::Polygon(region) => format!("POLYGON {}", rings(region)),
+            Geometry::MultiPolygon(regions) if regions.is_empty() => "MULTIPOLYGON EMPTY".to_string(),
+            Geometry::MultiPolygon(regions) => {
+                let regions: Vec<String> = regions.iter().map(rings).collect();
+                format!("MULTIPOLYGON ({})", regions.join(", "))
+            }
+        }
+    }
+
+    /// Reads a geometry from Well-Known Text. Only two dimensional geometries are supported.
+    pub fn from_wkt(wkt: &str) -> Result<Self, GeoError> {
+        let mut parser = Parser::new(wkt);
+        let geometry = parser.geometry()?;
+        match parser.next()? {
+            None => Ok(geometry),
+            Some((_, column)) => Err(wkt_error("unexpected text after geometry", column)),
+        }
+    }
+}
+
+fn coord<N: PrimaFloat>(point: &Point<N>) -> String {
+    format!("{} {}", point.x, point.y)
+}
+
+fn coords<N: PrimaFloat>(points: &[Point<N>]) -> String {
+    let points: Vec<String> = points.iter().map(coord).collect();
+    format!("({})", points.join(", "))
+}
+
+fn rings<N: PrimaFloat>(region: &Region<N>) -> String {
+    let rings: Vec<String> = region.rings().iter().map(|r| coords(r)).collect();
+    format!("({})", rings.join(", "))
+}
+
+fn wkt_error(message: &str, column: usize) -> GeoError {
+    GeoError::Wkt {
+        message: message.to_string(),
+        column,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Number(String),
+    Open,
+    Close,
+    Comma,
+}
+
+/// A recursive descent parser over WKT tokens, tracking columns for error reporting.
+struct Parser<'a> {
+    chars: Peekable<Enumerate<Chars<'a>>>,
+    peeked: Option<(Token, usize)>,
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.chars().enumerate().peekable(),
+            peeked: None,
+            end: text.chars().count() + 1,
+        }
+    }
+
+    /// Returns the next token along with the column it starts at.
+    fn next(&mut self) -> Result<Option<(Token, usize)>, GeoError> {
+        if let Some(peeked) = self.peeked.take() {
+            return Ok(Some(peeked));
+        }
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        let (start, c) = match self.chars.next() {
+            Some(next) => next,
+            None => return Ok(None),
+        };
+        let column = start + 1;
+        let token = match c {
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            c if c.is_ascii_alphabetic() => {
+                let mut word = c.to_string();
+                while let Some((_, c)) = self.chars.next_if(|(_, c)| c.is_ascii_alphabetic()) {
+                    word.push(c);
+                }
+                Token::Word(word.to_ascii_uppercase())
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => {
+                let mut number = c.to_string();
+                while let Some((_, c)) = self
+                    .chars
+                    .next_if(|(_, c)| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'))
+                {
+                    number.push(c);
+                }
+                Token::Number(number)
+            }
+            c => return Err(wkt_error(&format!("unexpected character `{}`", c), column)),
+        };
+        Ok(Some((token, column)))
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token>, GeoError> {
+        if self.peeked.is_none() {
+            self.peeked = self.next()?;
+        }
+        Ok(self.peeked.as_ref().map(|(t, _)| t))
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> Result<(), GeoError> {
+        match self.next()? {
+            Some((token, _)) if token == expected => Ok(()),
+            Some((_, column)) => Err(wkt_error(&format!("expected {}", what), column)),
+            None => Err(wkt_error(&format!("expected {}", what), self.end)),
+        }
+    }
+
+    fn geometry<N: PrimaFloat>(&mut self) -> Result<Geometry<N>, GeoError> {
+        let (tag, column) = match self.next()? {
+            Some((Token::Word(word), column)) => (word, column),
+            Some((_, column)) => return Err(wkt_error("expected a geometry type", column)),
+            None => return Err(wkt_error("expected a geometry type", self.end)),
+        };
+        if let Some(Token::Word(word)) = self.peek()?.cloned() {
+            let (_, column) = self.next()?.unwrap();
+            if word != "EMPTY" {
+                return Err(wkt_error(&format!("unsupported dimension `{}`", word), column));
+            }
+            return match tag.as_str() {
+                "LINESTRING" => Ok(Geometry::LineString(Polyline::new(Vec::new()))),
+                "MULTIPOLYGON" => Ok(Geometry::MultiPolygon(Vec::new())),
+                _ => Err(wkt_error(&format!("empty {} is not supported", tag), column)),
+            };
+        }
+        match tag.as_str() {
+            "POINT" => {
+                self.expect(Token::Open, "`(`")?;
+                let point = self.coord()?;
+                self.expect(Token::Close, "`)`")?;
+                Ok(Geometry::Point(point))
+            }
+            "LINESTRING" => Ok(Geometry::LineString(Polyline::new(self.coords()?))),
+            "POLYGON" => Ok(Geometry::Polygon(self.region()?)),
+            "MULTIPOLYGON" => Ok(Geometry::MultiPolygon(self.list(|p| p.region())?)),
+            _ => Err(wkt_error(&format!("unsupported geometry type `{}`", tag), column)),
+        }
+    }
+
+    /// Parses a bracketed, comma separated list.
+    fn list<T, F>(&mut self, mut item: F) -> Result<Vec<T>, GeoError>
+    where
+        F: FnMut(&mut Self) -> Result<T, GeoError>,
+    {
+        self.expect(Token::Open, "`(`")?;
+        let mut items = vec![item(self)?];
+        loop {
+            match self.next()? {
+                Some((Token::Comma, _)) => items.push(item(self)?),
+                Some((Token::Close, _)) => return Ok(items),
+                Some((_, column)) => return Err(wkt_error("expected `,` or `)`", column)),
+                None => return Err(wkt_error("expected `,` or `)`", self.end)),
+            }
+        }
+    }
+
+    fn region<N: PrimaFloat>(&mut self) -> Result<Region<N>, GeoError> {
+        let rings = self.list(|p| p.coords())?;
+        Ok(Region::from_rings(rings).unwrap())
+    }
+
+    fn coords<N: PrimaFloat>(&mut self) -> Result<Vec<Point<N>>, GeoError> {
+        self.list(|p| p.coord())
+    }
+
+    fn coord<N: PrimaFloat>(&mut self) -> Result<Point<N>, GeoError> {
+        let x = self.number()?;
+        let y = self.number()?;
+        if let Some(Token::Number(_)) = self.peek()? {
+            let (_, column) = self.next()?.unwrap();
+            return Err(wkt_error("only two dimensional coordinates are supported", column));
+        }
+        Ok(Point::new(x, y))
+    }
+
+    fn number<N: PrimaFloat>(&mut self) -> Result<N, GeoError> {
+        match self.next()? {
+            Some((Token::Number(number), column)) => {
+                N::from_str_radix(&number, 10).map_err(|_| wkt_error(&format!("invalid number `{}`", number), column))
+            }
+            Some((_, column)) => Err(wkt_error("expected a number", column)),
+            None => Err(wkt_error("expected a number", self.end)),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::Cell;
+use crate::shapes::Aabr;
+
+/// Which neighbouring cells count as connected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Connectivity {
+    /// Cells sharing an edge: up, right, down and left.
+    Four,
+    /// Cells sharing an edge or a corner.
+    Eight,
+}
+
+/// The offsets of the neighbours sharing an edge, clockwise from up.
+const EDGE_OFFSETS: [(i32, i32); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+/// The offsets of all eight neighbours, clockwise from up.
+const ALL_OFFSETS: [(i32, i32); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+
+impl Connectivity {
+    /// Returns the neighbours of a cell, clockwise from the one above it.
+    pub fn neighbours(self, cell: Cell) -> impl Iterator<Item = Cell> {
+        let offsets: &'static [(i32, i32)] = match self {
+            Connectivity::Four => &EDGE_OFFSETS,
+            Connectivity::Eight => &ALL_OFFSETS,
+        };
+        offsets
+            .iter()
+            .map(move |&(x, y)| Cell::new(cell.x + x, cell.y + y))
+    }
+}
+
+impl Aabr<i32> {
+    /// Iterates the cells inside this half-open range row by row, from the minimum corner.
+    /// The maximum corner is not included, so a range from `(0, 0)` to `(2, 2)` holds 4 cells.
+    pub fn cells(&self) -> Cells {
+        Cells {
+            range: *self,
+            next: self.min,
+        }
+    }
+
+    /// Returns true if the cell lies inside this half-open range.
+    pub fn contains_cell(&self, cell: Cell) -> bool {
+        cell.x >= self.min.x && cell.x < self.max.x && cell.y >= self.min.y && cell.y < self.max.y
+    }
+}
+
+/// An iterator over the cells of an [Aabr], created by [Aabr::cells].
+#[derive(Clone, Debug)]
+pub struct Cells {
+    range: Aabr<i32>,
+    next: Cell,
+}
+
+impl Iterator for Cells {
+    type Item = Cell;
+
+    fn next(&mut self) -> Option<Cell> {
+        if self.range.min.x >= self.range.max.x || self.next.y >= self.range.max.y {
+            return None;
+        }
+        let cell = self.next;
+        self.next.x += 1;
+        if self.next.x >= self.range.max.x {
+            self.next.x = self.range.min.x;
+            self.next.y += 1;
+        }
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.range.min.x >= self.range.max.x || self.next.y >= self.range.max.y {
+            return (0, Some(0));
+        }
+        let width = (self.range.max.x - self.range.min.x) as usize;
+        let rows = (self.range.max.y - self.next.y) as usize;
+        let remaining = rows * width - (self.next.x - self.range.min.x) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Cells {}
+
+/// Finds every cell connected to `start` that `passable` accepts, without leaving `bounds`.
+///
+/// Cells are returned in breadth first order, starting with `start`. Nothing is returned if
+/// `start` itself is outside the bounds or not passable.
+pub fn flood_fill<F>(
+    start: Cell,
+    bounds: &Aabr<i32>,
+    connectivity: Connectivity,
+    mut passable: F,
+) -> Vec<Cell>
+where
+    F: FnMut(Cell) -> bool,
+{
+    let mut filled = Vec::new();
+    if !bounds.contains_cell(start) || !passable(start) {
+        return filled;
+    }
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    seen.insert(start);
+    queue.push_back(start);
+    while let Some(cell) = queue.pop_front() {
+        filled.push(cell);
+        for next in connectivity.neighbours(cell) {
+            if bounds.contains_cell(next) && seen.insert(next) && passable(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    filled
+}
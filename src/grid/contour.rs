@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use super::Grid;
+use crate::{
+    core::{Point, Polyline},
+    nums::PrimaFloat,
+    shapes::Polygon,
+    traits::Shape,
+};
+
+/// A rectangular field of scalar samples, such as a height map or density field.
+///
+/// Sample `(x, y)` sits at the corner of grid cell `(x, y)`, so a field of `width` by `height`
+/// samples spans `width - 1` by `height - 1` cells.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScalarField<N> {
+    width: usize,
+    height: usize,
+    values: Vec<N>,
+}
+
+/// A closed region extracted from a [ScalarField], with any holes cut out of it.
+///
+/// The exterior is wound counter-clockwise and the holes clockwise. Regions inside a hole are
+/// returned as contours of their own.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contour<N> {
+    /// The outer boundary of the region.
+    pub exterior: Polygon<N>,
+    /// Areas cut out of the exterior.
+    pub holes: Vec<Polygon<N>>,
+}
+
+/// Identifies the lattice edge a contour crosses: the edge leaving sample `(x, y)` along x
+/// if horizontal, or along y otherwise.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct EdgeKey {
+    x: isize,
+    y: isize,
+    horizontal: bool,
+}
+
+/// A piece of contour crossing a single cell, with the region above the iso-level on its left.
+#[derive(Copy, Clone, Debug)]
+struct Segment<N> {
+    from: EdgeKey,
+    to: EdgeKey,
+    start: Point<N>,
+    end: Point<N>,
+}
+
+impl<N> ScalarField<N>
+where
+    N: PrimaFloat,
+{
+    /// Creates a field from samples stored row by row, starting at `y = 0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values is not `width * height`.
+    pub fn new(width: usize, height: usize, values: Vec<N>) -> Self {
+        assert_eq!(
+            values.len(),
+            width * height,
+            "a {}x{} field needs {} values",
+            width,
+            height,
+            width * height
+        );
+        Self {
+            width,
+            height,
+            values,
+        }
+    }
+
+    /// Creates a field by sampling a function at each sample coordinate.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> N,
+    {
+        let mut values = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                values.push(f(x, y));
+            }
+        }
+        Self::new(width, height, values)
+    }
+
+    /// The number of samples along x.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of samples along y.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the sample at the given coordinate, if it is inside the field.
+    pub fn get(&self, x: usize, y: usize) -> Option<N> {
+        if x < self.width && y < self.height {
+            Some(self.values[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Extracts every region at or above `iso`, each with the holes inside it.
+    ///
+    /// Samples outside the field count as below the level, so regions touching the border are
+    /// closed along it. Positions are placed in world space using `grid`.
+    pub fn contours(&self, iso: N, grid: &Grid<N>) -> Vec<Contour<N>> {
+        let segments = self.segments(iso, grid, true);
+        let (holes, outlines): (Vec<Polygon<N>>, Vec<Polygon<N>>) = chain(segments)
+            .into_iter()
+            .map(|(points, _)| Polygon::new(points))
+            .partition(|p| p.is_clockwise());
+
+        let mut contours: Vec<Contour<N>> = outlines.into_iter().map(Contour::new).collect();
+        for hole in holes {
+            // Contours never cross, so the tightest outline around any point of the hole owns it.
+            let owner = contours
+                .iter_mut()
+                .filter(|c| c.exterior.contains(&hole.points[0]))
+                .min_by(|a, b| {
+                    a.exterior
+                        .volume()
+                        .partial_cmp(&b.exterior.volume())
+                        .unwrap()
+                });
+            if let Some(owner) = owner {
+                owner.holes.push(hole);
+            }
+        }
+        contours
+    }
+
+    /// Extracts the lines where the field crosses `iso`, without closing them along the border.
+    ///
+    /// Lines run with the region at or above the level on their left. Lines that form a loop
+    /// repeat their first point at the end.
+    pub fn isolines(&self, iso: N, grid: &Grid<N>) -> Vec<Polyline<N>> {
+        let segments = self.segments(iso, grid, false);
+        chain(segments)
+            .into_iter()
+            .map(|(mut points, closed)| {
+                if closed {
+                    points.push(points[0]);
+                }
+                Polyline::new(points)
+            })
+            .collect()
+    }
+
+    /// Returns the sample at a signed coordinate, or None outside the field.
+    fn sample(&self, x: isize, y: isize) -> Option<N> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        self.get(x as usize, y as usize)
+    }
+
+    /// Runs marching squares over every cell, optionally including a ring of cells around
+    /// the border whose outer samples are treated as below the level.
+    fn segments(&self, iso: N, grid: &Grid<N>, padded: bool) -> Vec<Segment<N>> {
+        let pad = if padded { 1 } else { 0 };
+        let (width, height) = (self.width as isize, self.height as isize);
+        let mut segments = Vec::new();
+
+        for y in -pad..height - 1 + pad {
+            for x in -pad..width - 1 + pad {
+                // Corners counter-clockwise from the bottom left, each followed by the edge
+                // leading to the next corner.
+                let corners = [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)];
+                let edges = [
+                    EdgeKey {
+                        x,
+                        y,
+                        horizontal: true,
+                    },
+                    EdgeKey {
+                        x: x + 1,
+                        y,
+                        horizontal: false,
+                    },
+                    EdgeKey {
+                        x,
+                        y: y + 1,
+                        horizontal: true,
+                    },
+                    EdgeKey {
+                        x,
+                        y,
+                        horizontal: false,
+                    },
+                ];
+                let values = corners.map(|(cx, cy)| self.sample(cx, cy));
+                let above = values.map(|v| matches!(v, Some(v) if v >= iso));
+                if above.iter().all(|a| *a) || above.iter().all(|a| !*a) {
+                    continue;
+                }
+
+                // Crossings in counter-clockwise order, noting whether each leaves the region.
+                let mut crossings = Vec::with_capacity(4);
+                for i in 0..4 {
+                    let j = (i + 1) % 4;
+                    if above[i] != above[j] {
+                        let t = match (values[i], values[j]) {
+                            (Some(a), Some(b)) => (iso - a) / (b - a),
+                            (Some(_), None) => N::zero(),
+                            _ => N::one(),
+                        };
+                        let (ax, ay) = corners[i];
+                        let (bx, by) = corners[j];
+                        let local = Point::new(lerp_index(ax, bx, t), lerp_index(ay, by, t));
+                        crossings.push((edges[i], grid.to_world(&local), above[i]));
+                    }
+                }
+
+                // Saddles are resolved by the average of the corners: if the center is above
+                // the level, the two high corners are joined through it.
+                let join_high = crossings.len() == 4 && {
+                    let sum = values.iter().fold(N::zero(), |s, v| s + v.unwrap_or(iso));
+                    sum / N::from_f32(4.0).unwrap() >= iso
+                };
+                let n = crossings.len();
+                for (i, (edge, point, leaving)) in crossings.iter().enumerate() {
+                    if !leaving {
+                        continue;
+                    }
+                    // The next crossing cuts off the low corners after this one, while the
+                    // previous one cuts off the high corners before it.
+                    let partner = if join_high || n == 2 {
+                        (i + 1) % n
+                    } else {
+                        (i + n - 1) % n
+                    };
+                    segments.push(Segment {
+                        from: *edge,
+                        to: crossings[partner].0,
+                        start: *point,
+                        end: crossings[partner].1,
+                    });
+                }
+            }
+        }
+        segments
+    }
+}
+
+impl<N> Contour<N> {
+    /// Creates a contour without holes.
+    pub fn new(exterior: Polygon<N>) -> Self {
+        Self {
+            exterior,
+            holes: Vec::new(),
+        }
+    }
+}
+
+impl<N> Contour<N>
+where
+    N: PrimaFloat,
+{
+    /// Returns true if the point is inside the exterior and outside every hole.
+    pub fn contains(&self, point: &Point<N>) -> bool {
+        self.exterior.contains(point) && !self.holes.iter().any(|h| h.contains(point))
+    }
+
+    /// The area of the region, not counting its holes.
+    pub fn volume(&self) -> N {
+        self.holes
+            .iter()
+            .fold(self.exterior.volume(), |area, hole| area - hole.volume())
+    }
+}
+
+fn lerp_index<N: PrimaFloat>(a: isize, b: isize, t: N) -> N {
+    let a = N::from_isize(a).unwrap();
+    let b = N::from_isize(b).unwrap();
+    a + (b - a) * t
+}
+
+/// Links segments end to start, returning each chain of points and whether it loops.
+/// Repeated points, which occur where a contour runs along the border, are removed.
+fn chain<N: PrimaFloat>(segments: Vec<Segment<N>>) -> Vec<(Vec<Point<N>>, bool)> {
+    let mut by_start: HashMap<EdgeKey, Segment<N>> = HashMap::new();
+    let mut has_predecessor = HashMap::new();
+    for segment in segments.iter() {
+        by_start.insert(segment.from, *segment);
+        has_predecessor.insert(segment.to, true);
+    }
+
+    // Open chains start where nothing leads in, then whatever is left forms loops.
+    let mut starts: Vec<EdgeKey> = segments
+        .iter()
+        .filter(|s| !has_predecessor.contains_key(&s.from))
+        .map(|s| s.from)
+        .collect();
+    starts.extend(segments.iter().map(|s| s.from));
+
+    let mut chains = Vec::new();
+    for first in starts {
+        let mut key = first;
+        let mut points: Vec<Point<N>> = Vec::new();
+        let mut closed = false;
+        while let Some(segment) = by_start.remove(&key) {
+            if points.last() != Some(&segment.start) {
+                points.push(segment.start);
+            }
+            key = segment.to;
+            if key == first {
+                closed = true;
+            } else if !by_start.contains_key(&key) && points.last() != Some(&segment.end) {
+                // The end of an open chain is the crossing its last segment leads to.
+                points.push(segment.end);
+            }
+        }
+        if points.is_empty() {
+            continue;
+        }
+        if closed && points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        chains.push((points, closed));
+    }
+    chains
+}
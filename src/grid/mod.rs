@@ -0,0 +1,146 @@
+mod cells;
+mod contour;
+mod raster;
+mod traversal;
+
+pub use cells::*;
+pub use contour::*;
+pub use raster::*;
+pub use traversal::*;
+
+use crate::{
+    core::{Extent, Line, Point, Ray, Vector},
+    nums::PrimaFloat,
+    shapes::Aabr,
+    traits::Magnitude,
+};
+
+/// The integer coordinate of a cell in a grid.
+pub type Cell = Point<i32>;
+
+/// Maps between world space and the cells of a uniform grid.
+///
+/// Cell `(x, y)` covers the half-open area from `origin + (x, y) * cell_size` up to, but not
+/// including, the next cell along each axis.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid<N> {
+    /// The world position of the minimum corner of cell `(0, 0)`.
+    pub origin: Point<N>,
+    /// The width and height of each cell.
+    pub cell_size: Extent<N>,
+}
+
+impl<N> Grid<N>
+where
+    N: PrimaFloat,
+{
+    /// Creates a new grid.
+    pub fn new(origin: Point<N>, cell_size: Extent<N>) -> Self {
+        Self { origin, cell_size }
+    }
+
+    /// Creates a grid of unit cells at the origin, so that world and cell coordinates line up.
+    pub fn unit() -> Self {
+        Self::new(Point::zero(), Extent::one())
+    }
+
+    /// Converts a world position into cell space, where each cell is one unit wide.
+    pub fn to_local(&self, point: &Point<N>) -> Point<N> {
+        Point::new(
+            (point.x - self.origin.x) / self.cell_size.width(),
+            (point.y - self.origin.y) / self.cell_size.height(),
+        )
+    }
+
+    /// Converts a position in cell space back into world space.
+    pub fn to_world(&self, point: &Point<N>) -> Point<N> {
+        Point::new(
+            self.origin.x + point.x * self.cell_size.width(),
+            self.origin.y + point.y * self.cell_size.height(),
+        )
+    }
+
+    /// Returns the cell containing the given world position.
+    pub fn cell_at(&self, point: &Point<N>) -> Cell {
+        let local = self.to_local(point);
+        Cell::new(floor_to_i32(local.x), floor_to_i32(local.y))
+    }
+
+    /// Returns the world space area covered by a cell.
+    pub fn cell_rect(&self, cell: Cell) -> Aabr<N> {
+        let min = Point::new(from_i32(cell.x), from_i32(cell.y));
+        Aabr::new(self.to_world(&min), self.to_world(&(min + Vector::one())))
+    }
+
+    /// Returns the world space center of a cell.
+    pub fn cell_center(&self, cell: Cell) -> Point<N> {
+        let half = N::one() / (N::one() + N::one());
+        self.to_world(&Point::new(
+            from_i32::<N>(cell.x) + half,
+            from_i32::<N>(cell.y) + half,
+        ))
+    }
+
+    /// Returns the half-open range of cells that overlap the given area.
+    pub fn cells_overlapping(&self, aabr: &Aabr<N>) -> Aabr<i32> {
+        let min = self.to_local(&aabr.min);
+        let max = self.to_local(&aabr.max);
+        Aabr::new(
+            Cell::new(floor_to_i32(min.x), floor_to_i32(min.y)),
+            Cell::new(ceil_to_i32(max.x), ceil_to_i32(max.y)),
+        )
+    }
+
+    /// Walks the cells a line passes through, in order from its start to its end.
+    pub fn traverse_line(&self, line: &Line<N>) -> GridTraversal<N> {
+        GridTraversal::new(self, line.start, line.end - line.start, N::one())
+    }
+
+    /// Walks the cells a ray passes through, stopping once it has travelled `max_distance`.
+    pub fn traverse_ray(&self, ray: &Ray<N>, max_distance: N) -> GridTraversal<N> {
+        let length = ray.direction.magnitude();
+        let max_t = if length > N::zero() {
+            max_distance / length
+        } else {
+            N::zero()
+        };
+        GridTraversal::new(self, ray.origin, ray.direction, max_t)
+    }
+
+    /// Returns the cells covered by a shape, sorted by row and then column.
+    pub fn rasterize<S>(&self, shape: &S, mode: RasterMode) -> Vec<Cell>
+    where
+        S: Rasterize<N>,
+    {
+        shape.rasterize(self, mode)
+    }
+}
+
+impl<N> Default for Grid<N>
+where
+    N: PrimaFloat,
+{
+    fn default() -> Self {
+        Self::unit()
+    }
+}
+
+/// Rounds down to an integer cell coordinate, saturating at the limits of `i32`.
+pub(crate) fn floor_to_i32<N: PrimaFloat>(n: N) -> i32 {
+    saturate(n.floor())
+}
+
+/// Rounds up to an integer cell coordinate, saturating at the limits of `i32`.
+pub(crate) fn ceil_to_i32<N: PrimaFloat>(n: N) -> i32 {
+    saturate(n.ceil())
+}
+
+fn saturate<N: PrimaFloat>(n: N) -> i32 {
+    n.to_i32()
+        .unwrap_or(if n > N::zero() { i32::MAX } else { i32::MIN })
+}
+
+pub(crate) fn from_i32<N: PrimaFloat>(n: i32) -> N {
+    N::from_i32(n).unwrap()
+}
@@ -0,0 +1,179 @@
+use super::{ceil_to_i32, floor_to_i32, from_i32, Cell, Grid};
+use crate::{
+    core::Point,
+    nums::PrimaFloat,
+    shapes::{Aabr, Circle, Obr, Polygon},
+    traits::{Flat, Shape},
+};
+
+/// How a shape decides which cells it covers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RasterMode {
+    /// Covers every cell the shape overlaps by any area. Cells the shape only touches along an
+    /// edge or at a corner are left out.
+    Conservative,
+    /// Covers the cells whose centers lie inside the shape, like a renderer filling pixels.
+    Center,
+}
+
+/// A shape that can be rasterised into the cells of a [Grid].
+pub trait Rasterize<N> {
+    /// Returns the cells covered by the shape, sorted by row and then column.
+    fn rasterize(&self, grid: &Grid<N>, mode: RasterMode) -> Vec<Cell>;
+}
+
+impl<N> Rasterize<N> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    fn rasterize(&self, grid: &Grid<N>, mode: RasterMode) -> Vec<Cell> {
+        let rows = grid.cells_overlapping(&self.bounding_rect());
+        let mut cells = Vec::new();
+        for y in rows.min.y..rows.max.y {
+            let row = grid.cell_rect(Cell::new(0, y));
+            // The vertical distance from the center to the part of the row being tested.
+            let dy = match mode {
+                RasterMode::Center => (row.min.y + row.max.y) / two::<N>() - self.center.y,
+                RasterMode::Conservative if self.center.y < row.min.y => row.min.y - self.center.y,
+                RasterMode::Conservative if self.center.y > row.max.y => self.center.y - row.max.y,
+                RasterMode::Conservative => N::zero(),
+            };
+            let r_sq = self.radius * self.radius;
+            if dy * dy >= r_sq {
+                continue;
+            }
+            let half = (r_sq - dy * dy).sqrt();
+            let lo = (self.center.x - half - grid.origin.x) / grid.cell_size.width();
+            let hi = (self.center.x + half - grid.origin.x) / grid.cell_size.width();
+            push_span(&mut cells, y, lo, hi, mode);
+        }
+        cells
+    }
+}
+
+impl<N> Rasterize<N> for Polygon<N>
+where
+    N: PrimaFloat,
+{
+    fn rasterize(&self, grid: &Grid<N>, mode: RasterMode) -> Vec<Cell> {
+        let local: Vec<Point<N>> = self.points.iter().map(|p| grid.to_local(p)).collect();
+        rasterize_local(&local, mode)
+    }
+}
+
+impl<N> Rasterize<N> for Obr<N>
+where
+    N: PrimaFloat,
+{
+    fn rasterize(&self, grid: &Grid<N>, mode: RasterMode) -> Vec<Cell> {
+        let local: Vec<Point<N>> = self.vertices().iter().map(|p| grid.to_local(p)).collect();
+        rasterize_local(&local, mode)
+    }
+}
+
+impl<N> Rasterize<N> for Aabr<N>
+where
+    N: PrimaFloat,
+{
+    fn rasterize(&self, grid: &Grid<N>, mode: RasterMode) -> Vec<Cell> {
+        let local: Vec<Point<N>> = self.vertices().iter().map(|p| grid.to_local(p)).collect();
+        rasterize_local(&local, mode)
+    }
+}
+
+fn two<N: PrimaFloat>() -> N {
+    N::one() + N::one()
+}
+
+/// Rasterises a polygon given in cell space, where each cell is one unit wide.
+fn rasterize_local<N>(points: &[Point<N>], mode: RasterMode) -> Vec<Cell>
+where
+    N: PrimaFloat,
+{
+    let mut cells = Vec::new();
+    if points.len() < 3 {
+        return cells;
+    }
+    let (min_y, max_y) = points
+        .iter()
+        .fold((N::infinity(), N::neg_infinity()), |(lo, hi), p| {
+            (lo.min(p.y), hi.max(p.y))
+        });
+    let edges: Vec<(Point<N>, Point<N>)> = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+        .collect();
+    let half = N::one() / two::<N>();
+
+    for y in floor_to_i32(min_y)..ceil_to_i32(max_y) {
+        let (y0, y1) = (from_i32::<N>(y), from_i32::<N>(y) + N::one());
+        let mut spans = Vec::new();
+        match mode {
+            RasterMode::Center => crossings(&edges, y0 + half, true, &mut spans),
+            RasterMode::Conservative => {
+                // Every part of the polygon inside the row is either directly below the top of
+                // the row, directly above the bottom, or level with an edge crossing the row.
+                crossings(&edges, y0, true, &mut spans);
+                crossings(&edges, y1, false, &mut spans);
+                for (a, b) in edges.iter() {
+                    let (low, high) = if a.y < b.y { (a, b) } else { (b, a) };
+                    if high.y <= y0 || low.y >= y1 {
+                        continue;
+                    }
+                    let (x0, x1) = if low.y == high.y {
+                        (low.x, high.x)
+                    } else {
+                        let x_at = |y: N| low.x + (high.x - low.x) * (y - low.y) / (high.y - low.y);
+                        (x_at(low.y.max(y0)), x_at(high.y.min(y1)))
+                    };
+                    spans.push((x0.min(x1), x0.max(x1)));
+                }
+            }
+        }
+        let mut row = Vec::new();
+        for (lo, hi) in spans {
+            push_span(&mut row, y, lo, hi, mode);
+        }
+        row.sort_by_key(|c| c.x);
+        row.dedup();
+        cells.extend(row);
+    }
+    cells
+}
+
+/// Finds where the polygon's edges cross a horizontal line, pairing them into the spans inside
+/// the polygon using the even-odd rule. Crossings are taken just above the line if `above` is
+/// set and just below it otherwise, so edges lying along the line are not counted.
+fn crossings<N>(edges: &[(Point<N>, Point<N>)], y: N, above: bool, spans: &mut Vec<(N, N)>)
+where
+    N: PrimaFloat,
+{
+    let below = |p: &Point<N>| if above { p.y <= y } else { p.y < y };
+    let mut xs: Vec<N> = edges
+        .iter()
+        .filter(|(a, b)| below(a) != below(b))
+        .map(|(a, b)| a.x + (b.x - a.x) * (y - a.y) / (b.y - a.y))
+        .collect();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    spans.extend(xs.chunks_exact(2).map(|pair| (pair[0], pair[1])));
+}
+
+/// Adds the cells of row `y` covered by the span from `lo` to `hi` in cell space.
+fn push_span<N>(cells: &mut Vec<Cell>, y: i32, lo: N, hi: N, mode: RasterMode)
+where
+    N: PrimaFloat,
+{
+    let (first, last) = match mode {
+        // Cells whose centers lie in [lo, hi).
+        RasterMode::Center => {
+            let half = N::one() / two::<N>();
+            (ceil_to_i32(lo - half), ceil_to_i32(hi - half))
+        }
+        // Cells overlapping the open interval (lo, hi).
+        RasterMode::Conservative if lo < hi => (floor_to_i32(lo), ceil_to_i32(hi)),
+        RasterMode::Conservative => return,
+    };
+    cells.extend((first..last).map(|x| Cell::new(x, y)));
+}
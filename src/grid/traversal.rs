@@ -0,0 +1,139 @@
+use super::{floor_to_i32, from_i32, Cell, Grid};
+use crate::{
+    core::{Point, Vector},
+    nums::PrimaFloat,
+};
+
+/// Walks every cell a line or ray passes through using a DDA, created by
+/// [Grid::traverse_line] and [Grid::traverse_ray].
+///
+/// Cells are visited in order along the path. Where the path crosses a corner exactly, the
+/// cell stepped into along x is visited before the one diagonally across.
+#[derive(Clone, Debug)]
+pub struct GridTraversal<N> {
+    cell: Cell,
+    step: Cell,
+    t_max: Vector<N>,
+    t_delta: Vector<N>,
+    t_end: N,
+    done: bool,
+}
+
+impl<N> GridTraversal<N>
+where
+    N: PrimaFloat,
+{
+    /// Starts a traversal from `origin` along `direction`, ending after `t_end` lengths of it.
+    pub(crate) fn new(grid: &Grid<N>, origin: Point<N>, direction: Vector<N>, t_end: N) -> Self {
+        let start = grid.to_local(&origin);
+        let direction = Vector::new(
+            direction.x / grid.cell_size.width(),
+            direction.y / grid.cell_size.height(),
+        );
+        let cell = Cell::new(floor_to_i32(start.x), floor_to_i32(start.y));
+        let (step_x, t_max_x, t_delta_x) = axis(start.x, cell.x, direction.x);
+        let (step_y, t_max_y, t_delta_y) = axis(start.y, cell.y, direction.y);
+        Self {
+            cell,
+            step: Cell::new(step_x, step_y),
+            t_max: Vector::new(t_max_x, t_max_y),
+            t_delta: Vector::new(t_delta_x, t_delta_y),
+            t_end,
+            done: false,
+        }
+    }
+}
+
+/// Returns the step direction, the distance to the first boundary and the distance between
+/// boundaries along one axis, all in lengths of the direction.
+fn axis<N: PrimaFloat>(start: N, cell: i32, direction: N) -> (i32, N, N) {
+    if direction > N::zero() {
+        let boundary = from_i32::<N>(cell) + N::one();
+        (1, (boundary - start) / direction, N::one() / direction)
+    } else if direction < N::zero() {
+        let boundary = from_i32::<N>(cell);
+        (-1, (boundary - start) / direction, -N::one() / direction)
+    } else {
+        (0, N::infinity(), N::infinity())
+    }
+}
+
+impl<N> Iterator for GridTraversal<N>
+where
+    N: PrimaFloat,
+{
+    type Item = Cell;
+
+    fn next(&mut self) -> Option<Cell> {
+        if self.done {
+            return None;
+        }
+        let cell = self.cell;
+        if self.t_max.x <= self.t_max.y {
+            if self.t_max.x >= self.t_end {
+                self.done = true;
+            } else {
+                self.cell.x += self.step.x;
+                self.t_max.x += self.t_delta.x;
+            }
+        } else if self.t_max.y >= self.t_end {
+            self.done = true;
+        } else {
+            self.cell.y += self.step.y;
+            self.t_max.y += self.t_delta.y;
+        }
+        Some(cell)
+    }
+}
+
+/// Walks the cells of a Bresenham line between two cells, created by [bresenham].
+#[derive(Clone, Debug)]
+pub struct Bresenham {
+    cell: Cell,
+    end: Cell,
+    delta: Cell,
+    step: Cell,
+    error: i64,
+    done: bool,
+}
+
+/// Returns the cells of a Bresenham line from `start` to `end`, including both.
+///
+/// Exactly one cell is visited per step along the longer axis, so consecutive cells may only
+/// touch at a corner. Use [Grid::traverse_line] to find every cell a line passes through.
+pub fn bresenham(start: Cell, end: Cell) -> Bresenham {
+    let delta = Cell::new((end.x - start.x).abs(), -(end.y - start.y).abs());
+    Bresenham {
+        cell: start,
+        end,
+        delta,
+        step: Cell::new((end.x - start.x).signum(), (end.y - start.y).signum()),
+        error: delta.x as i64 + delta.y as i64,
+        done: false,
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = Cell;
+
+    fn next(&mut self) -> Option<Cell> {
+        if self.done {
+            return None;
+        }
+        let cell = self.cell;
+        if cell == self.end {
+            self.done = true;
+            return Some(cell);
+        }
+        let doubled = 2 * self.error;
+        if doubled >= self.delta.y as i64 {
+            self.error += self.delta.y as i64;
+            self.cell.x += self.step.x;
+        }
+        if doubled <= self.delta.x as i64 {
+            self.error += self.delta.x as i64;
+            self.cell.y += self.step.y;
+        }
+        Some(cell)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::core::{Angle, AngleMat, Extent, Point, Rotation, Vector};
+
+/// Implements conversions for one precision of glam's types.
+macro_rules! glam_impl {
+    ($N: ty, $V: ident, $M: ident) => {
+        impl From<Point<$N>> for ::glam::$V {
+            fn from(p: Point<$N>) -> Self {
+                ::glam::$V::new(p.x, p.y)
+            }
+        }
+
+        impl From<::glam::$V> for Point<$N> {
+            fn from(v: ::glam::$V) -> Self {
+                Point::new(v.x, v.y)
+            }
+        }
+
+        impl From<Vector<$N>> for ::glam::$V {
+            fn from(v: Vector<$N>) -> Self {
+                ::glam::$V::new(v.x, v.y)
+            }
+        }
+
+        impl From<::glam::$V> for Vector<$N> {
+            fn from(v: ::glam::$V) -> Self {
+                Vector::new(v.x, v.y)
+            }
+        }
+
+        impl From<Extent<$N>> for ::glam::$V {
+            fn from(e: Extent<$N>) -> Self {
+                ::glam::$V::new(e.width(), e.height())
+            }
+        }
+
+        impl From<::glam::$V> for Extent<$N> {
+            fn from(v: ::glam::$V) -> Self {
+                Extent::new(v.x, v.y)
+            }
+        }
+
+        impl From<AngleMat<$N>> for ::glam::$M {
+            fn from(m: AngleMat<$N>) -> Self {
+                ::glam::$M::from_cols(::glam::$V::new(m.m00, m.m01), ::glam::$V::new(m.m10, m.m11))
+            }
+        }
+
+        impl From<::glam::$M> for AngleMat<$N> {
+            fn from(m: ::glam::$M) -> Self {
+                AngleMat::new(m.x_axis.x, m.y_axis.x, m.x_axis.y, m.y_axis.y)
+            }
+        }
+
+        impl From<Rotation<$N>> for ::glam::$M {
+            fn from(r: Rotation<$N>) -> Self {
+                r.to_matrix().into()
+            }
+        }
+
+        impl From<Angle<$N>> for ::glam::$M {
+            fn from(a: Angle<$N>) -> Self {
+                a.to_matrix().into()
+            }
+        }
+    };
+}
+
+glam_impl!(f32, Vec2, Mat2);
+glam_impl!(f64, DVec2, DMat2);
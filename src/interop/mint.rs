@@ -0,0 +1,76 @@
+use crate::core::{AngleMat, Extent, Point, Vector};
+
+impl<N> From<Point<N>> for ::mint::Point2<N> {
+    fn from(p: Point<N>) -> Self {
+        ::mint::Point2 { x: p.x, y: p.y }
+    }
+}
+
+impl<N> From<::mint::Point2<N>> for Point<N> {
+    fn from(p: ::mint::Point2<N>) -> Self {
+        Point { x: p.x, y: p.y }
+    }
+}
+
+impl<N> From<Vector<N>> for ::mint::Vector2<N> {
+    fn from(v: Vector<N>) -> Self {
+        ::mint::Vector2 { x: v.x, y: v.y }
+    }
+}
+
+impl<N> From<::mint::Vector2<N>> for Vector<N> {
+    fn from(v: ::mint::Vector2<N>) -> Self {
+        Vector { x: v.x, y: v.y }
+    }
+}
+
+impl<N> From<Extent<N>> for ::mint::Vector2<N>
+where
+    N: crate::nums::PrimaNum,
+{
+    fn from(e: Extent<N>) -> Self {
+        ::mint::Vector2 {
+            x: e.width(),
+            y: e.height(),
+        }
+    }
+}
+
+impl<N> From<::mint::Vector2<N>> for Extent<N>
+where
+    N: crate::nums::PrimaNum,
+{
+    fn from(v: ::mint::Vector2<N>) -> Self {
+        Extent::new(v.x, v.y)
+    }
+}
+
+impl<N> From<AngleMat<N>> for ::mint::ColumnMatrix2<N> {
+    fn from(m: AngleMat<N>) -> Self {
+        ::mint::ColumnMatrix2 {
+            x: ::mint::Vector2 { x: m.m00, y: m.m01 },
+            y: ::mint::Vector2 { x: m.m10, y: m.m11 },
+        }
+    }
+}
+
+impl<N> From<::mint::ColumnMatrix2<N>> for AngleMat<N> {
+    fn from(m: ::mint::ColumnMatrix2<N>) -> Self {
+        AngleMat::new(m.x.x, m.y.x, m.x.y, m.y.y)
+    }
+}
+
+impl<N> From<AngleMat<N>> for ::mint::RowMatrix2<N> {
+    fn from(m: AngleMat<N>) -> Self {
+        ::mint::RowMatrix2 {
+            x: ::mint::Vector2 { x: m.m00, y: m.m10 },
+            y: ::mint::Vector2 { x: m.m01, y: m.m11 },
+        }
+    }
+}
+
+impl<N> From<::mint::RowMatrix2<N>> for AngleMat<N> {
+    fn from(m: ::mint::RowMatrix2<N>) -> Self {
+        AngleMat::new(m.x.x, m.x.y, m.y.x, m.y.y)
+    }
+}
@@ -0,0 +1,13 @@
+//! Conversions between prima's types and those of other geometry crates.
+//! Each crate is gated behind a feature of the same name.
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "parry2d")]
+mod parry2d;
+#[cfg(feature = "vek")]
+mod vek;
@@ -0,0 +1,134 @@
+use ::nalgebra::{Matrix2, Point2, RealField, Rotation2, Scalar, UnitComplex, Vector2};
+
+use crate::{
+    core::{Angle, AngleMat, Extent, Point, Rotation, Vector},
+    nums::{PrimaFloat, PrimaNum},
+};
+
+impl<N> From<Point<N>> for Point2<N>
+where
+    N: Scalar,
+{
+    fn from(p: Point<N>) -> Self {
+        Point2::new(p.x, p.y)
+    }
+}
+
+impl<N> From<Point2<N>> for Point<N>
+where
+    N: Scalar + PrimaNum,
+{
+    fn from(p: Point2<N>) -> Self {
+        Point::new(p.x, p.y)
+    }
+}
+
+impl<N> From<Vector<N>> for Vector2<N>
+where
+    N: Scalar,
+{
+    fn from(v: Vector<N>) -> Self {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl<N> From<Vector2<N>> for Vector<N>
+where
+    N: Scalar + PrimaNum,
+{
+    fn from(v: Vector2<N>) -> Self {
+        Vector::new(v.x, v.y)
+    }
+}
+
+impl<N> From<Extent<N>> for Vector2<N>
+where
+    N: Scalar + PrimaNum,
+{
+    fn from(e: Extent<N>) -> Self {
+        Vector2::new(e.width(), e.height())
+    }
+}
+
+impl<N> From<Vector2<N>> for Extent<N>
+where
+    N: Scalar + PrimaNum,
+{
+    fn from(v: Vector2<N>) -> Self {
+        Extent::new(v.x, v.y)
+    }
+}
+
+impl<N> From<AngleMat<N>> for Matrix2<N>
+where
+    N: Scalar,
+{
+    fn from(m: AngleMat<N>) -> Self {
+        Matrix2::new(m.m00, m.m10, m.m01, m.m11)
+    }
+}
+
+impl<N> From<Matrix2<N>> for AngleMat<N>
+where
+    N: Scalar,
+{
+    fn from(m: Matrix2<N>) -> Self {
+        AngleMat::new(m[(0, 0)].clone(), m[(0, 1)].clone(), m[(1, 0)].clone(), m[(1, 1)].clone())
+    }
+}
+
+// Prima's rotations run clockwise, while nalgebra's run counter-clockwise.
+
+impl<N> From<Rotation<N>> for Rotation2<N>
+where
+    N: PrimaFloat + RealField,
+{
+    fn from(r: Rotation<N>) -> Self {
+        Rotation2::new(-r.as_radians())
+    }
+}
+
+impl<N> From<Rotation2<N>> for Rotation<N>
+where
+    N: PrimaFloat + RealField,
+{
+    fn from(r: Rotation2<N>) -> Self {
+        Rotation::from_radians(-r.angle())
+    }
+}
+
+impl<N> From<Rotation<N>> for UnitComplex<N>
+where
+    N: PrimaFloat + RealField,
+{
+    fn from(r: Rotation<N>) -> Self {
+        UnitComplex::new(-r.as_radians())
+    }
+}
+
+impl<N> From<UnitComplex<N>> for Rotation<N>
+where
+    N: PrimaFloat + RealField,
+{
+    fn from(r: UnitComplex<N>) -> Self {
+        Rotation::from_radians(-r.angle())
+    }
+}
+
+impl<N> From<Angle<N>> for Rotation2<N>
+where
+    N: PrimaFloat + RealField,
+{
+    fn from(a: Angle<N>) -> Self {
+        Rotation2::new(-a.as_radians())
+    }
+}
+
+impl<N> From<Angle<N>> for UnitComplex<N>
+where
+    N: PrimaFloat + RealField,
+{
+    fn from(a: Angle<N>) -> Self {
+        UnitComplex::new(-a.as_radians())
+    }
+}
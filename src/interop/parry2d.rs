@@ -0,0 +1,56 @@
+use ::parry2d::{
+    bounding_volume::{Aabb, BoundingSphere},
+    math::Vector as ParryVector,
+    shape::{Ball, Cuboid},
+};
+
+use crate::{
+    core::Point,
+    shapes::{Aabr, Circle},
+};
+
+// parry2d works in single precision, so these conversions only cover `f32`.
+
+impl From<Aabr<f32>> for Aabb {
+    fn from(aabr: Aabr<f32>) -> Self {
+        Aabb::new(
+            ParryVector::new(aabr.min.x, aabr.min.y),
+            ParryVector::new(aabr.max.x, aabr.max.y),
+        )
+    }
+}
+
+impl From<Aabb> for Aabr<f32> {
+    fn from(aabb: Aabb) -> Self {
+        Aabr::new(
+            Point::new(aabb.mins.x, aabb.mins.y),
+            Point::new(aabb.maxs.x, aabb.maxs.y),
+        )
+    }
+}
+
+impl From<Aabr<f32>> for Cuboid {
+    /// The cuboid only captures the size of the rectangle, as parry stores positions separately.
+    fn from(aabr: Aabr<f32>) -> Self {
+        Cuboid::new(ParryVector::new(aabr.width() / 2.0, aabr.height() / 2.0))
+    }
+}
+
+impl From<Circle<f32>> for BoundingSphere {
+    fn from(circle: Circle<f32>) -> Self {
+        BoundingSphere::new(ParryVector::new(circle.center.x, circle.center.y), circle.radius)
+    }
+}
+
+impl From<BoundingSphere> for Circle<f32> {
+    fn from(sphere: BoundingSphere) -> Self {
+        Circle::new(Point::new(sphere.center.x, sphere.center.y), sphere.radius)
+    }
+}
+
+impl From<Circle<f32>> for Ball {
+    /// The ball only captures the radius of the circle, as parry stores positions separately.
+    fn from(circle: Circle<f32>) -> Self {
+        Ball::new(circle.radius)
+    }
+}
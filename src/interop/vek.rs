@@ -0,0 +1,112 @@
+use crate::{
+    core::{AngleMat, Extent, Point, Vector},
+    curves::Bezier,
+    nums::PrimaNum,
+    shapes::{Aabr, Circle},
+};
+
+impl<N> From<Point<N>> for ::vek::Vec2<N> {
+    fn from(p: Point<N>) -> Self {
+        ::vek::Vec2::new(p.x, p.y)
+    }
+}
+
+impl<N> From<::vek::Vec2<N>> for Point<N> {
+    fn from(v: ::vek::Vec2<N>) -> Self {
+        Point { x: v.x, y: v.y }
+    }
+}
+
+impl<N> From<Vector<N>> for ::vek::Vec2<N> {
+    fn from(v: Vector<N>) -> Self {
+        ::vek::Vec2::new(v.x, v.y)
+    }
+}
+
+impl<N> From<::vek::Vec2<N>> for Vector<N> {
+    fn from(v: ::vek::Vec2<N>) -> Self {
+        Vector { x: v.x, y: v.y }
+    }
+}
+
+impl<N> From<Extent<N>> for ::vek::Extent2<N>
+where
+    N: PrimaNum,
+{
+    fn from(e: Extent<N>) -> Self {
+        ::vek::Extent2::new(e.width(), e.height())
+    }
+}
+
+impl<N> From<::vek::Extent2<N>> for Extent<N>
+where
+    N: PrimaNum,
+{
+    fn from(e: ::vek::Extent2<N>) -> Self {
+        Extent::new(e.w, e.h)
+    }
+}
+
+impl<N> From<AngleMat<N>> for ::vek::Mat2<N> {
+    fn from(m: AngleMat<N>) -> Self {
+        ::vek::Mat2::from_col_arrays([[m.m00, m.m01], [m.m10, m.m11]])
+    }
+}
+
+impl<N> From<::vek::Mat2<N>> for AngleMat<N> {
+    fn from(m: ::vek::Mat2<N>) -> Self {
+        let [[m00, m01], [m10, m11]] = m.into_col_arrays();
+        AngleMat::new(m00, m10, m01, m11)
+    }
+}
+
+impl<N> From<Aabr<N>> for ::vek::Aabr<N> {
+    fn from(aabr: Aabr<N>) -> Self {
+        ::vek::Aabr {
+            min: aabr.min.into(),
+            max: aabr.max.into(),
+        }
+    }
+}
+
+impl<N> From<::vek::Aabr<N>> for Aabr<N> {
+    fn from(aabr: ::vek::Aabr<N>) -> Self {
+        Aabr {
+            min: aabr.min.into(),
+            max: aabr.max.into(),
+        }
+    }
+}
+
+impl<N> From<Circle<N>> for ::vek::Disk<N, N> {
+    fn from(circle: Circle<N>) -> Self {
+        ::vek::Disk {
+            center: circle.center.into(),
+            radius: circle.radius,
+        }
+    }
+}
+
+impl<N> From<::vek::Disk<N, N>> for Circle<N> {
+    fn from(disk: ::vek::Disk<N, N>) -> Self {
+        Circle::new(disk.center.into(), disk.radius)
+    }
+}
+
+impl<N> From<::vek::QuadraticBezier2<N>> for Bezier<N>
+where
+    N: PrimaNum,
+{
+    fn from(b: ::vek::QuadraticBezier2<N>) -> Self {
+        Bezier::quadratic(b.start.into(), b.ctrl.into(), b.end.into())
+    }
+}
+
+impl<N> From<::vek::CubicBezier2<N>> for Bezier<N>
+where
+    N: PrimaNum,
+{
+    fn from(b: ::vek::CubicBezier2<N>) -> Self {
+        Bezier::cubic(b.start.into(), b.ctrl0.into(), b.ctrl1.into(), b.end.into())
+    }
+}
@@ -10,9 +10,45 @@ pub mod core;
 /// Geometry primitives.
 pub mod shapes;
 
+/// Parametric curves and splines.
+pub mod curves;
+
 /// various utility structs and enums for common geometric concepts.
 pub mod abstracts;
 
+/// Batch collision queries over structure-of-arrays shape collections.
+pub mod batch;
+
+/// Mixed shape collections with filtered broad phase queries.
+pub mod world;
+
+/// Combinators for building signed distance fields from shapes.
+pub mod sdf;
+
+/// Integer grid cells, traversal and rasterisation.
+pub mod grid;
+
+/// Navigation meshes, pathfinding and line of sight.
+pub mod nav;
+
+/// Conversions to and from other geometry crates.
+mod interop;
+
+/// Reading and writing shapes in file formats.
+pub mod formats;
+
+/// Random point sampling inside shapes and along their edges.
+#[cfg(feature = "rand_core")]
+pub mod sampling;
+
+/// Proptest strategies for generating arbitrary geometry.
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+
+/// Serialization helpers for storing angles in a chosen unit.
+#[cfg(feature = "serde")]
+pub mod serde_angle;
+
 /// Useful macros for geometric types (mainly internal use).
 pub mod macros;
 
@@ -24,10 +60,14 @@ pub mod traits;
 
 /// A collection of commonly used imports for this library.
 pub mod prelude {
-    pub use crate::core::{Angle, Collision, Line, Point, Rotation, Vector, Extent};
+    pub use crate::core::{
+        Angle, Collision, Extent, Line, Mat2, Mat3, Point, Polyline, Rotation, Vector,
+    };
+    pub use crate::curves::{Bezier, CatmullRom};
     pub use crate::nums::{PrimaFloat, PrimaNum};
-    pub use crate::shapes::{Aabr, Circle, Obr};
+    pub use crate::shapes::{Aabr, AnyShape, Capsule, Circle, Obr, Polygon, RoundedPolygon};
     pub use crate::traits::{
-        Collide, Curved, Distance, Flat, LocalPosition, LocalRotation, Magnitude, Nearest, Shape,
+        Collide, Curve, Curved, Distance, Flat, Intersections, LocalPosition, LocalRotation,
+        Magnitude, Minkowski, Nearest, Shape, SignedDistance,
     };
 }
@@ -3,39 +3,72 @@
 macro_rules! rotation_impl(
     ($T: ident) => {
         impl<N> $T<N> where N: PrimaFloat {
-            /// Creates a new rotation at 0 radians.
+            /// Creates a new rotation of zero.
             pub fn zero() -> Self {
-                Self::new(N::zero())
+                Self::from_radians(N::zero())
             }
 
-            /// Creates a new rotation from radians. Same as `new`. just explicit.
-            pub fn from_radians(rads: N) -> Self {
-                Self::new(rads)
+            /// Creates a new rotation from degrees.
+            pub fn from_degrees(degs: N) -> Self {
+                Self::from_radians(degs.to_radians())
+            }
+
+            /// Creates a new rotation from whole turns, where 1 is a full circle.
+            pub fn from_turns(turns: N) -> Self {
+                Self::from_radians(turns * N::pi() * (N::one() + N::one()))
+            }
+
+            /// Creates a new rotation from half turns, where 1 is π radians.
+            pub fn from_half_turns(half_turns: N) -> Self {
+                Self::from_radians(half_turns * N::pi())
             }
 
-            /// Use this when passing in a literal value for radians.
+            /// Creates a new rotation from radians.
+            #[deprecated(note = "rotations are now stored in radians, use `from_radians`")]
             pub fn from_radians_pi(rads: N) -> Self {
-                Self::from_radians(rads / N::pi())
+                Self::from_radians(rads)
             }
 
-            /// Creates a new rotation from degrees.
-            pub fn from_degrees(degs: N) -> Self {
-                Self::new(degs / N::from_u8(180).unwrap())
+            /// Creates a rotation that points along the given vector, measured clockwise from up.
+            /// A zero vector gives a rotation of zero.
+            pub fn from_vector(vector: Vector<N>) -> Self {
+                Self::from_radians(vector.x.atan2(vector.y))
+            }
+
+            /// Returns the rotation in radians.
+            pub fn as_radians(&self) -> N {
+                self.0
             }
 
             /// Returns the rotation in degrees.
             pub fn as_degrees(&self) -> N {
-                self.0 * N::from_u8(180).unwrap()
+                self.0.to_degrees()
+            }
+
+            /// Returns the rotation in whole turns, where 1 is a full circle.
+            pub fn as_turns(&self) -> N {
+                self.0 / (N::pi() * (N::one() + N::one()))
+            }
+
+            /// Returns the rotation in half turns, where 1 is π radians.
+            pub fn as_half_turns(&self) -> N {
+                self.0 / N::pi()
             }
 
             /// Returns the rotation in radians.
-            pub fn as_radians(&self) -> N {
+            #[deprecated(note = "rotations are now stored in radians, use `as_radians`")]
+            pub fn as_radians_pi(&self) -> N {
                 self.0
             }
 
-            /// Returns the angle in radians times by pi.
-            pub fn as_radians_pi(&self) -> N {
-                self.0 * N::pi()
+            /// Returns the rotation wrapped to the range [0..2π).
+            pub fn as_unsigned_radians(&self) -> N {
+                normalize_radians(self.0)
+            }
+
+            /// Returns the rotation wrapped to the range (-π..π].
+            pub fn as_signed_radians(&self) -> N {
+                normalize_radians_signed(self.0)
             }
 
             /// Returns the angle matrix for this rotation.
@@ -51,52 +84,55 @@ macro_rules! rotation_impl(
             /// Returns true if the rotation is aligned with the x or y axis.
             /// This essentially checks if facing up, down, left or right.
             pub fn is_axis_aligned(&self) -> bool {
-                self.0 % (N::one() / (N::one() + N::one())) == N::zero()
+                let quarters = self.0 / (N::pi() / (N::one() + N::one()));
+                let tolerance = N::epsilon() * N::from_u8(16).unwrap() * quarters.abs().max(N::one());
+                (quarters - quarters.round()).abs() <= tolerance
             }
 
             /// Returns the sine of the angle.
             pub fn sin(&self) -> N {
-                self.as_radians_pi().sin()
+                self.0.sin()
             }
 
             /// Returns the co-sine of the angle.
             pub fn cos(&self) -> N {
-                self.as_radians_pi().cos()
+                self.0.cos()
             }
 
             /// Returns the tangent of the angle.
             pub fn tan(&self) -> N {
-                self.as_radians_pi().tan()
+                self.0.tan()
+            }
+
+            /// Returns the sine and co-sine of the angle.
+            pub fn sin_cos(&self) -> (N, N) {
+                self.0.sin_cos()
             }
 
             /// Mirrors the rotation.
             pub fn mirror(self) -> Self {
-                Self::new(N::zero() - self.as_radians())
+                Self::from_radians(-self.0)
             }
 
-            /// Returns the rotation from self to other.
+            /// Returns the shortest rotation from self to other, in the range (-π..π].
             pub fn rotation_to(&self, other: &Self) -> Rotation<N> {
-                let a = N::one() + N::one();
-                let b = a / (N::one() + N::one());
-                let t = (other.0 - self.0 + a + b) % a - b;
-                Rotation::new(t)
+                Rotation::from_radians(normalize_radians_signed(other.0 - self.0))
             }
 
-            /// returns the rotation from other to self._
+            /// Returns the shortest rotation from other to self, in the range (-π..π].
             pub fn rotation_from(&self, other: &Self) -> Rotation<N> {
                 other.rotation_to(self)
             }
 
-            /// Lerp from self to other.
-            pub fn lerp(&self, other: &Self, t: N) -> Self {
-                let rotation = self.rotation_to(other) * t;
-                *self + rotation
+            /// Interpolates from self to other along the shortest arc between them.
+            pub fn lerp_shortest(&self, other: &Self, t: N) -> Self {
+                Self::from_radians(self.0 + self.rotation_to(other).0 * t)
             }
 
-            /// Converts the rotation to a vector.
+            /// Returns a unit vector pointing in the direction of the rotation, measured clockwise from up.
             pub fn as_vector(&self) -> Vector<N> {
-                let v = self.as_radians_pi();
-                Vector::new(v.sin(), v.cos())
+                let (s, c) = snapped_sin_cos(self.0);
+                Vector::new(s, c)
             }
         }
 
@@ -107,7 +143,7 @@ macro_rules! rotation_impl(
             type Output = Self;
 
             fn add(self, rhs: Self) -> Self {
-                Self::new(self.0 + rhs.0)
+                Self::from_radians(self.0 + rhs.0)
             }
         }
 
@@ -116,7 +152,7 @@ macro_rules! rotation_impl(
             N: PrimaFloat,
         {
             fn add_assign(&mut self, rhs: Self) {
-                self.0 = Self::new(self.0 + rhs.0).0;
+                *self = *self + rhs;
             }
         }
 
@@ -127,7 +163,7 @@ macro_rules! rotation_impl(
             type Output = Self;
 
             fn sub(self, rhs: Self) -> Self {
-                Self::new(self.0 - rhs.0)
+                Self::from_radians(self.0 - rhs.0)
             }
         }
 
@@ -136,7 +172,7 @@ macro_rules! rotation_impl(
             N: PrimaFloat,
         {
             fn sub_assign(&mut self, rhs: Self) {
-                self.0 = self.0 - rhs.0;
+                *self = *self - rhs;
             }
         }
 
@@ -147,7 +183,7 @@ macro_rules! rotation_impl(
             type Output = Self;
 
             fn neg(self) -> Self {
-                Self::new(N::zero() - self.0)
+                Self::from_radians(-self.0)
             }
         }
 
@@ -158,7 +194,7 @@ macro_rules! rotation_impl(
             type Output = Self;
 
             fn mul(self, rhs: N) -> Self {
-                Self::new(self.0 * rhs)
+                Self::from_radians(self.0 * rhs)
             }
         }
 
@@ -167,7 +203,7 @@ macro_rules! rotation_impl(
             N: PrimaFloat,
         {
             fn mul_assign(&mut self, rhs: N) {
-                self.0 = Self::new(self.0 * rhs).0;
+                *self = *self * rhs;
             }
         }
 
@@ -178,7 +214,7 @@ macro_rules! rotation_impl(
             type Output = Self;
 
             fn div(self, rhs: N) -> Self {
-                Self::new(self.0 / rhs)
+                Self::from_radians(self.0 / rhs)
             }
         }
 
@@ -187,37 +223,27 @@ macro_rules! rotation_impl(
             N: PrimaFloat,
         {
             fn div_assign(&mut self, rhs: N) {
-                self.0 = Self::new(self.0 / rhs).0;
+                *self = *self / rhs;
             }
         }
 
-        impl<N> Into<AngleMat<N>> for $T<N>
+        impl<N> From<$T<N>> for AngleMat<N>
         where
             N: PrimaFloat,
         {
-            fn into(self) -> AngleMat<N> {
-                let two = N::one() + N::one();
-                let half = N::one() / two;
-
-                let r = -self.as_radians();
-
-                let (c, s) = if r % half == N::zero() {
-                    if r % two == N::zero() {
-                        (N::one(), N::zero())
-                    } else if r % N::one() == N::zero() {
-                        (N::zero() -N::one(), N::zero())
-                    } else if r % (N::one() + half) == N::zero() {
-                        (N::zero(), N::one())
-                    } else {
-                        (N::zero(), N::zero() -N::one())
-                    }
-                } else {
-                    let r = -self.as_radians_pi();
-                    (r.cos(), r.sin())
-                };
-
-                let mat = AngleMat::<N>::new(c, -s, s, c);
-                mat
+            fn from(rotation: $T<N>) -> AngleMat<N> {
+                let (s, c) = snapped_sin_cos(-rotation.0);
+                AngleMat::<N>::new(c, -s, s, c)
+            }
+        }
+
+        impl<N> From<AngleMat<N>> for $T<N>
+        where
+            N: PrimaFloat,
+        {
+            /// Recovers the rotation from a matrix, assuming it has no scale or shear.
+            fn from(matrix: AngleMat<N>) -> $T<N> {
+                Self::from_radians(matrix.m10.atan2(matrix.m00))
             }
         }
     }
@@ -287,7 +313,7 @@ macro_rules! xy_impl(
             }
         }
 
-        impl<N> Mul<N> for $T<N> where N: PrimaFloat {
+        impl<N> Mul<N> for $T<N> where N: PrimaNum {
             type Output = Self;
 
             fn mul(self, rhs: N) -> Self {
@@ -295,7 +321,13 @@ macro_rules! xy_impl(
             }
         }
 
-        impl<N> Div<N> for $T<N> where N: PrimaFloat {
+        impl<N> std::ops::MulAssign<N> for $T<N> where N: PrimaNum {
+            fn mul_assign(&mut self, rhs: N) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<N> Div<N> for $T<N> where N: PrimaNum {
             type Output = Self;
 
             fn div(self, rhs: N) -> Self {
@@ -303,6 +335,94 @@ macro_rules! xy_impl(
             }
         }
 
+        impl<N> std::ops::DivAssign<N> for $T<N> where N: PrimaNum {
+            fn div_assign(&mut self, rhs: N) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<N> std::ops::Rem<N> for $T<N> where N: PrimaNum {
+            type Output = Self;
+
+            fn rem(self, rhs: N) -> Self {
+                Self::new(self.x % rhs, self.y % rhs)
+            }
+        }
+
+        impl<N> std::ops::RemAssign<N> for $T<N> where N: PrimaNum {
+            fn rem_assign(&mut self, rhs: N) {
+                *self = *self % rhs;
+            }
+        }
+
+        /// Multiplies each component by the matching component of the other.
+        impl<N> Mul for $T<N> where N: PrimaNum {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                Self::new(self.x * rhs.x, self.y * rhs.y)
+            }
+        }
+
+        impl<N> std::ops::MulAssign for $T<N> where N: PrimaNum {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        /// Divides each component by the matching component of the other.
+        impl<N> Div for $T<N> where N: PrimaNum {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                Self::new(self.x / rhs.x, self.y / rhs.y)
+            }
+        }
+
+        impl<N> std::ops::DivAssign for $T<N> where N: PrimaNum {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        /// Takes the remainder of each component by the matching component of the other.
+        impl<N> std::ops::Rem for $T<N> where N: PrimaNum {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self {
+                Self::new(self.x % rhs.x, self.y % rhs.y)
+            }
+        }
+
+        impl<N> std::ops::RemAssign for $T<N> where N: PrimaNum {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs;
+            }
+        }
+
+        /// Indexes the components, with x at 0 and y at 1.
+        impl<N> std::ops::Index<usize> for $T<N> {
+            type Output = N;
+
+            fn index(&self, index: usize) -> &N {
+                match index {
+                    0 => &self.x,
+                    1 => &self.y,
+                    _ => panic!("index {} is out of range for a 2D {}", index, stringify!($T)),
+                }
+            }
+        }
+
+        impl<N> std::ops::IndexMut<usize> for $T<N> {
+            fn index_mut(&mut self, index: usize) -> &mut N {
+                match index {
+                    0 => &mut self.x,
+                    1 => &mut self.y,
+                    _ => panic!("index {} is out of range for a 2D {}", index, stringify!($T)),
+                }
+            }
+        }
+
         impl<N> Mul<AngleMat<N>> for $T<N>
         where N: Mul<Output = N> + Add<Output = N> + Copy {
             type Output = Self;
@@ -343,9 +463,35 @@ macro_rules! xy_impl(
             }
         }
 
-        impl<N> Into<(N, N)> for $T<N> where N: PrimaNum {
-            fn into(self) -> (N, N) {
-                (self.x, self.y)
+        impl<N> $T<N> where N: PrimaNum + num_traits::NumCast {
+            /// Casts each component to another number type, returning None if either
+            /// does not fit, such as a NaN or out of range float cast to an integer.
+            pub fn cast<M>(&self) -> Option<$T<M>> where M: PrimaNum + num_traits::NumCast {
+                Some($T::new(M::from(self.x)?, M::from(self.y)?))
+            }
+        }
+
+        impl<N> From<(N, N)> for $T<N> {
+            fn from((x, y): (N, N)) -> Self {
+                $T { x, y }
+            }
+        }
+
+        impl<N> From<[N; 2]> for $T<N> {
+            fn from([x, y]: [N; 2]) -> Self {
+                $T { x, y }
+            }
+        }
+
+        impl<N> From<$T<N>> for (N, N) {
+            fn from(value: $T<N>) -> Self {
+                (value.x, value.y)
+            }
+        }
+
+        impl<N> From<$T<N>> for [N; 2] {
+            fn from(value: $T<N>) -> Self {
+                [value.x, value.y]
             }
         }
     }
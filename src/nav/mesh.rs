@@ -0,0 +1,383 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
+
+use super::triangulation::{conforming_delaunay, orient, P64};
+use crate::{
+    core::{Point, Polyline},
+    nums::PrimaFloat,
+    shapes::{Aabr, Circle, Obr, Polygon},
+    traits::{Distance, Flat, Shape},
+};
+
+/// The number of sides used when approximating circular obstacles.
+pub(crate) const CIRCLE_SIDES: usize = 16;
+
+/// Something that blocks movement within a [NavMesh].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: PrimaFloat + serde::Serialize",
+        deserialize = "N: PrimaFloat + serde::Deserialize<'de>"
+    ))
+)]
+pub enum Obstacle<N> {
+    /// An axis-aligned rectangle.
+    Aabr(Aabr<N>),
+    /// A circle.
+    Circle(Circle<N>),
+    /// An orientated rectangle.
+    Obr(Obr<N>),
+    /// An arbitrary polygon.
+    Polygon(Polygon<N>),
+}
+
+impl<N> Obstacle<N>
+where
+    N: PrimaFloat,
+{
+    /// Returns the outline of the obstacle as a counter-clockwise polygon, grown by the given radius.
+    /// Circles are approximated with a polygon that fully encloses them.
+    pub fn outline(&self, radius: N) -> Polygon<N> {
+        let mut polygon = match self {
+            Obstacle::Circle(circle) => {
+                let half_step = N::pi() / N::from_usize(CIRCLE_SIDES).unwrap();
+                let r = (circle.radius + radius) / half_step.cos();
+                return Circle::new(circle.center, r).to_polygon(CIRCLE_SIDES);
+            }
+            Obstacle::Aabr(aabr) => Polygon::new(aabr.vertices()),
+            Obstacle::Obr(obr) => Polygon::new(obr.vertices()),
+            Obstacle::Polygon(polygon) => polygon.clone(),
+        };
+        if polygon.is_clockwise() {
+            polygon.reverse();
+        }
+        if radius > N::zero() {
+            polygon = polygon.offset(radius);
+        }
+        polygon
+    }
+}
+
+impl<N> From<Aabr<N>> for Obstacle<N> {
+    fn from(aabr: Aabr<N>) -> Self {
+        Obstacle::Aabr(aabr)
+    }
+}
+
+impl<N> From<Circle<N>> for Obstacle<N> {
+    fn from(circle: Circle<N>) -> Self {
+        Obstacle::Circle(circle)
+    }
+}
+
+impl<N> From<Obr<N>> for Obstacle<N> {
+    fn from(obr: Obr<N>) -> Self {
+        Obstacle::Obr(obr)
+    }
+}
+
+impl<N> From<Polygon<N>> for Obstacle<N> {
+    fn from(polygon: Polygon<N>) -> Self {
+        Obstacle::Polygon(polygon)
+    }
+}
+
+/// A single triangle of a [NavMesh].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NavTriangle {
+    /// Indices of the triangle's vertices, wound counter-clockwise.
+    pub vertices: [usize; 3],
+    /// The neighbouring triangle across each edge, where edge `i` runs from vertex `i` to vertex `i + 1`.
+    pub neighbours: [Option<usize>; 3],
+}
+
+/// A triangulated navigation mesh covering the walkable space of an area.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NavMesh<N> {
+    /// The vertices of the mesh.
+    pub vertices: Vec<Point<N>>,
+    /// The walkable triangles of the mesh.
+    pub triangles: Vec<NavTriangle>,
+    /// False if the mesh could not be fitted to every wall, which happens when walls meet at
+    /// extremely sharp angles or lie too close together. Triangles of such a mesh may cross
+    /// walls, so paths through it can pass through obstacles.
+    pub conforming: bool,
+}
+
+impl<N> NavMesh<N>
+where
+    N: PrimaFloat,
+{
+    /// The number of wall splits [NavMesh::build] allows before giving up.
+    pub const DEFAULT_SPLIT_LIMIT: usize = 4096;
+
+    /// Builds a navigation mesh from a walkable boundary and a set of obstacles.
+    /// Both the boundary and the obstacles are inflated by `agent_radius`, so that any path
+    /// through the mesh keeps an agent of that size clear of the walls.
+    /// Walls are split at most [NavMesh::DEFAULT_SPLIT_LIMIT] times while fitting the mesh to
+    /// them; check [NavMesh::conforming] to see whether that was enough.
+    pub fn build(boundary: &Polygon<N>, obstacles: &[Obstacle<N>], agent_radius: N) -> Self {
+        Self::build_with_split_limit(boundary, obstacles, agent_radius, Self::DEFAULT_SPLIT_LIMIT)
+    }
+
+    /// Builds a navigation mesh like [NavMesh::build], splitting walls at most `split_limit`
+    /// times. Large or detailed maps may need a higher limit to produce a conforming mesh.
+    pub fn build_with_split_limit(
+        boundary: &Polygon<N>,
+        obstacles: &[Obstacle<N>],
+        agent_radius: N,
+        split_limit: usize,
+    ) -> Self {
+        let mut boundary = boundary.clone();
+        if boundary.is_clockwise() {
+            boundary.reverse();
+        }
+        if agent_radius > N::zero() {
+            boundary = boundary.offset(-agent_radius);
+        }
+        let outlines: Vec<Polygon<N>> = obstacles.iter().map(|o| o.outline(agent_radius)).collect();
+
+        let to64 = |p: &Point<N>| -> P64 { [p.x.to_f64().unwrap(), p.y.to_f64().unwrap()] };
+        let mut segments = Vec::new();
+        for ring in std::iter::once(&boundary).chain(outlines.iter()) {
+            for edge in ring.edges() {
+                segments.push((to64(&edge.start), to64(&edge.end)));
+            }
+        }
+
+        let bounds = boundary.bounding_rect();
+        let size = (bounds.width().max(bounds.height())).to_f64().unwrap();
+        let triangulation = conforming_delaunay(&segments, size * 1e-7, split_limit);
+
+        let vertices: Vec<Point<N>> = triangulation
+            .points
+            .iter()
+            .map(|p| Point::new(N::from_f64(p[0]).unwrap(), N::from_f64(p[1]).unwrap()))
+            .collect();
+
+        // Keep only the triangles that sit inside the boundary and outside of every obstacle.
+        let three = N::from_u8(3).unwrap();
+        let walkable: Vec<[usize; 3]> = triangulation
+            .triangles
+            .into_iter()
+            .filter(|t| {
+                let [a, b, c] = t.map(|i| triangulation.points[i]);
+                if orient(a, b, c) <= 0.0 {
+                    return false;
+                }
+                let [a, b, c] = t.map(|i| vertices[i]);
+                let centroid = Point::new((a.x + b.x + c.x) / three, (a.y + b.y + c.y) / three);
+                boundary.contains(&centroid) && !outlines.iter().any(|o| o.contains(&centroid))
+            })
+            .collect();
+
+        // Link up neighbours through their shared edges.
+        let mut edges: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+        let mut triangles: Vec<NavTriangle> = walkable
+            .iter()
+            .map(|t| NavTriangle {
+                vertices: *t,
+                neighbours: [None; 3],
+            })
+            .collect();
+        for (index, t) in walkable.iter().enumerate() {
+            for edge in 0..3 {
+                let (a, b) = (t[edge], t[(edge + 1) % 3]);
+                if let Some((other, other_edge)) = edges.remove(&(b, a)) {
+                    triangles[index].neighbours[edge] = Some(other);
+                    triangles[other].neighbours[other_edge] = Some(index);
+                } else {
+                    edges.insert((a, b), (index, edge));
+                }
+            }
+        }
+
+        Self {
+            vertices,
+            triangles,
+            conforming: triangulation.conforming,
+        }
+    }
+
+    /// Returns the three corners of a triangle.
+    pub fn triangle_points(&self, index: usize) -> [Point<N>; 3] {
+        self.triangles[index].vertices.map(|i| self.vertices[i])
+    }
+
+    /// Returns the centroid of a triangle.
+    pub fn triangle_center(&self, index: usize) -> Point<N> {
+        let [a, b, c] = self.triangle_points(index);
+        let three = N::from_u8(3).unwrap();
+        Point::new((a.x + b.x + c.x) / three, (a.y + b.y + c.y) / three)
+    }
+
+    /// Returns the index of the triangle containing the given point, if any.
+    pub fn triangle_at(&self, point: &Point<N>) -> Option<usize> {
+        (0..self.triangles.len()).find(|i| {
+            let [a, b, c] = self.triangle_points(*i);
+            side(a, b, *point) >= N::zero()
+                && side(b, c, *point) >= N::zero()
+                && side(c, a, *point) >= N::zero()
+        })
+    }
+
+    /// Finds the shortest path between two points, using A* across the triangles and then
+    /// pulling the path taut with the funnel algorithm. Returns `None` if either point is
+    /// outside of the mesh, or if there is no route between them.
+    pub fn find_path(&self, start: Point<N>, end: Point<N>) -> Option<Polyline<N>> {
+        let from = self.triangle_at(&start)?;
+        let to = self.triangle_at(&end)?;
+        let corridor = self.corridor(from, to, &end)?;
+
+        // Build the portals that the path must pass through, as (left, right) pairs.
+        let mut portals = vec![(start, start)];
+        for pair in corridor.windows(2) {
+            let t = &self.triangles[pair[0]];
+            let edge = t.neighbours.iter().position(|n| *n == Some(pair[1]))?;
+            let right = self.vertices[t.vertices[edge]];
+            let left = self.vertices[t.vertices[(edge + 1) % 3]];
+            portals.push((left, right));
+        }
+        portals.push((end, end));
+
+        Some(Polyline::new(string_pull(&portals)))
+    }
+
+    /// A* search over the triangle graph, returning the chain of triangles from `from` to `to`.
+    fn corridor(&self, from: usize, to: usize, goal: &Point<N>) -> Option<Vec<usize>> {
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<usize, usize> = HashMap::new();
+        let mut cost = vec![N::infinity(); self.triangles.len()];
+        cost[from] = N::zero();
+        open.push(Node {
+            priority: self.triangle_center(from).distance(goal),
+            index: from,
+        });
+
+        while let Some(Node { index, .. }) = open.pop() {
+            if index == to {
+                let mut path = vec![to];
+                let mut current = to;
+                while let Some(previous) = came_from.get(&current) {
+                    current = *previous;
+                    path.push(current);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let center = self.triangle_center(index);
+            for next in self.triangles[index].neighbours.iter().flatten() {
+                let next_center = self.triangle_center(*next);
+                let g = cost[index] + center.distance(&next_center);
+                if g < cost[*next] {
+                    cost[*next] = g;
+                    came_from.insert(*next, index);
+                    open.push(Node {
+                        priority: g + next_center.distance(goal),
+                        index: *next,
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Twice the signed area of abc. Positive when c lies to the left of a -> b.
+fn side<N>(a: Point<N>, b: Point<N>, c: Point<N>) -> N
+where
+    N: PrimaFloat,
+{
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// The simple stupid funnel algorithm, which pulls a path taut through a list of portals.
+fn string_pull<N>(portals: &[(Point<N>, Point<N>)]) -> Vec<Point<N>>
+where
+    N: PrimaFloat,
+{
+    let mut path = vec![portals[0].0];
+    let mut apex = portals[0].0;
+    let (mut left, mut right) = (apex, apex);
+    let (mut left_index, mut right_index) = (0, 0);
+
+    let mut i = 1;
+    while i < portals.len() {
+        let (next_left, next_right) = portals[i];
+
+        // Try to narrow the right side of the funnel.
+        if side(apex, right, next_right) >= N::zero() {
+            if apex == right || side(apex, left, next_right) < N::zero() {
+                right = next_right;
+                right_index = i;
+            } else {
+                // The right side crossed over the left, so the left becomes a corner.
+                path.push(left);
+                apex = left;
+                right = apex;
+                right_index = left_index;
+                i = left_index + 1;
+                continue;
+            }
+        }
+
+        // Try to narrow the left side of the funnel.
+        if side(apex, left, next_left) <= N::zero() {
+            if apex == left || side(apex, right, next_left) > N::zero() {
+                left = next_left;
+                left_index = i;
+            } else {
+                // The left side crossed over the right, so the right becomes a corner.
+                path.push(right);
+                apex = right;
+                left = apex;
+                left_index = right_index;
+                i = right_index + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+
+    let end = portals[portals.len() - 1].0;
+    if path.last() != Some(&end) {
+        path.push(end);
+    }
+    path.dedup();
+    path
+}
+
+/// An entry in the A* open set, ordered so that the lowest priority is popped first.
+struct Node<N> {
+    priority: N,
+    index: usize,
+}
+
+impl<N: PartialOrd> PartialEq for Node<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N: PartialOrd> Eq for Node<N> {}
+
+impl<N: PartialOrd> PartialOrd for Node<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: PartialOrd> Ord for Node<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .partial_cmp(&self.priority)
+            .unwrap_or(Ordering::Equal)
+    }
+}
@@ -0,0 +1,6 @@
+mod mesh;
+mod triangulation;
+mod visibility;
+
+pub use mesh::*;
+pub use visibility::*;
@@ -0,0 +1,209 @@
+//! A conforming Delaunay triangulation, used to build navigation meshes.
+//! Everything in here runs in double precision, as the large super-triangle
+//! and the circumcircle tests are too sensitive for single precision floats.
+
+/// A point in double precision.
+pub(crate) type P64 = [f64; 2];
+
+/// Twice the signed area of the triangle abc. Positive when c lies to the left of a -> b.
+pub(crate) fn orient(a: P64, b: P64, c: P64) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// The result of a triangulation. Every triangle is wound counter-clockwise.
+pub(crate) struct Triangulation {
+    pub points: Vec<P64>,
+    pub triangles: Vec<[usize; 3]>,
+    /// False if some segments could not be recovered, so triangles may cross them.
+    pub conforming: bool,
+}
+
+/// Builds a Delaunay triangulation that includes every one of the given segments as an edge.
+/// Segments are split at their crossings, and missing segments are split at their midpoints
+/// until the triangulation conforms to them. Splitting gives up once it has made `split_limit`
+/// splits, or a missing segment is too short to split, leaving the result non-conforming.
+pub(crate) fn conforming_delaunay(
+    segments: &[(P64, P64)],
+    epsilon: f64,
+    split_limit: usize,
+) -> Triangulation {
+    let mut builder = Builder {
+        points: Vec::new(),
+        triangles: Vec::new(),
+        epsilon,
+    };
+
+    // Split every segment wherever it crosses another one.
+    let mut constraints = Vec::new();
+    for (i, (a, b)) in segments.iter().enumerate() {
+        let mut ts = vec![0.0, 1.0];
+        for (j, (c, d)) in segments.iter().enumerate() {
+            if i != j {
+                if let Some(t) = segment_crossing(*a, *b, *c, *d) {
+                    ts.push(t);
+                }
+            }
+        }
+        ts.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        let points: Vec<usize> = ts
+            .iter()
+            .map(|t| builder.index_of([a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]))
+            .collect();
+        for w in points.windows(2) {
+            if w[0] != w[1] {
+                constraints.push((w[0], w[1]));
+            }
+        }
+    }
+
+    // Super triangle, large enough to contain everything.
+    let (mut min, mut max) = ([f64::MAX; 2], [f64::MIN; 2]);
+    for p in builder.points.iter() {
+        min = [min[0].min(p[0]), min[1].min(p[1])];
+        max = [max[0].max(p[0]), max[1].max(p[1])];
+    }
+    let size = (max[0] - min[0]).max(max[1] - min[1]).max(1.0);
+    let mid = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+    let count = builder.points.len();
+    builder.points.push([mid[0] - 20.0 * size, mid[1] - size]);
+    builder.points.push([mid[0] + 20.0 * size, mid[1] - size]);
+    builder.points.push([mid[0], mid[1] + 20.0 * size]);
+    builder.triangles.push([count, count + 1, count + 2]);
+
+    for i in 0..count {
+        builder.insert(i);
+    }
+
+    // Keep splitting segments that are missing from the triangulation.
+    let mut budget = split_limit;
+    let mut conforming = true;
+    while let Some((a, b)) = constraints.pop() {
+        if builder.has_edge(a, b) {
+            continue;
+        }
+        if budget == 0 {
+            conforming = false;
+            continue;
+        }
+        budget -= 1;
+        let (pa, pb) = (builder.points[a], builder.points[b]);
+        let before = builder.points.len();
+        let m = builder.index_of([(pa[0] + pb[0]) / 2.0, (pa[1] + pb[1]) / 2.0]);
+        if m == a || m == b {
+            conforming = false;
+            continue;
+        }
+        if m >= before {
+            builder.insert(m);
+        }
+        constraints.push((a, m));
+        constraints.push((m, b));
+    }
+
+    // Strip away the super triangle.
+    let points = builder.points;
+    let super_start = count;
+    let super_end = count + 3;
+    let triangles = builder
+        .triangles
+        .into_iter()
+        .filter(|t| t.iter().all(|v| *v < super_start || *v >= super_end))
+        .collect();
+    Triangulation {
+        points,
+        triangles,
+        conforming,
+    }
+}
+
+/// Returns the parameter along a -> b where it crosses c -> d, if it does.
+fn segment_crossing(a: P64, b: P64, c: P64, d: P64) -> Option<f64> {
+    let r = [b[0] - a[0], b[1] - a[1]];
+    let s = [d[0] - c[0], d[1] - c[1]];
+    let denom = r[0] * s[1] - r[1] * s[0];
+    if denom == 0.0 {
+        return None;
+    }
+    let q = [c[0] - a[0], c[1] - a[1]];
+    let t = (q[0] * s[1] - q[1] * s[0]) / denom;
+    let u = (q[0] * r[1] - q[1] * r[0]) / denom;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+struct Builder {
+    points: Vec<P64>,
+    triangles: Vec<[usize; 3]>,
+    epsilon: f64,
+}
+
+impl Builder {
+    /// Finds an existing point within epsilon, or adds a new one.
+    fn index_of(&mut self, p: P64) -> usize {
+        let eps2 = self.epsilon * self.epsilon;
+        if let Some(i) = self
+            .points
+            .iter()
+            .position(|q| (q[0] - p[0]).powi(2) + (q[1] - p[1]).powi(2) <= eps2)
+        {
+            return i;
+        }
+        self.points.push(p);
+        self.points.len() - 1
+    }
+
+    fn has_edge(&self, a: usize, b: usize) -> bool {
+        self.triangles.iter().any(|t| {
+            (0..3).any(|i| {
+                let (u, v) = (t[i], t[(i + 1) % 3]);
+                (u == a && v == b) || (u == b && v == a)
+            })
+        })
+    }
+
+    /// Returns true if the point lies strictly within the circumcircle of the triangle.
+    fn in_circumcircle(&self, t: &[usize; 3], p: P64) -> bool {
+        let [a, b, c] = t.map(|i| self.points[i]);
+        let (ax, ay) = (a[0] - p[0], a[1] - p[1]);
+        let (bx, by) = (b[0] - p[0], b[1] - p[1]);
+        let (cx, cy) = (c[0] - p[0], c[1] - p[1]);
+        let det = (ax * ax + ay * ay) * (bx * cy - cx * by) - (bx * bx + by * by) * (ax * cy - cx * ay)
+            + (cx * cx + cy * cy) * (ax * by - bx * ay);
+        det > 0.0
+    }
+
+    /// Bowyer-Watson insertion of a single point.
+    fn insert(&mut self, index: usize) {
+        let p = self.points[index];
+        let (bad, good): (Vec<[usize; 3]>, Vec<[usize; 3]>) = self
+            .triangles
+            .iter()
+            .partition(|t| self.in_circumcircle(t, p));
+        if bad.is_empty() {
+            return;
+        }
+
+        // The edges of the cavity are those that are not shared between two bad triangles.
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for t in bad.iter() {
+            for i in 0..3 {
+                let (a, b) = (t[i], t[(i + 1) % 3]);
+                if let Some(shared) = edges.iter().position(|e| *e == (b, a)) {
+                    edges.swap_remove(shared);
+                } else {
+                    edges.push((a, b));
+                }
+            }
+        }
+
+        self.triangles = good;
+        for (a, b) in edges {
+            if orient(self.points[a], self.points[b], p) > 0.0 {
+                self.triangles.push([a, b, index]);
+            }
+        }
+    }
+}
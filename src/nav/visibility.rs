@@ -0,0 +1,214 @@
+use super::mesh::CIRCLE_SIDES;
+use crate::{
+    core::{normalize_radians, Angle, Line, Point, Ray, Vector},
+    nums::PrimaFloat,
+    shapes::{Aabr, Circle, Obr, Polygon},
+    traits::Flat,
+};
+
+/// The number of sides used to approximate the edge of an observer's view radius.
+const VIEW_SIDES: usize = 64;
+
+/// Something that blocks line of sight.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: PrimaFloat + serde::Serialize",
+        deserialize = "N: PrimaFloat + serde::Deserialize<'de>"
+    ))
+)]
+pub enum Occluder<N> {
+    /// A single wall.
+    Line(Line<N>),
+    /// An axis-aligned rectangle.
+    Aabr(Aabr<N>),
+    /// An orientated rectangle.
+    Obr(Obr<N>),
+    /// A circle, approximated by a polygon drawn around it so that no light leaks past its edge.
+    Circle(Circle<N>),
+    /// An arbitrary polygon.
+    Polygon(Polygon<N>),
+}
+
+impl<N> Occluder<N>
+where
+    N: PrimaFloat,
+{
+    /// Returns the edges that block sight.
+    pub fn edges(&self) -> Vec<Line<N>> {
+        match self {
+            Occluder::Line(line) => vec![*line],
+            Occluder::Aabr(aabr) => aabr.edges(),
+            Occluder::Obr(obr) => obr.edges(),
+            Occluder::Circle(circle) => {
+                // Circumscribe the circle, so its edges never cut inside the real boundary.
+                let half_step = N::pi() / N::from_usize(CIRCLE_SIDES).unwrap();
+                Circle::new(circle.center, circle.radius / half_step.cos())
+                    .to_polygon(CIRCLE_SIDES)
+                    .edges()
+            }
+            Occluder::Polygon(polygon) => polygon.edges(),
+        }
+    }
+}
+
+impl<N> From<Line<N>> for Occluder<N> {
+    fn from(line: Line<N>) -> Self {
+        Occluder::Line(line)
+    }
+}
+
+impl<N> From<Aabr<N>> for Occluder<N> {
+    fn from(aabr: Aabr<N>) -> Self {
+        Occluder::Aabr(aabr)
+    }
+}
+
+impl<N> From<Obr<N>> for Occluder<N> {
+    fn from(obr: Obr<N>) -> Self {
+        Occluder::Obr(obr)
+    }
+}
+
+impl<N> From<Circle<N>> for Occluder<N> {
+    fn from(circle: Circle<N>) -> Self {
+        Occluder::Circle(circle)
+    }
+}
+
+impl<N> From<Polygon<N>> for Occluder<N> {
+    fn from(polygon: Polygon<N>) -> Self {
+        Occluder::Polygon(polygon)
+    }
+}
+
+/// A limited field of view, sweeping clockwise from `start` to `end`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: PrimaFloat + serde::Serialize",
+        deserialize = "N: PrimaFloat + serde::Deserialize<'de>"
+    ))
+)]
+pub struct ViewCone<N> {
+    /// The angle the cone starts at.
+    pub start: Angle<N>,
+    /// The angle the cone ends at, clockwise from the start.
+    pub end: Angle<N>,
+    /// How far the observer can see.
+    pub radius: N,
+}
+
+impl<N> ViewCone<N> {
+    /// Creates a new view cone.
+    pub fn new(start: Angle<N>, end: Angle<N>, radius: N) -> Self {
+        Self { start, end, radius }
+    }
+}
+
+/// Computes the area that can be seen from `observer` within `radius`, as a counter-clockwise polygon.
+pub fn visibility_polygon<N>(observer: Point<N>, occluders: &[Occluder<N>], radius: N) -> Polygon<N>
+where
+    N: PrimaFloat,
+{
+    let hits = cast_rays(observer, occluders, radius, None);
+    Polygon::new(hits.into_iter().map(|(_, p)| p).collect())
+}
+
+/// Computes the area that can be seen from `observer` within a view cone, as a polygon
+/// that starts at the observer and sweeps clockwise across the cone.
+pub fn visibility_cone<N>(observer: Point<N>, occluders: &[Occluder<N>], cone: &ViewCone<N>) -> Polygon<N>
+where
+    N: PrimaFloat,
+{
+    let mut sweep = normalize_radians(cone.end.as_radians() - cone.start.as_radians());
+    if sweep == N::zero() {
+        sweep = N::pi() + N::pi();
+    }
+    let hits = cast_rays(
+        observer,
+        occluders,
+        cone.radius,
+        Some((cone.start.as_radians(), sweep)),
+    );
+    let mut points = vec![observer];
+    points.extend(hits.into_iter().map(|(_, p)| p));
+    Polygon::new(points)
+}
+
+/// Casts rays from the observer towards every occluder vertex, returning the nearest hits ordered
+/// by angle. The optional cone is a start angle and clockwise sweep, both in radians.
+/// Without a cone, hits are ordered counter-clockwise; with one they are ordered clockwise from its start.
+fn cast_rays<N>(
+    observer: Point<N>,
+    occluders: &[Occluder<N>],
+    radius: N,
+    cone: Option<(N, N)>,
+) -> Vec<(N, Point<N>)>
+where
+    N: PrimaFloat,
+{
+    let mut edges: Vec<Line<N>> = occluders.iter().flat_map(|o| o.edges()).collect();
+    // The view radius is treated as one more occluder, so that every ray hits something.
+    edges.extend(Circle::new(observer, radius).to_polygon(VIEW_SIDES).edges());
+
+    let tau = N::pi() + N::pi();
+    let epsilon = N::from_f32(0.0003).unwrap();
+
+    // Angles are measured clockwise from up, to match [Angle].
+    let angle_of = |p: &Point<N>| -> N {
+        let v = *p - observer;
+        normalize_radians(v.x.atan2(v.y))
+    };
+    let mut angles = Vec::new();
+    for edge in edges.iter() {
+        for p in [edge.start, edge.end] {
+            let a = angle_of(&p);
+            angles.push(normalize_radians(a - epsilon));
+            angles.push(a);
+            angles.push(normalize_radians(a + epsilon));
+        }
+    }
+
+    // Re-express each angle as an offset into the cone, dropping anything outside of it.
+    let mut offsets: Vec<N> = match cone {
+        Some((start, sweep)) => {
+            let mut offsets: Vec<N> = angles
+                .into_iter()
+                .map(|a| normalize_radians(a - start))
+                .filter(|o| *o <= sweep)
+                .collect();
+            offsets.push(N::zero());
+            offsets.push(sweep);
+            offsets
+        }
+        None => angles.into_iter().map(|a| tau - a).collect(),
+    };
+    offsets.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    offsets.dedup();
+
+    let start = cone.map(|c| c.0).unwrap_or_else(N::zero);
+    let mut hits: Vec<(N, Point<N>)> = Vec::with_capacity(offsets.len());
+    for offset in offsets {
+        let angle = match cone {
+            Some(_) => start + offset,
+            None => tau - offset,
+        };
+        let ray = Ray::new(observer, Vector::new(angle.sin(), angle.cos()));
+        let nearest = edges
+            .iter()
+            .filter_map(|e| ray.line_intersection(e))
+            .fold(N::infinity(), |a, b| a.min(b));
+        if nearest.is_finite() {
+            let hit = ray.point_at(nearest);
+            if hits.last().map(|(_, p)| *p != hit).unwrap_or(true) {
+                hits.push((offset, hit));
+            }
+        }
+    }
+    hits
+}
@@ -0,0 +1,261 @@
+//! Randomness comes from any [RngCore], so samples can be reproduced by seeding the generator.
+//! Every shape can be sampled by rejection with [sample_rejection], while [SampleInside] gives
+//! exact samplers for shapes where one is cheap. [poisson_disk] scatters points that are evenly
+//! spread but never closer than a set distance, which suits placing trees, rocks and props.
+
+use rand_core::RngCore;
+
+use crate::{
+    core::{Line, Point, Polyline, Vector},
+    nums::PrimaFloat,
+    shapes::{Aabr, Circle, Obr, Polygon},
+    traits::{Distance, Flat, Magnitude, Shape},
+};
+
+/// How many candidates Poisson disk sampling tries around each point before retiring it.
+const POISSON_ATTEMPTS: usize = 30;
+
+/// A shape that can be sampled uniformly by area without rejection.
+pub trait SampleInside<N> {
+    /// Returns a point chosen uniformly from the area of the shape.
+    fn sample_inside<R: RngCore + ?Sized>(&self, rng: &mut R) -> Point<N>;
+}
+
+/// A shape that can be sampled uniformly along its outline.
+pub trait SamplePerimeter<N> {
+    /// Returns a point chosen uniformly by length from the outline of the shape, or None if the
+    /// outline has no length.
+    fn sample_perimeter<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<Point<N>>;
+}
+
+/// Returns a number chosen uniformly from `[0, 1)`.
+pub fn random_unit<N, R>(rng: &mut R) -> N
+where
+    N: PrimaFloat,
+    R: RngCore + ?Sized,
+{
+    // 53 random bits fill the mantissa of an f64 exactly.
+    let unit = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    let n = N::from_f64(unit).unwrap();
+    // Narrower floats can round up to one.
+    if n >= N::one() {
+        N::one() - N::epsilon()
+    } else {
+        n
+    }
+}
+
+/// Returns a number chosen uniformly from `[min, max)`.
+pub fn random_range<N, R>(rng: &mut R, min: N, max: N) -> N
+where
+    N: PrimaFloat,
+    R: RngCore + ?Sized,
+{
+    min + (max - min) * random_unit(rng)
+}
+
+/// Samples a point uniformly inside any shape by drawing from its bounding rectangle until a
+/// point lands inside. Returns None if no point is found within `max_attempts`.
+pub fn sample_rejection<N, S, R>(shape: &S, rng: &mut R, max_attempts: usize) -> Option<Point<N>>
+where
+    N: PrimaFloat,
+    S: Shape<N>,
+    R: RngCore + ?Sized,
+{
+    let bounds = shape.bounding_rect();
+    (0..max_attempts)
+        .map(|_| bounds.sample_inside(rng))
+        .find(|p| shape.contains(p))
+}
+
+/// Samples a point uniformly by length along a set of edges, or None if they have no length.
+pub fn sample_edges<N, R>(edges: &[Line<N>], rng: &mut R) -> Option<Point<N>>
+where
+    N: PrimaFloat,
+    R: RngCore + ?Sized,
+{
+    let total = edges.iter().fold(N::zero(), |sum, e| sum + e.magnitude());
+    if total <= N::zero() {
+        return None;
+    }
+    let mut remaining = random_unit::<N, R>(rng) * total;
+    for edge in edges {
+        let length = edge.magnitude();
+        if remaining < length {
+            return Some(edge.start + edge.vector() * (remaining / length));
+        }
+        remaining -= length;
+    }
+    // Rounding can leave a sliver past the final edge.
+    edges.iter().rev().find(|e| e.magnitude() > N::zero()).map(|e| e.end)
+}
+
+/// Scatters points inside a shape using Bridson's algorithm, so that no two points are closer
+/// than `min_distance` and no gap is large enough to fit another.
+///
+/// Sampling grows outward from a single random seed, so only the part of the shape connected
+/// to it is filled.
+pub fn poisson_disk<N, S, R>(shape: &S, min_distance: N, rng: &mut R) -> Vec<Point<N>>
+where
+    N: PrimaFloat,
+    S: Shape<N>,
+    R: RngCore + ?Sized,
+{
+    let mut points = Vec::new();
+    if min_distance <= N::zero() {
+        return points;
+    }
+    let seed = match sample_rejection(shape, rng, POISSON_ATTEMPTS * POISSON_ATTEMPTS) {
+        Some(seed) => seed,
+        None => return points,
+    };
+
+    // A background grid small enough that each cell holds at most one point.
+    let bounds = shape.bounding_rect();
+    let cell = min_distance / (N::one() + N::one()).sqrt();
+    let columns = (bounds.width() / cell).ceil().to_usize().unwrap_or(0).max(1);
+    let rows = (bounds.height() / cell).ceil().to_usize().unwrap_or(0).max(1);
+    let mut cells: Vec<Option<usize>> = vec![None; columns * rows];
+    let cell_of = |p: &Point<N>| {
+        let x = ((p.x - bounds.min.x) / cell).to_usize().unwrap_or(0).min(columns - 1);
+        let y = ((p.y - bounds.min.y) / cell).to_usize().unwrap_or(0).min(rows - 1);
+        (x, y)
+    };
+
+    let (x, y) = cell_of(&seed);
+    cells[y * columns + x] = Some(0);
+    points.push(seed);
+    let mut active = vec![0];
+    let min_sq = min_distance * min_distance;
+    let tau = N::pi() + N::pi();
+
+    while !active.is_empty() {
+        let index = (random_unit::<N, R>(rng) * N::from_usize(active.len()).unwrap())
+            .to_usize()
+            .unwrap_or(0)
+            .min(active.len() - 1);
+        let center = points[active[index]];
+        let mut found = false;
+
+        for _ in 0..POISSON_ATTEMPTS {
+            // Uniform by area within the ring from one to two times the minimum distance.
+            let (s, c) = (random_unit::<N, R>(rng) * tau).sin_cos();
+            let three = N::from_f32(3.0).unwrap();
+            let r = min_distance * (N::one() + three * random_unit::<N, R>(rng)).sqrt();
+            let candidate = center + Vector::new(c, s) * r;
+            if !bounds.contains(&candidate) || !shape.contains(&candidate) {
+                continue;
+            }
+            let (cx, cy) = cell_of(&candidate);
+            let crowded = (cy.saturating_sub(2)..(cy + 3).min(rows)).any(|y| {
+                (cx.saturating_sub(2)..(cx + 3).min(columns)).any(|x| {
+                    cells[y * columns + x]
+                        .map(|i| points[i].distance_squared(&candidate) < min_sq)
+                        .unwrap_or(false)
+                })
+            });
+            if crowded {
+                continue;
+            }
+            cells[cy * columns + cx] = Some(points.len());
+            active.push(points.len());
+            points.push(candidate);
+            found = true;
+            break;
+        }
+
+        if !found {
+            active.swap_remove(index);
+        }
+    }
+    points
+}
+
+//=============================================================//
+//=========================== SHAPES ==========================//
+//=============================================================//
+
+impl<N> SampleInside<N> for Aabr<N>
+where
+    N: PrimaFloat,
+{
+    fn sample_inside<R: RngCore + ?Sized>(&self, rng: &mut R) -> Point<N> {
+        Point::new(
+            random_range(rng, self.min.x, self.max.x),
+            random_range(rng, self.min.y, self.max.y),
+        )
+    }
+}
+
+impl<N> SampleInside<N> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    fn sample_inside<R: RngCore + ?Sized>(&self, rng: &mut R) -> Point<N> {
+        // Taking the root of the radius keeps the density even rather than bunched at the center.
+        let (s, c) = (random_unit::<N, R>(rng) * (N::pi() + N::pi())).sin_cos();
+        let r = self.radius * random_unit::<N, R>(rng).sqrt();
+        self.center + Vector::new(c, s) * r
+    }
+}
+
+impl<N> SampleInside<N> for Obr<N>
+where
+    N: PrimaFloat,
+{
+    fn sample_inside<R: RngCore + ?Sized>(&self, rng: &mut R) -> Point<N> {
+        let half = N::one() / (N::one() + N::one());
+        let x = (random_unit::<N, R>(rng) - half) * self.extent.width();
+        let y = (random_unit::<N, R>(rng) - half) * self.extent.height();
+        self.center + self.x_axis() * x + self.y_axis() * y
+    }
+}
+
+impl<N> SamplePerimeter<N> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    fn sample_perimeter<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<Point<N>> {
+        if self.radius <= N::zero() {
+            return None;
+        }
+        let (s, c) = (random_unit::<N, R>(rng) * (N::pi() + N::pi())).sin_cos();
+        Some(self.center + Vector::new(c, s) * self.radius)
+    }
+}
+
+impl<N> SamplePerimeter<N> for Aabr<N>
+where
+    N: PrimaFloat,
+{
+    fn sample_perimeter<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<Point<N>> {
+        sample_edges(&self.edges(), rng)
+    }
+}
+
+impl<N> SamplePerimeter<N> for Obr<N>
+where
+    N: PrimaFloat,
+{
+    fn sample_perimeter<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<Point<N>> {
+        sample_edges(&self.edges(), rng)
+    }
+}
+
+impl<N> SamplePerimeter<N> for Polygon<N>
+where
+    N: PrimaFloat,
+{
+    fn sample_perimeter<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<Point<N>> {
+        sample_edges(&self.edges(), rng)
+    }
+}
+
+impl<N> SamplePerimeter<N> for Polyline<N>
+where
+    N: PrimaFloat,
+{
+    fn sample_perimeter<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<Point<N>> {
+        sample_edges(&self.segments(), rng)
+    }
+}
@@ -0,0 +1,152 @@
+//! Every shape implementing [SignedDistance] can be combined using the methods on that trait,
+//! which wrap the shapes in the types below. The results are signed distance fields in their
+//! own right, so they can be combined further:
+//!
+//! ```
+//! use prima::{prelude::*, traits::SignedDistance};
+//!
+//! let body = Circle::new(Point::new(0.0f32, 0.0), 1.0);
+//! let arm = Capsule::new(Line::new(Point::new(0.0, 0.0), Point::new(3.0, 0.0)), 0.25);
+//! let blob = body.smooth_union(arm, 0.5).offset(0.1);
+//! assert!(blob.signed_distance(&Point::new(2.9, 0.0)) < 0.0);
+//! ```
+//!
+//! Unions and intersections give exact distances outside and inside respectively, but only a
+//! bound on the other side. This is enough for collision and outline rendering, where only the
+//! sign and the distance near the boundary matter.
+
+use crate::{
+    core::{Point, Vector},
+    nums::PrimaFloat,
+    traits::SignedDistance,
+};
+
+/// The area covered by either of two shapes, created by [SignedDistance::union].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Union<A, B> {
+    /// The first shape.
+    pub a: A,
+    /// The second shape.
+    pub b: B,
+}
+
+/// The area covered by both of two shapes, created by [SignedDistance::intersection].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Intersection<A, B> {
+    /// The first shape.
+    pub a: A,
+    /// The second shape.
+    pub b: B,
+}
+
+/// A union with a blended seam, created by [SignedDistance::smooth_union].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SmoothUnion<A, B, N> {
+    /// The first shape.
+    pub a: A,
+    /// The second shape.
+    pub b: B,
+    /// Roughly how far the blend reaches from the seam.
+    pub smoothing: N,
+}
+
+/// A shape grown or shrunk by a distance, created by [SignedDistance::offset].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Offset<S, N> {
+    /// The shape being offset.
+    pub shape: S,
+    /// How far the boundary moves outward.
+    pub distance: N,
+}
+
+impl<A, B> Union<A, B> {
+    /// Creates the union of two shapes.
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A, B> Intersection<A, B> {
+    /// Creates the intersection of two shapes.
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A, B, N> SmoothUnion<A, B, N> {
+    /// Creates a smooth union of two shapes.
+    pub fn new(a: A, b: B, smoothing: N) -> Self {
+        Self { a, b, smoothing }
+    }
+}
+
+impl<S, N> Offset<S, N> {
+    /// Creates an offset shape.
+    pub fn new(shape: S, distance: N) -> Self {
+        Self { shape, distance }
+    }
+}
+
+/// Blends the minimum of two values over the range `k` using a quadratic polynomial.
+pub fn smooth_min<N>(a: N, b: N, k: N) -> N
+where
+    N: PrimaFloat,
+{
+    if k <= N::zero() {
+        return a.min(b);
+    }
+    let h = (k - (a - b).abs()).max(N::zero()) / k;
+    let four = N::from_f32(4.0).unwrap();
+    a.min(b) - h * h * k / four
+}
+
+impl<N, A, B> SignedDistance<N> for Union<A, B>
+where
+    N: PrimaFloat,
+    A: SignedDistance<N>,
+    B: SignedDistance<N>,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        self.a.signed_distance(point).min(self.b.signed_distance(point))
+    }
+}
+
+impl<N, A, B> SignedDistance<N> for Intersection<A, B>
+where
+    N: PrimaFloat,
+    A: SignedDistance<N>,
+    B: SignedDistance<N>,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        self.a.signed_distance(point).max(self.b.signed_distance(point))
+    }
+}
+
+impl<N, A, B> SignedDistance<N> for SmoothUnion<A, B, N>
+where
+    N: PrimaFloat,
+    A: SignedDistance<N>,
+    B: SignedDistance<N>,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        smooth_min(
+            self.a.signed_distance(point),
+            self.b.signed_distance(point),
+            self.smoothing,
+        )
+    }
+}
+
+impl<N, S> SignedDistance<N> for Offset<S, N>
+where
+    N: PrimaFloat,
+    S: SignedDistance<N>,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        self.shape.signed_distance(point) - self.distance
+    }
+
+    fn gradient(&self, point: &Point<N>) -> Vector<N> {
+        self.shape.gradient(point)
+    }
+}
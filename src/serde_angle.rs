@@ -0,0 +1,57 @@
+//! [Angle] and [Rotation] are written as a plain number of degrees by default. The modules here can
+//! be used with `#[serde(with = "...")]` to store them in another unit instead:
+//!
+//! ```
+//! use prima::core::{Angle, Rotation};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Turret {
+//!     #[serde(with = "prima::serde_angle::radians")]
+//!     facing: Angle<f32>,
+//!     #[serde(with = "prima::serde_angle::half_turns")]
+//!     spin: Rotation<f32>,
+//! }
+//! ```
+//!
+//! Everything else in prima derives its representation from its public fields, so a [Point] is
+//! `{ "x": 1.0, "y": 2.0 }` and an [Extent] is `{ "width": 1.0, "height": 2.0 }`.
+
+#[allow(unused_imports)]
+use crate::core::{Angle, Extent, Point, Rotation};
+
+macro_rules! angle_unit {
+    ($name: ident, $doc: literal, $to: ident, $from: ident) => {
+        #[doc = $doc]
+        pub mod $name {
+            use crate::{core::Rotation, nums::PrimaFloat};
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            /// Serializes an angle or rotation.
+            pub fn serialize<A, N, S>(value: &A, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                A: Copy + Into<Rotation<N>>,
+                N: PrimaFloat + Serialize,
+                S: Serializer,
+            {
+                let rotation: Rotation<N> = (*value).into();
+                rotation.$to().serialize(serializer)
+            }
+
+            /// Deserializes an angle or rotation.
+            pub fn deserialize<'de, A, N, D>(deserializer: D) -> Result<A, D::Error>
+            where
+                A: From<Rotation<N>>,
+                N: PrimaFloat + Deserialize<'de>,
+                D: Deserializer<'de>,
+            {
+                let value = N::deserialize(deserializer)?;
+                Ok(A::from(Rotation::$from(value)))
+            }
+        }
+    };
+}
+
+angle_unit!(degrees, "Stores angles as degrees, where a full turn is 360.", as_degrees, from_degrees);
+angle_unit!(radians, "Stores angles as radians, where a full turn is 2π.", as_radians, from_radians);
+angle_unit!(half_turns, "Stores angles in units of π, where a full turn is 2.", as_half_turns, from_half_turns);
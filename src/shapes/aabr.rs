@@ -1,13 +1,13 @@
 use crate::{
-    core::{project_shape_to_axis_pair, Collision, Extent, Line, Point, Vector},
+    core::{edge_crossings, project_shape_to_axis_pair, Collision, Extent, Line, Point, Vector},
     nums::{PrimaFloat, PrimaNum},
-    traits::{Collide, Distance, Flat, LocalPosition, Nearest, Shape},
+    traits::{Collide, Distance, Flat, Intersections, LocalPosition, Nearest, Shape, SignedDistance},
 };
-use serde::{Deserialize, Serialize};
-use super::{Circle, Obr};
+use super::{Circle, Obr, Polygon, RoundedPolygon};
 
 /// An axis-aligned bounding rectangle.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Aabr<N> {
     /// The minimum point of the rectangle.
     pub min: Point<N>,
@@ -78,6 +78,16 @@ where
     }
 }
 
+impl<N> Aabr<N>
+where
+    N: PrimaFloat,
+{
+    /// Grows the rectangle outward by the given radius, returning a rounded rectangle.
+    pub fn inflate(&self, radius: N) -> RoundedPolygon<N> {
+        RoundedPolygon::new(Polygon::convex_hull(&self.vertices()), radius)
+    }
+}
+
 impl<N> Shape<N> for Aabr<N>
 where
     N: PrimaFloat,
@@ -91,7 +101,7 @@ where
     }
 
     fn bounding_rect(&self) -> Aabr<N> {
-        self.clone()
+        *self
     }
 
     fn bounding_circle(&self) -> Circle<N> {
@@ -141,6 +151,27 @@ where
 //========================= POINT =================================//
 //=================================================================//
 
+impl<N> SignedDistance<N> for Aabr<N>
+where
+    N: PrimaFloat,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        let half = self.extent().half();
+        let offset = (*point - self.position()).abs();
+        box_distance(offset.x - half.width(), offset.y - half.height())
+    }
+}
+
+/// The signed distance to a box, given how far a point lies beyond its half-extents on each
+/// axis in the box's own frame.
+pub(crate) fn box_distance<N>(qx: N, qy: N) -> N
+where
+    N: PrimaFloat,
+{
+    let outside = qx.max(N::zero()).hypot(qy.max(N::zero()));
+    outside + qx.max(qy).min(N::zero())
+}
+
 impl<N> Distance<N, Point<N>> for Aabr<N>
 where
     N: PrimaFloat,
@@ -154,21 +185,12 @@ impl<N> Nearest<N, Point<N>> for Aabr<N>
 where
     N: PrimaFloat,
 {
+    /// Returns the point itself if it lies inside the rectangle.
     fn nearest_point(&self, point: &Point<N>) -> Point<N> {
-        let mut nearest = self.position();
-        let min = self.min;
-        let max = self.max;
-        if point.x < min.x {
-            nearest.x = min.x;
-        } else if point.x > max.x {
-            nearest.x = max.x;
-        }
-        if point.y < min.y {
-            nearest.y = min.y;
-        } else if point.y > max.y {
-            nearest.y = max.y;
-        }
-        nearest
+        Point::new(
+            point.x.max(self.min.x).min(self.max.x),
+            point.y.max(self.min.y).min(self.max.y),
+        )
     }
 }
 
@@ -282,6 +304,26 @@ where
     }
 }
 
+impl<N> Intersections<N, Line<N>> for Aabr<N>
+where
+    N: PrimaFloat,
+{
+    /// Ordered from the start of the line.
+    fn intersections(&self, line: &Line<N>) -> Vec<Point<N>> {
+        line.intersections(self)
+    }
+}
+
+impl<N> Intersections<N, Aabr<N>> for Line<N>
+where
+    N: PrimaFloat,
+{
+    /// Ordered from the start of the line.
+    fn intersections(&self, aabr: &Aabr<N>) -> Vec<Point<N>> {
+        self.crossings(&aabr.edges())
+    }
+}
+
 //=================================================================//
 //============================ CIRCLE =============================//
 //=================================================================//
@@ -291,8 +333,8 @@ where
     N: PrimaFloat,
 {
     fn distance_squared(&self, other: &Circle<N>) -> N {
-        self.nearest_point(&other.center)
-            .distance_squared(&other.center)
+        let gap = self.distance(&other.center) - other.radius;
+        gap.max(N::zero()).powi(2)
     }
 }
 
@@ -493,6 +535,16 @@ where
     }
 }
 
+impl<N> Intersections<N, Aabr<N>> for Aabr<N>
+where
+    N: PrimaFloat,
+{
+    /// Ordered around the edges of self.
+    fn intersections(&self, other: &Aabr<N>) -> Vec<Point<N>> {
+        edge_crossings(&self.edges(), &other.edges())
+    }
+}
+
 //=================================================================//
 //============================== OBR ==============================//
 //=================================================================//
@@ -0,0 +1,489 @@
+use crate::{
+    core::{Collision, Line, Point, Ray, Vector},
+    nums::PrimaFloat,
+    traits::{Collide, Distance, Flat, LocalPosition, Nearest, Shape, SignedDistance},
+};
+
+use super::{Aabr, Capsule, Circle, Obr, Polygon, RoundedPolygon};
+
+/// Any one of prima's closed shapes, for containers that hold a mix of them.
+///
+/// Pairs of shapes are tested by reducing each to a core, which is a point, a segment or a
+/// polygon, grown outward by a radius. Intersection and distance are exact for every pair.
+/// Penetration depth and enveloping assume convex shapes, so are approximate for a concave
+/// [Polygon].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: PrimaFloat + serde::Serialize",
+        deserialize = "N: PrimaFloat + serde::Deserialize<'de>"
+    ))
+)]
+pub enum AnyShape<N> {
+    /// A circle.
+    Circle(Circle<N>),
+    /// An axis-aligned rectangle.
+    Aabr(Aabr<N>),
+    /// An orientated rectangle.
+    Obr(Obr<N>),
+    /// A capsule.
+    Capsule(Capsule<N>),
+    /// A simple polygon.
+    Polygon(Polygon<N>),
+    /// A convex polygon with rounded corners.
+    RoundedPolygon(RoundedPolygon<N>),
+}
+
+macro_rules! dispatch {
+    ($shape:expr, $s:ident => $e:expr) => {
+        match $shape {
+            AnyShape::Circle($s) => $e,
+            AnyShape::Aabr($s) => $e,
+            AnyShape::Obr($s) => $e,
+            AnyShape::Capsule($s) => $e,
+            AnyShape::Polygon($s) => $e,
+            AnyShape::RoundedPolygon($s) => $e,
+        }
+    };
+}
+
+macro_rules! any_from {
+    ($($variant:ident),*) => {$(
+        impl<N> From<$variant<N>> for AnyShape<N> {
+            fn from(shape: $variant<N>) -> Self {
+                AnyShape::$variant(shape)
+            }
+        }
+    )*};
+}
+
+any_from!(Circle, Aabr, Obr, Capsule, Polygon, RoundedPolygon);
+
+impl<N> AnyShape<N>
+where
+    N: PrimaFloat,
+{
+    /// Reduces the shape to its core and the radius the core is grown by.
+    fn core(&self) -> Core<N> {
+        let (points, radius) = match self {
+            AnyShape::Circle(c) => (vec![c.center], c.radius),
+            AnyShape::Aabr(a) => (a.vertices(), N::zero()),
+            AnyShape::Obr(o) => (o.vertices(), N::zero()),
+            AnyShape::Capsule(c) => (vec![c.line.start, c.line.end], c.radius),
+            AnyShape::Polygon(p) => (p.points.clone(), N::zero()),
+            AnyShape::RoundedPolygon(r) => (r.polygon.points.clone(), r.radius),
+        };
+        Core { points, radius }
+    }
+
+    /// Returns true if the shape has no points at all, such as an empty polygon.
+    fn is_empty(&self) -> bool {
+        match self {
+            AnyShape::Polygon(p) => p.points.is_empty(),
+            AnyShape::RoundedPolygon(r) => r.polygon.points.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Casts a ray at the shape, returning how far along the ray (in lengths of its direction)
+    /// it first touches the shape, and the shape's outward normal there. A ray starting inside
+    /// the shape hits it straight away.
+    pub fn cast_ray(&self, ray: &Ray<N>) -> Option<(N, Vector<N>)> {
+        if self.signed_distance(&ray.origin) <= N::zero() {
+            let normal = self
+                .normal(&ray.origin)
+                .unwrap_or(-ray.direction.normalize());
+            return Some((N::zero(), normal));
+        }
+        let core = self.core();
+        core.edges()
+            .iter()
+            .filter_map(|edge| ray_capsule(ray, edge, core.radius))
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+    }
+
+    /// Sweeps the shape along a velocity, returning the fraction of the velocity it can travel
+    /// before touching the other shape, and the collision at that moment. The collision's normal
+    /// points from self towards the other shape. Shapes that already intersect touch at zero.
+    ///
+    /// The sweep advances by the gap between the shapes each step, so it never passes through
+    /// the other shape. It is exact for convex shapes.
+    pub fn cast(&self, velocity: &Vector<N>, other: &AnyShape<N>) -> Option<(N, Collision<N>)> {
+        if let Some(collision) = self.collision(other) {
+            return Some((N::zero(), collision));
+        }
+        let b = other.core();
+        let tolerance = N::epsilon().sqrt();
+        let mut t = N::zero();
+        for _ in 0..CAST_ITERATIONS {
+            let mut moved = self.clone();
+            moved.translate(&(*velocity * t));
+            let a = moved.core();
+            let Some((pa, pb, d)) = a.separation(&b) else {
+                return moved.collision(other).map(|collision| (t, collision));
+            };
+            let gap = d - a.radius - b.radius;
+            let normal = if d > N::zero() {
+                (pb - pa) / d
+            } else {
+                velocity.normalize()
+            };
+            if gap <= tolerance {
+                return Some((t, Collision::new(pa + normal * a.radius, normal, N::zero())));
+            }
+            let closing = velocity.dot(&normal);
+            if closing <= N::zero() {
+                return None;
+            }
+            t += gap / closing;
+            if t > N::one() {
+                return None;
+            }
+        }
+        None
+    }
+}
+
+/// The most steps a shape cast takes before giving up on closing the gap.
+const CAST_ITERATIONS: usize = 64;
+
+impl<N> Shape<N> for AnyShape<N>
+where
+    N: PrimaFloat,
+{
+    fn volume(&self) -> N {
+        dispatch!(self, s => s.volume())
+    }
+
+    fn circumference(&self) -> N {
+        dispatch!(self, s => s.circumference())
+    }
+
+    fn bounding_rect(&self) -> Aabr<N> {
+        dispatch!(self, s => s.bounding_rect())
+    }
+
+    fn bounding_circle(&self) -> Circle<N> {
+        dispatch!(self, s => s.bounding_circle())
+    }
+
+    fn contains(&self, point: &Point<N>) -> bool {
+        dispatch!(self, s => s.contains(point))
+    }
+}
+
+impl<N> LocalPosition<N> for AnyShape<N>
+where
+    N: PrimaFloat,
+{
+    fn position(&self) -> Point<N> {
+        dispatch!(self, s => s.position())
+    }
+
+    fn translate(&mut self, offset: &Vector<N>) {
+        dispatch!(self, s => s.translate(offset))
+    }
+}
+
+impl<N> SignedDistance<N> for AnyShape<N>
+where
+    N: PrimaFloat,
+{
+    /// Shapes without any points are infinitely far from everything.
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        if self.is_empty() {
+            return N::infinity();
+        }
+        dispatch!(self, s => s.signed_distance(point))
+    }
+}
+
+impl<N> Distance<N, AnyShape<N>> for AnyShape<N>
+where
+    N: PrimaFloat,
+{
+    /// Shapes without any points are infinitely far from everything.
+    fn distance_squared(&self, other: &AnyShape<N>) -> N {
+        let (a, b) = (self.core(), other.core());
+        if a.is_empty() || b.is_empty() {
+            return N::infinity();
+        }
+        match a.separation(&b) {
+            Some((_, _, d)) => {
+                let gap = (d - a.radius - b.radius).max(N::zero());
+                gap * gap
+            }
+            None => N::zero(),
+        }
+    }
+}
+
+impl<N> Distance<N, Point<N>> for AnyShape<N>
+where
+    N: PrimaFloat,
+{
+    fn distance_squared(&self, point: &Point<N>) -> N {
+        let d = self.signed_distance(point).max(N::zero());
+        d * d
+    }
+}
+
+impl<N> Nearest<N, Point<N>> for AnyShape<N>
+where
+    N: PrimaFloat,
+{
+    /// Points inside the shape are their own nearest point.
+    fn nearest_point(&self, point: &Point<N>) -> Point<N> {
+        let core = self.core();
+        let target = Core {
+            points: vec![*point],
+            radius: N::zero(),
+        };
+        match core.separation(&target) {
+            Some((near, _, d)) if d > core.radius => near + (*point - near) * (core.radius / d),
+            _ => *point,
+        }
+    }
+}
+
+impl<N> Collide<N, AnyShape<N>> for AnyShape<N>
+where
+    N: PrimaFloat,
+{
+    /// The normal points from self towards the other shape, and the point lies on self's surface.
+    /// Shapes without any points, such as an empty polygon, never collide.
+    fn collision(&self, other: &AnyShape<N>) -> Option<Collision<N>> {
+        let (a, b) = (self.core(), other.core());
+        if a.is_empty() || b.is_empty() {
+            return None;
+        }
+        let reach = a.radius + b.radius;
+        let (normal, depth) = match a.separation(&b) {
+            Some((pa, pb, d)) if d > N::zero() => {
+                if d > reach {
+                    return None;
+                }
+                let normal = (pb - pa) / d;
+                return Some(Collision::new(pa + normal * a.radius, normal, reach - d));
+            }
+            _ => a.penetration(&b),
+        };
+        a.support(normal)
+            .map(|point| Collision::new(point, normal, depth.max(N::zero())))
+    }
+
+    fn intersecting(&self, other: &AnyShape<N>) -> bool {
+        let (a, b) = (self.core(), other.core());
+        if a.is_empty() || b.is_empty() {
+            return false;
+        }
+        match a.separation(&b) {
+            Some((_, _, d)) => d <= a.radius + b.radius,
+            None => true,
+        }
+    }
+
+    /// Exact when self is convex.
+    fn enveloping(&self, other: &AnyShape<N>) -> bool {
+        let core = other.core();
+        core.points
+            .iter()
+            .all(|p| self.signed_distance(p) <= -core.radius)
+    }
+
+    fn enveloped_by(&self, other: &AnyShape<N>) -> bool {
+        other.enveloping(self)
+    }
+}
+
+/// A shape reduced to a point, segment or polygon, and the radius it is grown by.
+struct Core<N> {
+    points: Vec<Point<N>>,
+    radius: N,
+}
+
+impl<N> Core<N>
+where
+    N: PrimaFloat,
+{
+    /// Returns true if the core has no points, such as an empty polygon, so covers nothing.
+    fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The edges of the core. A point core has a single edge of zero length.
+    fn edges(&self) -> Vec<Line<N>> {
+        let n = self.points.len();
+        match n {
+            0 => Vec::new(),
+            1 => vec![Line::new(self.points[0], self.points[0])],
+            2 => vec![Line::new(self.points[0], self.points[1])],
+            _ => (0..n)
+                .map(|i| Line::new(self.points[i], self.points[(i + 1) % n]))
+                .collect(),
+        }
+    }
+
+    /// Returns true if the point lies strictly inside a polygon core.
+    fn surrounds(&self, point: &Point<N>) -> bool {
+        if self.points.len() < 3 {
+            return false;
+        }
+        let mut inside = false;
+        for edge in self.edges() {
+            let (a, b) = (edge.start, edge.end);
+            if (a.y > point.y) != (b.y > point.y)
+                && point.x < a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y)
+            {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    /// The closest points between the two cores and the distance between them, or `None` if
+    /// the cores overlap.
+    fn separation(&self, other: &Self) -> Option<(Point<N>, Point<N>, N)> {
+        let inside = other.points.first().is_some_and(|p| self.surrounds(p))
+            || self.points.first().is_some_and(|p| other.surrounds(p));
+        if inside {
+            return None;
+        }
+        let mut best: Option<(Point<N>, Point<N>, N)> = None;
+        for a in self.edges() {
+            for b in other.edges() {
+                if a.collision(&b).is_some() {
+                    return None;
+                }
+                let (pa, pb) = closest_points(&a, &b);
+                let d = pa.distance(&pb);
+                if best.is_none_or(|(_, _, closest)| d < closest) {
+                    best = Some((pa, pb, d));
+                }
+            }
+        }
+        best
+    }
+
+    /// The axis along which the cores overlap the least, pointing from self towards the other,
+    /// and how far the grown shapes overlap along it.
+    fn penetration(&self, other: &Self) -> (Vector<N>, N) {
+        let reach = self.radius + other.radius;
+        let mut best: Option<(Vector<N>, N)> = None;
+        for edge in self.edges().iter().chain(other.edges().iter()) {
+            if edge.start == edge.end {
+                continue;
+            }
+            let axis = edge.normal();
+            let (a_min, a_max) = self.project(&axis);
+            let (b_min, b_max) = other.project(&axis);
+            let forward = a_max - b_min + reach;
+            let backward = b_max - a_min + reach;
+            let (normal, depth) = if forward <= backward {
+                (axis, forward)
+            } else {
+                (-axis, backward)
+            };
+            if best.is_none_or(|(_, least)| depth < least) {
+                best = Some((normal, depth));
+            }
+        }
+        best.unwrap_or((Vector::right(), reach))
+    }
+
+    /// The range the core covers along an axis.
+    fn project(&self, axis: &Vector<N>) -> (N, N) {
+        self.points
+            .iter()
+            .fold((N::infinity(), N::neg_infinity()), |(min, max), p| {
+                let d = Vector::new(p.x, p.y).dot(axis);
+                (min.min(d), max.max(d))
+            })
+    }
+
+    /// The point on the grown shape furthest along a unit direction, or `None` if the core is
+    /// empty.
+    fn support(&self, direction: Vector<N>) -> Option<Point<N>> {
+        let mut best = *self.points.first()?;
+        let mut furthest = N::neg_infinity();
+        for p in &self.points {
+            let d = Vector::new(p.x, p.y).dot(&direction);
+            if d > furthest {
+                furthest = d;
+                best = *p;
+            }
+        }
+        Some(best + direction * self.radius)
+    }
+}
+
+/// The closest pair of points between two segments, either of which may have zero length.
+fn closest_points<N: PrimaFloat>(a: &Line<N>, b: &Line<N>) -> (Point<N>, Point<N>) {
+    let clamp = |v: N| v.max(N::zero()).min(N::one());
+    let (da, db) = (a.vector(), b.vector());
+    let r = a.start - b.start;
+    let (la, lb) = (da.dot(&da), db.dot(&db));
+    let f = db.dot(&r);
+    let (s, t) = if la == N::zero() && lb == N::zero() {
+        (N::zero(), N::zero())
+    } else if la == N::zero() {
+        (N::zero(), clamp(f / lb))
+    } else {
+        let c = da.dot(&r);
+        if lb == N::zero() {
+            (clamp(-c / la), N::zero())
+        } else {
+            let d = da.dot(&db);
+            let denom = la * lb - d * d;
+            let s = if denom != N::zero() {
+                clamp((d * f - c * lb) / denom)
+            } else {
+                N::zero()
+            };
+            let t = (d * s + f) / lb;
+            if t < N::zero() {
+                (clamp(-c / la), N::zero())
+            } else if t > N::one() {
+                (clamp((d - c) / la), N::one())
+            } else {
+                (s, t)
+            }
+        }
+    };
+    (a.start + da * s, b.start + db * t)
+}
+
+/// Where a ray first touches a segment grown by a radius, and the outward normal there.
+fn ray_capsule<N: PrimaFloat>(ray: &Ray<N>, edge: &Line<N>, radius: N) -> Option<(N, Vector<N>)> {
+    let mut hits: Vec<(N, Vector<N>)> = Vec::new();
+    if edge.start != edge.end {
+        // A ray can only enter the grown segment through the side facing it.
+        let mut normal = edge.normal();
+        if normal.dot(&ray.direction) > N::zero() {
+            normal = -normal;
+        }
+        let offset = normal * radius;
+        let line = Line::new(edge.start + offset, edge.end + offset);
+        if let Some(t) = ray.line_intersection(&line) {
+            hits.push((t, normal));
+        }
+    }
+    if radius > N::zero() {
+        for center in [edge.start, edge.end] {
+            let f = ray.origin - center;
+            let a = ray.direction.dot(&ray.direction);
+            let b = f.dot(&ray.direction);
+            let disc = b * b - a * (f.dot(&f) - radius * radius);
+            if a > N::zero() && disc >= N::zero() {
+                let t = (-b - disc.sqrt()) / a;
+                if t >= N::zero() {
+                    hits.push((t, (ray.point_at(t) - center) / radius));
+                }
+            }
+        }
+    }
+    hits.into_iter()
+        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+}
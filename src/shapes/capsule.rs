@@ -0,0 +1,124 @@
+use crate::{
+    core::{Line, Point, Rotation, Vector},
+    nums::PrimaFloat,
+    traits::{Curved, Distance, LocalPosition, LocalRotation, Magnitude, Nearest, Shape, SignedDistance},
+};
+
+use super::{Aabr, Circle};
+
+/// A capsule, which is a line segment grown outward by a radius. This is the shape swept by a moving circle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capsule<N> {
+    /// The line running through the middle of the capsule.
+    pub line: Line<N>,
+    /// The radius of the capsule.
+    pub radius: N,
+}
+
+impl<N> Capsule<N> {
+    /// Creates a new capsule from a line and a radius.
+    pub fn new(line: Line<N>, radius: N) -> Self {
+        Self { line, radius }
+    }
+}
+
+impl<N> Shape<N> for Capsule<N>
+where
+    N: PrimaFloat,
+{
+    fn volume(&self) -> N {
+        let length = self.line.magnitude();
+        (self.radius + self.radius) * length + N::pi() * self.radius * self.radius
+    }
+
+    fn circumference(&self) -> N {
+        let length = self.line.magnitude();
+        length + length + (N::pi() + N::pi()) * self.radius
+    }
+
+    fn bounding_rect(&self) -> Aabr<N> {
+        let (a, b) = (self.line.start, self.line.end);
+        let r = Vector::splat(self.radius);
+        Aabr::new(
+            Point::new(a.x.min(b.x), a.y.min(b.y)) + -r,
+            Point::new(a.x.max(b.x), a.y.max(b.y)) + r,
+        )
+    }
+
+    fn bounding_circle(&self) -> Circle<N> {
+        let center = self.position();
+        Circle::new(center, center.distance(&self.line.start) + self.radius)
+    }
+
+    fn contains(&self, point: &Point<N>) -> bool {
+        self.line.distance_squared(point) <= self.radius * self.radius
+    }
+}
+
+impl<N> Curved<N> for Capsule<N> where N: PrimaFloat {}
+
+impl<N> LocalPosition<N> for Capsule<N>
+where
+    N: PrimaFloat,
+{
+    fn position(&self) -> Point<N> {
+        self.line.start + self.line.vector() / (N::one() + N::one())
+    }
+
+    fn translate(&mut self, offset: &Vector<N>) {
+        self.line.start += *offset;
+        self.line.end += *offset;
+    }
+}
+
+/// Capsules do not store a rotation, so rotating one rotates its line around the center.
+impl<N> LocalRotation<N> for Capsule<N>
+where
+    N: PrimaFloat,
+{
+    fn rotation(&self) -> Rotation<N> {
+        Rotation::zero()
+    }
+
+    fn rotate(&mut self, rotation: Rotation<N>) {
+        let center = self.position();
+        self.line.start = self.line.start.rotate_around(center, rotation);
+        self.line.end = self.line.end.rotate_around(center, rotation);
+    }
+}
+
+//=================================================================//
+//========================= POINT =================================//
+//=================================================================//
+
+impl<N> SignedDistance<N> for Capsule<N>
+where
+    N: PrimaFloat,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        self.line.distance(point) - self.radius
+    }
+}
+
+impl<N> Distance<N, Point<N>> for Capsule<N>
+where
+    N: PrimaFloat,
+{
+    fn distance_squared(&self, point: &Point<N>) -> N {
+        self.nearest_point(point).distance_squared(point)
+    }
+}
+
+impl<N> Nearest<N, Point<N>> for Capsule<N>
+where
+    N: PrimaFloat,
+{
+    fn nearest_point(&self, point: &Point<N>) -> Point<N> {
+        if self.contains(point) {
+            return *point;
+        }
+        let core = self.line.nearest_point(point);
+        core + (*point - core).normalize() * self.radius
+    }
+}
@@ -1,13 +1,14 @@
 use crate::{
     core::{Collision, Line, Point, Rotation, Vector},
     nums::PrimaFloat,
-    traits::{Collide, Curved, Distance, LocalPosition, LocalRotation, Magnitude, Nearest, Shape, Flat},
+    traits::{Collide, Curved, Distance, Intersections, LocalPosition, LocalRotation, Magnitude, Nearest, Shape, SignedDistance, Flat},
 };
 
-use super::{Aabr, Obr};
+use super::{Aabr, Obr, Polygon};
 
 /// A circle. It is big and round and has a radius.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Circle<N> {
     /// The radius of the circle.
     pub radius: N,
@@ -22,6 +23,24 @@ impl<N> Circle<N> {
     }
 }
 
+impl<N> Circle<N>
+where
+    N: PrimaFloat,
+{
+    /// Approximates the circle as a regular polygon with the given number of sides, wound counter-clockwise.
+    /// The vertices lie on the circle, so the polygon sits just inside it.
+    pub fn to_polygon(&self, sides: usize) -> Polygon<N> {
+        let step = (N::pi() + N::pi()) / N::from_usize(sides).unwrap();
+        let points = (0..sides)
+            .map(|i| {
+                let a = step * N::from_usize(i).unwrap();
+                self.center + Vector::new(a.cos(), a.sin()) * self.radius
+            })
+            .collect();
+        Polygon::new(points)
+    }
+}
+
 impl<N> Shape<N> for Circle<N>
 where
     N: PrimaFloat,
@@ -42,7 +61,7 @@ where
     }
 
     fn bounding_circle(&self) -> Circle<N> {
-        self.clone()
+        *self
     }
 
     fn contains(&self, point: &Point<N>) -> bool {
@@ -83,6 +102,19 @@ impl<N> Curved<N> for Circle<N> where N: PrimaFloat {}
 //========================= POINT =================================//
 //=================================================================//
 
+impl<N> SignedDistance<N> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        self.center.distance(point) - self.radius
+    }
+
+    fn gradient(&self, point: &Point<N>) -> Vector<N> {
+        (*point - self.center).try_normalize().unwrap_or_else(Vector::zero)
+    }
+}
+
 impl<N> Distance<N, Point<N>> for Circle<N>
 where
     N: PrimaFloat,
@@ -179,6 +211,45 @@ where
     }
 }
 
+impl<N> Intersections<N, Line<N>> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    /// Ordered from the start of the line.
+    fn intersections(&self, line: &Line<N>) -> Vec<Point<N>> {
+        line.intersections(self)
+    }
+}
+
+impl<N> Intersections<N, Circle<N>> for Line<N>
+where
+    N: PrimaFloat,
+{
+    /// Ordered from the start of the line. A line that only touches the circle gives one point.
+    fn intersections(&self, circle: &Circle<N>) -> Vec<Point<N>> {
+        let v = self.vector();
+        let f = self.start - circle.center;
+        let a = v.dot(&v);
+        if a == N::zero() {
+            return Vec::new();
+        }
+        let b = f.dot(&v);
+        let disc = b * b - a * (f.dot(&f) - circle.radius * circle.radius);
+        if disc < N::zero() {
+            return Vec::new();
+        }
+        let root = disc.sqrt();
+        let mut ts = vec![(-b - root) / a];
+        if root > N::zero() {
+            ts.push((-b + root) / a);
+        }
+        ts.into_iter()
+            .filter(|t| t.is_decimal())
+            .map(|t| self.start + v * t)
+            .collect()
+    }
+}
+
 //=================================================================//
 //============================ CIRCLE =============================//
 //=================================================================//
@@ -188,10 +259,8 @@ where
     N: PrimaFloat,
 {
     fn distance_squared(&self, other: &Circle<N>) -> N {
-        let ar = self.radius.powi(2);
-        let br = other.radius.powi(2);
-        let total_dist_sqrd = self.center.distance_squared(&other.center);
-        total_dist_sqrd - ar - br
+        let gap = self.center.distance(&other.center) - self.radius - other.radius;
+        gap.max(N::zero()).powi(2)
     }
 }
 
@@ -213,7 +282,7 @@ where
         let d = self.center.distance(&other.center);
         let r = self.radius + other.radius;
         if d <= r {
-            let normal: Vector<N> = (other.center - self.center).into();
+            let normal: Vector<N> = other.center - self.center;
             if normal == Vector::zero() {
                 Some(Collision::new(
                     self.center,
@@ -248,6 +317,35 @@ where
     }
 }
 
+impl<N> Intersections<N, Circle<N>> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    /// Circles that only touch give one point. Otherwise the first point lies to the left of
+    /// the line from this circle's center to the other's. Identical circles give none.
+    fn intersections(&self, other: &Circle<N>) -> Vec<Point<N>> {
+        let offset = other.center - self.center;
+        let d = offset.magnitude();
+        if d == N::zero()
+            || d > self.radius + other.radius
+            || d < (self.radius - other.radius).abs()
+        {
+            return Vec::new();
+        }
+        let two = N::one() + N::one();
+        let r2 = self.radius * self.radius;
+        let along = (r2 - other.radius * other.radius + d * d) / (two * d);
+        let direction = offset / d;
+        let middle = self.center + direction * along;
+        let h2 = r2 - along * along;
+        if h2 <= N::zero() {
+            return vec![middle];
+        }
+        let side = direction.perpendicular_cc() * h2.sqrt();
+        vec![middle + side, middle - side]
+    }
+}
+
 //=================================================================//
 //============================= AABR ==============================//
 //=================================================================//
@@ -257,9 +355,7 @@ where
     N: PrimaFloat,
 {
     fn distance_squared(&self, aabr: &Aabr<N>) -> N {
-        let a = self.nearest_point(aabr);
-        let b = aabr.nearest_point(self);
-        a.distance_squared(&b)
+        aabr.distance_squared(self)
     }
 }
 
@@ -326,7 +422,7 @@ where
     fn nearest_point(&self, obr: &Obr<N>) -> Point<N> {
         let rotation = obr.rotation.into();
         let aabr = obr.as_aabr();
-        let mut circle = self.clone();
+        let mut circle = *self;
         circle.rotate_around(obr.center, rotation);
         let p = circle.nearest_point(&aabr);
         p.rotate_around(obr.center, -rotation)
@@ -340,7 +436,7 @@ where
     fn collision(&self, obr: &Obr<N>) -> Option<Collision<N>> {
         let rotation = obr.rotation.into();
         let aabr = obr.as_aabr();
-        let mut circle = self.clone();
+        let mut circle = *self;
         circle.rotate_around(obr.center, rotation);
 
         let collision = circle.collision(&aabr);
@@ -360,7 +456,7 @@ where
     fn enveloped_by(&self, obr: &Obr<N>) -> bool {
         let rotation = obr.rotation.into();
         let aabr = obr.as_aabr();
-        let mut circle = self.clone();
+        let mut circle = *self;
         circle.rotate_around(obr.center, rotation);
         aabr.enveloping(&circle)
     }
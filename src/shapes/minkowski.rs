@@ -0,0 +1,292 @@
+use crate::{
+    core::{Line, Point},
+    nums::PrimaFloat,
+    traits::{Flat, Minkowski},
+};
+
+use super::{Aabr, Capsule, Circle, Obr, Polygon, RoundedPolygon};
+
+/// The convex hull of every pairing of points from `a` and `b`.
+fn hull_of_sums<N>(a: &[Point<N>], b: &[Point<N>]) -> Polygon<N>
+where
+    N: PrimaFloat,
+{
+    let mut sums = Vec::with_capacity(a.len() * b.len());
+    for p in a.iter() {
+        for q in b.iter() {
+            sums.push(*p + q.as_vector());
+        }
+    }
+    Polygon::convex_hull(&sums)
+}
+
+/// Reflects a set of points through the origin.
+fn reflect<N>(points: Vec<Point<N>>) -> Vec<Point<N>>
+where
+    N: PrimaFloat,
+{
+    points.into_iter().map(|p| -p).collect()
+}
+
+/// Returns the points describing a convex shape, and how far the shape is grown beyond them.
+trait ConvexPoints<N>
+where
+    N: PrimaFloat,
+{
+    fn convex_points(&self) -> Vec<Point<N>>;
+
+    fn convex_radius(&self) -> N {
+        N::zero()
+    }
+}
+
+macro_rules! convex_flat {
+    ($($T: ident),*) => {
+        $(impl<N> ConvexPoints<N> for $T<N> where N: PrimaFloat {
+            fn convex_points(&self) -> Vec<Point<N>> {
+                self.vertices()
+            }
+        })*
+    };
+}
+
+convex_flat!(Aabr, Obr, Polygon);
+
+impl<N> ConvexPoints<N> for Line<N>
+where
+    N: PrimaFloat,
+{
+    fn convex_points(&self) -> Vec<Point<N>> {
+        vec![self.start, self.end]
+    }
+}
+
+impl<N> ConvexPoints<N> for Capsule<N>
+where
+    N: PrimaFloat,
+{
+    fn convex_points(&self) -> Vec<Point<N>> {
+        vec![self.line.start, self.line.end]
+    }
+
+    fn convex_radius(&self) -> N {
+        self.radius
+    }
+}
+
+impl<N> ConvexPoints<N> for RoundedPolygon<N>
+where
+    N: PrimaFloat,
+{
+    fn convex_points(&self) -> Vec<Point<N>> {
+        self.polygon.vertices()
+    }
+
+    fn convex_radius(&self) -> N {
+        self.radius
+    }
+}
+
+/// Implements minkowski operations between two vertex-based convex shapes, producing a polygon.
+macro_rules! minkowski_convex {
+    ($A: ident, $($B: ident),*) => {
+        $(impl<N> Minkowski<N, $B<N>> for $A<N> where N: PrimaFloat {
+            type Output = Polygon<N>;
+
+            fn minkowski_sum(&self, other: &$B<N>) -> Polygon<N> {
+                hull_of_sums(&self.convex_points(), &other.convex_points())
+            }
+
+            fn minkowski_difference(&self, other: &$B<N>) -> Polygon<N> {
+                hull_of_sums(&self.convex_points(), &reflect(other.convex_points()))
+            }
+        })*
+    };
+}
+
+minkowski_convex!(Aabr, Aabr, Obr, Polygon, Line);
+minkowski_convex!(Obr, Aabr, Obr, Polygon, Line);
+minkowski_convex!(Polygon, Aabr, Obr, Polygon, Line);
+minkowski_convex!(Line, Aabr, Obr, Polygon, Line);
+
+/// Implements minkowski operations between a vertex-based convex shape and a circle, producing a rounded polygon.
+macro_rules! minkowski_rounded {
+    ($($A: ident),*) => {
+        $(impl<N> Minkowski<N, Circle<N>> for $A<N> where N: PrimaFloat {
+            type Output = RoundedPolygon<N>;
+
+            fn minkowski_sum(&self, circle: &Circle<N>) -> RoundedPolygon<N> {
+                let core = hull_of_sums(&self.convex_points(), &[circle.center]);
+                RoundedPolygon::new(core, circle.radius)
+            }
+
+            fn minkowski_difference(&self, circle: &Circle<N>) -> RoundedPolygon<N> {
+                let core = hull_of_sums(&self.convex_points(), &[-circle.center]);
+                RoundedPolygon::new(core, circle.radius)
+            }
+        }
+
+        impl<N> Minkowski<N, $A<N>> for Circle<N> where N: PrimaFloat {
+            type Output = RoundedPolygon<N>;
+
+            fn minkowski_sum(&self, other: &$A<N>) -> RoundedPolygon<N> {
+                other.minkowski_sum(self)
+            }
+
+            fn minkowski_difference(&self, other: &$A<N>) -> RoundedPolygon<N> {
+                let core = hull_of_sums(&[self.center], &reflect(other.convex_points()));
+                RoundedPolygon::new(core, self.radius)
+            }
+        })*
+    };
+}
+
+minkowski_rounded!(Aabr, Obr, Polygon);
+
+/// Implements minkowski operations between two convex shapes where either is rounded, producing a rounded
+/// polygon around the combined cores with the summed radius.
+macro_rules! minkowski_cores {
+    ($A: ident, $($B: ident),*) => {
+        $(impl<N> Minkowski<N, $B<N>> for $A<N> where N: PrimaFloat {
+            type Output = RoundedPolygon<N>;
+
+            fn minkowski_sum(&self, other: &$B<N>) -> RoundedPolygon<N> {
+                let core = hull_of_sums(&self.convex_points(), &other.convex_points());
+                RoundedPolygon::new(core, self.convex_radius() + other.convex_radius())
+            }
+
+            fn minkowski_difference(&self, other: &$B<N>) -> RoundedPolygon<N> {
+                let core = hull_of_sums(&self.convex_points(), &reflect(other.convex_points()));
+                RoundedPolygon::new(core, self.convex_radius() + other.convex_radius())
+            }
+        })*
+    };
+}
+
+minkowski_cores!(Capsule, Aabr, Obr, Polygon, Line, Capsule, RoundedPolygon);
+minkowski_cores!(RoundedPolygon, Aabr, Obr, Polygon, Line, Capsule, RoundedPolygon);
+minkowski_cores!(Aabr, Capsule, RoundedPolygon);
+minkowski_cores!(Obr, Capsule, RoundedPolygon);
+minkowski_cores!(Polygon, Capsule, RoundedPolygon);
+minkowski_cores!(Line, Capsule, RoundedPolygon);
+
+impl<N> Minkowski<N, Circle<N>> for Line<N>
+where
+    N: PrimaFloat,
+{
+    type Output = Capsule<N>;
+
+    fn minkowski_sum(&self, circle: &Circle<N>) -> Capsule<N> {
+        let offset = circle.center.as_vector();
+        Capsule::new(Line::new(self.start + offset, self.end + offset), circle.radius)
+    }
+
+    fn minkowski_difference(&self, circle: &Circle<N>) -> Capsule<N> {
+        let offset = -circle.center.as_vector();
+        Capsule::new(Line::new(self.start + offset, self.end + offset), circle.radius)
+    }
+}
+
+impl<N> Minkowski<N, Line<N>> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    type Output = Capsule<N>;
+
+    fn minkowski_sum(&self, line: &Line<N>) -> Capsule<N> {
+        line.minkowski_sum(self)
+    }
+
+    fn minkowski_difference(&self, line: &Line<N>) -> Capsule<N> {
+        let start = (self.center - line.start).as_point();
+        let end = (self.center - line.end).as_point();
+        Capsule::new(Line::new(start, end), self.radius)
+    }
+}
+
+impl<N> Minkowski<N> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    type Output = Circle<N>;
+
+    fn minkowski_sum(&self, other: &Self) -> Circle<N> {
+        Circle::new(self.center + other.center.as_vector(), self.radius + other.radius)
+    }
+
+    fn minkowski_difference(&self, other: &Self) -> Circle<N> {
+        Circle::new((self.center - other.center).as_point(), self.radius + other.radius)
+    }
+}
+
+impl<N> Minkowski<N, Circle<N>> for Capsule<N>
+where
+    N: PrimaFloat,
+{
+    type Output = Capsule<N>;
+
+    fn minkowski_sum(&self, circle: &Circle<N>) -> Capsule<N> {
+        let mut capsule = self.line.minkowski_sum(circle);
+        capsule.radius += self.radius;
+        capsule
+    }
+
+    fn minkowski_difference(&self, circle: &Circle<N>) -> Capsule<N> {
+        let mut capsule = self.line.minkowski_difference(circle);
+        capsule.radius += self.radius;
+        capsule
+    }
+}
+
+impl<N> Minkowski<N, Circle<N>> for RoundedPolygon<N>
+where
+    N: PrimaFloat,
+{
+    type Output = RoundedPolygon<N>;
+
+    fn minkowski_sum(&self, circle: &Circle<N>) -> RoundedPolygon<N> {
+        let mut rounded = self.polygon.minkowski_sum(circle);
+        rounded.radius += self.radius;
+        rounded
+    }
+
+    fn minkowski_difference(&self, circle: &Circle<N>) -> RoundedPolygon<N> {
+        let mut rounded = self.polygon.minkowski_difference(circle);
+        rounded.radius += self.radius;
+        rounded
+    }
+}
+
+impl<N> Minkowski<N, Capsule<N>> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    type Output = Capsule<N>;
+
+    fn minkowski_sum(&self, capsule: &Capsule<N>) -> Capsule<N> {
+        capsule.minkowski_sum(self)
+    }
+
+    fn minkowski_difference(&self, capsule: &Capsule<N>) -> Capsule<N> {
+        let mut difference = self.minkowski_difference(&capsule.line);
+        difference.radius += capsule.radius;
+        difference
+    }
+}
+
+impl<N> Minkowski<N, RoundedPolygon<N>> for Circle<N>
+where
+    N: PrimaFloat,
+{
+    type Output = RoundedPolygon<N>;
+
+    fn minkowski_sum(&self, rounded: &RoundedPolygon<N>) -> RoundedPolygon<N> {
+        rounded.minkowski_sum(self)
+    }
+
+    fn minkowski_difference(&self, rounded: &RoundedPolygon<N>) -> RoundedPolygon<N> {
+        let mut difference = self.minkowski_difference(&rounded.polygon);
+        difference.radius += rounded.radius;
+        difference
+    }
+}
@@ -1,7 +1,16 @@
 mod aabr;
+mod any;
+mod capsule;
 mod circle;
+mod minkowski;
 mod obr;
+mod polygon;
+mod rounded_polygon;
 
 pub use aabr::*;
+pub use any::*;
+pub use capsule::*;
 pub use circle::*;
-pub use obr::*;
\ No newline at end of file
+pub use obr::*;
+pub use polygon::*;
+pub use rounded_polygon::*;
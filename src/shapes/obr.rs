@@ -1,9 +1,17 @@
-use crate::{prelude::*, core::project_shape_to_axis_pair};
+use crate::{core::{edge_crossings, project_shape_to_axis_pair}, prelude::*};
 
-use super::{Aabr, Circle};
+use super::{box_distance, Aabr, Circle, Polygon, RoundedPolygon};
 
 /// An orientated bounding rectangle.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: PrimaFloat + serde::Serialize",
+        deserialize = "N: PrimaFloat + serde::Deserialize<'de>"
+    ))
+)]
 pub struct Obr<N> {
     /// The center point of this rectangle.
     pub center: Point<N>,
@@ -36,6 +44,11 @@ where
         Aabr::from_point(Point::zero(), self.extent.width(), self.extent.height())
     }
 
+    /// Grows the rectangle outward by the given radius, returning a rounded rectangle.
+    pub fn inflate(&self, radius: N) -> RoundedPolygon<N> {
+        RoundedPolygon::new(Polygon::convex_hull(&self.vertices()), radius)
+    }
+
     /// Returns the normal of the x axis in global space.
     pub fn x_axis(&self) -> Vector<N> {
         let x: Vector<N> = Vector::right();
@@ -68,37 +81,25 @@ where
         let mut max = Point::new(N::neg_infinity(), N::neg_infinity());
 
         for v in self.vertices() {
-            if v.x < min.x {
-                min.x = v.x;
-            } else if v.x > max.x {
-                max.x = v.x;
-            }
-            if v.y < min.y {
-                min.y = v.y;
-            } else if v.y > max.y {
-                max.y = v.y;
-            }
+            min.x = min.x.min(v.x);
+            min.y = min.y.min(v.y);
+            max.x = max.x.max(v.x);
+            max.y = max.y.max(v.y);
         }
         Aabr::new(min, max)
     }
 
     fn bounding_circle(&self) -> Circle<N> {
         let center = self.position();
-        let corner = self.center + self.extent.half();
+        let corner = self.center + self.extent;
         let r = self.center.distance(&corner);
         Circle::new(center, r)
     }
 
     fn contains(&self, point: &Point<N>) -> bool {
-        let aabr = Aabr::new(
-            Point::zero(),
-            Point::new(self.extent.width(), self.extent.height()),
-        );
-        let point_relative = Point::new(
-            point.x - self.center.x - self.extent.half_width(),
-            point.y - self.center.y - self.extent.half_height(),
-        ) * -self.rotation();
-        aabr.contains(&point_relative)
+        let d = *point - self.center;
+        d.dot(&self.x_axis()).abs() <= self.extent.half_width()
+            && d.dot(&self.y_axis()).abs() <= self.extent.half_height()
     }
 }
 
@@ -137,7 +138,7 @@ where
     }
 
     fn translate(&mut self, offset: &Vector<N>) {
-        self.center = self.center + *offset;
+        self.center += *offset;
     }
 }
 
@@ -157,6 +158,18 @@ where
 //========================= POINT =================================//
 //=================================================================//
 
+impl<N> SignedDistance<N> for Obr<N>
+where
+    N: PrimaFloat,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        let d = *point - self.center;
+        let x = d.dot(&self.x_axis()).abs() - self.extent.half_width();
+        let y = d.dot(&self.y_axis()).abs() - self.extent.half_height();
+        box_distance(x, y)
+    }
+}
+
 impl<N> Distance<N, Point<N>> for Obr<N>
 where
     N: PrimaFloat,
@@ -170,8 +183,14 @@ impl<N> Nearest<N, Point<N>> for Obr<N>
 where
     N: PrimaFloat,
 {
-    fn nearest_point(&self, _point: &Point<N>) -> Point<N> {
-        todo!()
+    /// Returns the point itself if it lies inside the rectangle.
+    fn nearest_point(&self, point: &Point<N>) -> Point<N> {
+        let (x_axis, y_axis) = (self.x_axis(), self.y_axis());
+        let d = *point - self.center;
+        let (hw, hh) = (self.extent.half_width(), self.extent.half_height());
+        let x = d.dot(&x_axis).max(-hw).min(hw);
+        let y = d.dot(&y_axis).max(-hh).min(hh);
+        self.center + x_axis * x + y_axis * y
     }
 }
 
@@ -214,6 +233,26 @@ where
     }
 }
 
+impl<N> Intersections<N, Line<N>> for Obr<N>
+where
+    N: PrimaFloat,
+{
+    /// Ordered from the start of the line.
+    fn intersections(&self, line: &Line<N>) -> Vec<Point<N>> {
+        line.intersections(self)
+    }
+}
+
+impl<N> Intersections<N, Obr<N>> for Line<N>
+where
+    N: PrimaFloat,
+{
+    /// Ordered from the start of the line.
+    fn intersections(&self, obr: &Obr<N>) -> Vec<Point<N>> {
+        self.crossings(&obr.edges())
+    }
+}
+
 //=================================================================//
 //============================ CIRCLE =============================//
 //=================================================================//
@@ -359,3 +398,13 @@ where
         todo!()
     }
 }
+
+impl<N> Intersections<N, Obr<N>> for Obr<N>
+where
+    N: PrimaFloat,
+{
+    /// Ordered around the edges of self.
+    fn intersections(&self, other: &Obr<N>) -> Vec<Point<N>> {
+        edge_crossings(&self.edges(), &other.edges())
+    }
+}
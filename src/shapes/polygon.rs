@@ -0,0 +1,306 @@
+use crate::{
+    core::{offset_points, Line, Point, Rotation, Vector},
+    nums::PrimaFloat,
+    traits::{Cross, Distance, Flat, LocalPosition, LocalRotation, Nearest, Shape, SignedDistance},
+};
+use super::{Aabr, Circle, RoundedPolygon};
+
+/// A simple polygon, made up of a closed loop of vertices.
+/// The last vertex is implicitly connected back to the first.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon<N> {
+    /// The vertices of the polygon, in order.
+    pub points: Vec<Point<N>>,
+}
+
+impl<N> Polygon<N> {
+    /// Creates a new polygon from a loop of points.
+    pub fn new(points: Vec<Point<N>>) -> Self {
+        Self { points }
+    }
+
+    /// The number of vertices in this polygon.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns true if the polygon has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+impl<N> Polygon<N>
+where
+    N: PrimaFloat,
+{
+    /// Creates the convex hull of a set of points, wound counter-clockwise.
+    pub fn convex_hull(points: &[Point<N>]) -> Self {
+        // Andrew's monotone chain.
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| {
+            a.x.partial_cmp(&b.x)
+                .unwrap()
+                .then(a.y.partial_cmp(&b.y).unwrap())
+        });
+        sorted.dedup();
+        if sorted.len() < 3 {
+            return Self::new(sorted);
+        }
+        let turn = |a: Point<N>, b: Point<N>, c: Point<N>| (b - a).cross(&(c - a));
+        let mut hull: Vec<Point<N>> = Vec::with_capacity(sorted.len() * 2);
+        for pass in [sorted.clone(), sorted.into_iter().rev().collect()] {
+            let floor = hull.len();
+            for p in pass {
+                while hull.len() >= floor + 2
+                    && turn(hull[hull.len() - 2], hull[hull.len() - 1], p) <= N::zero()
+                {
+                    hull.pop();
+                }
+                hull.push(p);
+            }
+            hull.pop();
+        }
+        Self::new(hull)
+    }
+
+    /// The signed area of the polygon. This is positive when the vertices wind counter-clockwise.
+    pub fn signed_area(&self) -> N {
+        let mut area = N::zero();
+        for i in 0..self.points.len() {
+            let a = self.points[i];
+            let b = self.points[(i + 1) % self.points.len()];
+            area += a.x * b.y - b.x * a.y;
+        }
+        area / (N::one() + N::one())
+    }
+
+    /// Returns true if the vertices wind clockwise.
+    pub fn is_clockwise(&self) -> bool {
+        self.signed_area() < N::zero()
+    }
+
+    /// Reverses the winding order of the polygon.
+    pub fn reverse(&mut self) {
+        self.points.reverse();
+    }
+
+    /// Returns true if the polygon is convex.
+    pub fn is_convex(&self) -> bool {
+        let n = self.points.len();
+        if n < 3 {
+            return false;
+        }
+        let mut sign = N::zero();
+        for i in 0..n {
+            let a = self.points[i];
+            let b = self.points[(i + 1) % n];
+            let c = self.points[(i + 2) % n];
+            let cross = (b - a).x * (c - b).y - (b - a).y * (c - b).x;
+            if cross != N::zero() {
+                if sign != N::zero() && cross.signum() != sign {
+                    return false;
+                }
+                sign = cross.signum();
+            }
+        }
+        true
+    }
+
+    /// Grows the polygon outward by the given distance, using mitered corners. Negative values shrink it.
+    /// Sharp outer corners are squared off rather than mitered, so they keep `distance` clear of the original.
+    pub fn offset(&self, distance: N) -> Self {
+        // The left-hand normal of a counter-clockwise polygon faces inward.
+        let distance = if self.is_clockwise() { distance } else { -distance };
+        Self::new(offset_points(&self.points, distance, true))
+    }
+
+    /// Grows the convex hull of the polygon outward by the given radius, giving it rounded corners.
+    pub fn inflate(&self, radius: N) -> RoundedPolygon<N> {
+        RoundedPolygon::new(Self::convex_hull(&self.points), radius)
+    }
+
+    /// The area-weighted centroid of the polygon.
+    pub fn centroid(&self) -> Point<N> {
+        // Work relative to the first vertex, as products of large coordinates lose the precision
+        // of small polygons far from the origin.
+        let origin = self.points.first().copied().unwrap_or(Point::zero());
+        let mut x = N::zero();
+        let mut y = N::zero();
+        let mut twice_area = N::zero();
+        for i in 0..self.points.len() {
+            let a = self.points[i] - origin;
+            let b = self.points[(i + 1) % self.points.len()] - origin;
+            let f = a.x * b.y - b.x * a.y;
+            x += (a.x + b.x) * f;
+            y += (a.y + b.y) * f;
+            twice_area += f;
+        }
+        if twice_area == N::zero() {
+            // Degenerate polygon, so fall back to the vertex average.
+            let mut sum = Vector::zero();
+            for p in self.points.iter() {
+                sum += p.as_vector();
+            }
+            return (sum / N::from_usize(self.points.len().max(1)).unwrap()).as_point();
+        }
+        let three = N::from_u8(3).unwrap();
+        origin + Vector::new(x / (three * twice_area), y / (three * twice_area))
+    }
+}
+
+impl<N> Shape<N> for Polygon<N>
+where
+    N: PrimaFloat,
+{
+    fn volume(&self) -> N {
+        self.signed_area().abs()
+    }
+
+    fn circumference(&self) -> N {
+        let mut total = N::zero();
+        for edge in self.edges() {
+            total += edge.start.distance(&edge.end);
+        }
+        total
+    }
+
+    fn bounding_rect(&self) -> Aabr<N> {
+        let mut min = Point::new(N::infinity(), N::infinity());
+        let mut max = Point::new(N::neg_infinity(), N::neg_infinity());
+        for p in self.points.iter() {
+            min.x = min.x.min(p.x);
+            min.y = min.y.min(p.y);
+            max.x = max.x.max(p.x);
+            max.y = max.y.max(p.y);
+        }
+        Aabr::new(min, max)
+    }
+
+    fn bounding_circle(&self) -> Circle<N> {
+        let center = self.bounding_rect().position();
+        let mut radius = N::zero();
+        for p in self.points.iter() {
+            radius = radius.max(center.distance(p));
+        }
+        Circle::new(center, radius)
+    }
+
+    fn contains(&self, point: &Point<N>) -> bool {
+        // Even-odd crossing test.
+        let mut inside = false;
+        let n = self.points.len();
+        let mut j = n.wrapping_sub(1);
+        for i in 0..n {
+            let a = self.points[i];
+            let b = self.points[j];
+            if (a.y > point.y) != (b.y > point.y)
+                && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+            {
+                inside = !inside;
+            }
+            j = i;
+        }
+        inside || self.edges().iter().any(|e| e.distance_squared(point) == N::zero())
+    }
+}
+
+impl<N> Flat<N> for Polygon<N>
+where
+    N: PrimaFloat,
+{
+    fn vertices(&self) -> Vec<Point<N>> {
+        self.points.clone()
+    }
+}
+
+impl<N> LocalPosition<N> for Polygon<N>
+where
+    N: PrimaFloat,
+{
+    fn position(&self) -> Point<N> {
+        self.centroid()
+    }
+
+    fn translate(&mut self, offset: &Vector<N>) {
+        for p in self.points.iter_mut() {
+            *p += *offset;
+        }
+    }
+}
+
+/// Polygons do not store a rotation, so rotating one rotates all of its vertices around the centroid.
+impl<N> LocalRotation<N> for Polygon<N>
+where
+    N: PrimaFloat,
+{
+    fn rotation(&self) -> Rotation<N> {
+        Rotation::zero()
+    }
+
+    fn rotate(&mut self, rotation: Rotation<N>) {
+        let center = self.centroid();
+        let mat = rotation.to_matrix();
+        for p in self.points.iter_mut() {
+            *p = p.rotate_around_mat(center, mat);
+        }
+    }
+}
+
+//=================================================================//
+//========================= POINT =================================//
+//=================================================================//
+
+impl<N> SignedDistance<N> for Polygon<N>
+where
+    N: PrimaFloat,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        let d = nearest_on_edges(&self.edges(), point).distance(point);
+        if self.contains(point) {
+            -d
+        } else {
+            d
+        }
+    }
+}
+
+impl<N> Distance<N, Point<N>> for Polygon<N>
+where
+    N: PrimaFloat,
+{
+    fn distance_squared(&self, point: &Point<N>) -> N {
+        self.nearest_point(point).distance_squared(point)
+    }
+}
+
+impl<N> Nearest<N, Point<N>> for Polygon<N>
+where
+    N: PrimaFloat,
+{
+    fn nearest_point(&self, point: &Point<N>) -> Point<N> {
+        if self.contains(point) {
+            return *point;
+        }
+        nearest_on_edges(&self.edges(), point)
+    }
+}
+
+/// Returns the nearest point on a collection of edges.
+pub(crate) fn nearest_on_edges<N>(edges: &[Line<N>], point: &Point<N>) -> Point<N>
+where
+    N: PrimaFloat,
+{
+    let mut nearest = *point;
+    let mut best = N::infinity();
+    for edge in edges.iter() {
+        let p = edge.nearest_point(point);
+        let d = p.distance_squared(point);
+        if d < best {
+            best = d;
+            nearest = p;
+        }
+    }
+    nearest
+}
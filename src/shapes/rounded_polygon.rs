@@ -0,0 +1,119 @@
+use crate::{
+    core::{Point, Rotation, Vector},
+    nums::PrimaFloat,
+    traits::{Curved, Distance, Flat, LocalPosition, LocalRotation, Nearest, Shape, SignedDistance},
+};
+
+use super::{nearest_on_edges, Aabr, Circle, Polygon};
+
+/// A convex polygon grown outward by a radius, giving it rounded corners.
+/// An inflated rectangle gives a rounded rectangle.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoundedPolygon<N> {
+    /// The convex polygon at the core of the shape.
+    pub polygon: Polygon<N>,
+    /// How far the shape extends beyond the core polygon.
+    pub radius: N,
+}
+
+impl<N> RoundedPolygon<N> {
+    /// Creates a new rounded polygon from a convex core and a radius.
+    pub fn new(polygon: Polygon<N>, radius: N) -> Self {
+        Self { polygon, radius }
+    }
+}
+
+impl<N> Shape<N> for RoundedPolygon<N>
+where
+    N: PrimaFloat,
+{
+    fn volume(&self) -> N {
+        self.polygon.volume()
+            + self.polygon.circumference() * self.radius
+            + N::pi() * self.radius * self.radius
+    }
+
+    fn circumference(&self) -> N {
+        self.polygon.circumference() + (N::pi() + N::pi()) * self.radius
+    }
+
+    fn bounding_rect(&self) -> Aabr<N> {
+        let bb = self.polygon.bounding_rect();
+        let r = Vector::splat(self.radius);
+        Aabr::new(bb.min + -r, bb.max + r)
+    }
+
+    fn bounding_circle(&self) -> Circle<N> {
+        let circle = self.polygon.bounding_circle();
+        Circle::new(circle.center, circle.radius + self.radius)
+    }
+
+    fn contains(&self, point: &Point<N>) -> bool {
+        self.polygon.distance_squared(point) <= self.radius * self.radius
+    }
+}
+
+impl<N> Curved<N> for RoundedPolygon<N> where N: PrimaFloat {}
+
+impl<N> LocalPosition<N> for RoundedPolygon<N>
+where
+    N: PrimaFloat,
+{
+    fn position(&self) -> Point<N> {
+        self.polygon.position()
+    }
+
+    fn translate(&mut self, offset: &Vector<N>) {
+        self.polygon.translate(offset);
+    }
+}
+
+/// Like [Polygon], rotating a rounded polygon rotates its vertices around the centroid.
+impl<N> LocalRotation<N> for RoundedPolygon<N>
+where
+    N: PrimaFloat,
+{
+    fn rotation(&self) -> Rotation<N> {
+        Rotation::zero()
+    }
+
+    fn rotate(&mut self, rotation: Rotation<N>) {
+        self.polygon.rotate(rotation);
+    }
+}
+
+//=================================================================//
+//========================= POINT =================================//
+//=================================================================//
+
+impl<N> SignedDistance<N> for RoundedPolygon<N>
+where
+    N: PrimaFloat,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        self.polygon.signed_distance(point) - self.radius
+    }
+}
+
+impl<N> Distance<N, Point<N>> for RoundedPolygon<N>
+where
+    N: PrimaFloat,
+{
+    fn distance_squared(&self, point: &Point<N>) -> N {
+        self.nearest_point(point).distance_squared(point)
+    }
+}
+
+impl<N> Nearest<N, Point<N>> for RoundedPolygon<N>
+where
+    N: PrimaFloat,
+{
+    fn nearest_point(&self, point: &Point<N>) -> Point<N> {
+        if self.contains(point) {
+            return *point;
+        }
+        let core = nearest_on_edges(&self.polygon.edges(), point);
+        core + (*point - core).normalize() * self.radius
+    }
+}
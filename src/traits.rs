@@ -1,11 +1,12 @@
 use crate::{
     core::*,
     nums::{PrimaFloat, PrimaNum},
+    sdf::{Intersection, Offset, SmoothUnion, Union},
     shapes::{Aabr, Circle},
 };
-///=============================================================///
-///======================= LOCAL TRAITS ========================///
-///=============================================================///
+//=============================================================//
+//======================= LOCAL TRAITS ========================//
+//=============================================================//
 
 /// The main trait for a geometric object.
 pub trait Shape<N> {
@@ -40,6 +41,86 @@ where
     }
 }
 
+/// A parametric curve, evaluated over `t` in the range of [0..1].
+pub trait Curve<N>
+where
+    N: PrimaFloat,
+{
+    /// Returns the point on the curve at `t`.
+    fn point_at(&self, t: N) -> Point<N>;
+
+    /// Returns the first derivative of the curve at `t`.
+    fn derivative_at(&self, t: N) -> Vector<N>;
+
+    /// Flattens the curve into a polyline that stays within `tolerance` of the true curve.
+    fn flatten(&self, tolerance: N) -> Polyline<N>;
+
+    /// Returns the normalized direction of the curve at `t`.
+    fn tangent_at(&self, t: N) -> Vector<N> {
+        self.derivative_at(t).normalize()
+    }
+
+    /// Returns the normal of the curve at `t`. This faces 90 degrees to the left, matching [Line::normal].
+    fn normal_at(&self, t: N) -> Vector<N> {
+        self.tangent_at(t).perpendicular_cc()
+    }
+
+    /// Returns the arc length of the curve between the start and `t`.
+    fn length_to(&self, t: N) -> N {
+        // Composite Simpson's rule over the speed of the curve.
+        let steps = 32;
+        let t = t.clamp_01();
+        let h = t / N::from_usize(steps).unwrap();
+        let two = N::one() + N::one();
+        let four = two + two;
+        let mut sum = self.derivative_at(N::zero()).magnitude() + self.derivative_at(t).magnitude();
+        for i in 1..steps {
+            let weight = if i % 2 == 0 { two } else { four };
+            sum += weight * self.derivative_at(h * N::from_usize(i).unwrap()).magnitude();
+        }
+        sum * h / N::from_u8(3).unwrap()
+    }
+
+    /// Returns the `t` value that lies at the given arc length along the curve.
+    fn t_at_distance(&self, distance: N) -> N {
+        let length = self.length_to(N::one());
+        if distance <= N::zero() || length == N::zero() {
+            return N::zero();
+        }
+        if distance >= length {
+            return N::one();
+        }
+        // Newton's method, falling back on bisection when it strays out of bounds.
+        let mut low = N::zero();
+        let mut high = N::one();
+        let mut t = distance / length;
+        for _ in 0..16 {
+            let error = self.length_to(t) - distance;
+            if error.abs() <= N::epsilon() * length {
+                break;
+            }
+            if error > N::zero() {
+                high = t;
+            } else {
+                low = t;
+            }
+            let speed = self.derivative_at(t).magnitude();
+            let next = t - error / speed;
+            t = if speed == N::zero() || next <= low || next >= high {
+                (low + high) / (N::one() + N::one())
+            } else {
+                next
+            };
+        }
+        t
+    }
+
+    /// Returns the point at the given arc length along the curve.
+    fn point_at_distance(&self, distance: N) -> Point<N> {
+        self.point_at(self.t_at_distance(distance))
+    }
+}
+
 /// A shape with curved edges.
 pub trait Curved<N>: Shape<N> {}
 
@@ -83,9 +164,9 @@ where
     fn magnitude_squared(&self) -> N;
 }
 
-///=============================================================///
-///======================= INTERACTIONS ========================///
-///=============================================================///
+//=============================================================//
+//======================= INTERACTIONS ========================//
+//=============================================================//
 
 /// Cross product for points, floats and other such things.
 pub trait Cross<Rhs = Self> {
@@ -110,6 +191,87 @@ where
     }
 }
 
+/// A shape that can measure how far a point lies from its boundary, with distances inside the
+/// shape being negative. Shapes can be combined with [crate::sdf] to build more complex fields.
+pub trait SignedDistance<N>
+where
+    N: PrimaFloat,
+{
+    /// Returns the distance from the point to the boundary, negative inside the shape.
+    fn signed_distance(&self, point: &Point<N>) -> N;
+
+    /// Returns the gradient of the distance at the given point, which points away from the
+    /// shape and has a length of about one. This is estimated by central differences unless
+    /// a shape provides it directly.
+    fn gradient(&self, point: &Point<N>) -> Vector<N> {
+        let scale = N::one().max(point.x.abs()).max(point.y.abs());
+        let h = N::epsilon().sqrt() * scale;
+        let dx = Vector::new(h, N::zero());
+        let dy = Vector::new(N::zero(), h);
+        let two_h = h + h;
+        Vector::new(
+            (self.signed_distance(&(*point + dx)) - self.signed_distance(&(*point - dx))) / two_h,
+            (self.signed_distance(&(*point + dy)) - self.signed_distance(&(*point - dy))) / two_h,
+        )
+    }
+
+    /// Returns the direction away from the shape at the given point, or None where it is
+    /// undefined, such as the very center of a circle.
+    fn normal(&self, point: &Point<N>) -> Option<Vector<N>> {
+        self.gradient(point).try_normalize()
+    }
+
+    /// Combines this shape with another, covering the area of either.
+    fn union<B>(self, other: B) -> Union<Self, B>
+    where
+        Self: Sized,
+        B: SignedDistance<N>,
+    {
+        Union::new(self, other)
+    }
+
+    /// Combines this shape with another, covering only the area of both.
+    fn intersection<B>(self, other: B) -> Intersection<Self, B>
+    where
+        Self: Sized,
+        B: SignedDistance<N>,
+    {
+        Intersection::new(self, other)
+    }
+
+    /// Combines this shape with another like [SignedDistance::union], but blends the seam
+    /// between them over roughly the given distance.
+    fn smooth_union<B>(self, other: B, smoothing: N) -> SmoothUnion<Self, B, N>
+    where
+        Self: Sized,
+        B: SignedDistance<N>,
+    {
+        SmoothUnion::new(self, other, smoothing)
+    }
+
+    /// Grows the shape outward by the given distance, or shrinks it if the distance is negative.
+    fn offset(self, distance: N) -> Offset<Self, N>
+    where
+        Self: Sized,
+    {
+        Offset::new(self, distance)
+    }
+}
+
+impl<N, S> SignedDistance<N> for &S
+where
+    N: PrimaFloat,
+    S: SignedDistance<N> + ?Sized,
+{
+    fn signed_distance(&self, point: &Point<N>) -> N {
+        (**self).signed_distance(point)
+    }
+
+    fn gradient(&self, point: &Point<N>) -> Vector<N> {
+        (**self).gradient(point)
+    }
+}
+
 /// Calculating nearest extents.
 pub trait Nearest<N, Rhs = Self>
 where
@@ -134,4 +296,31 @@ where
     fn enveloping(&self, other: &Rhs) -> bool;
     /// Checks if this object is entirely contained by the other object.
     fn enveloped_by(&self, other: &Rhs) -> bool;
-}
\ No newline at end of file
+}
+
+/// A trait for finding every point where the boundaries of two objects cross.
+pub trait Intersections<N, Rhs = Self>
+where
+    N: PrimaFloat,
+{
+    /// Returns the points where the boundary of self crosses the boundary of the other object.
+    /// Where the boundaries overlap along a stretch, such as collinear edges, no points are given
+    /// for that stretch.
+    fn intersections(&self, other: &Rhs) -> Vec<Point<N>>;
+}
+
+/// Minkowski sums and differences between convex shapes. Any pair of rectangles, polygons, lines,
+/// circles, capsules and rounded polygons can be combined. Flat shapes give a polygon, and rounded
+/// shapes give a rounded polygon, capsule or circle with the radii summed.
+pub trait Minkowski<N, Rhs = Self>
+where
+    N: PrimaFloat,
+{
+    /// The shape produced by combining the two shapes.
+    type Output;
+    /// Returns the minkowski sum of the two shapes, which is every point of self offset by every point of other.
+    fn minkowski_sum(&self, other: &Rhs) -> Self::Output;
+    /// Returns the minkowski difference of the two shapes, which is the sum of self and other reflected through
+    /// the origin. The two shapes are intersecting when the difference contains the origin.
+    fn minkowski_difference(&self, other: &Rhs) -> Self::Output;
+}
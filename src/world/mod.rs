@@ -0,0 +1,5 @@
+mod query;
+mod set;
+
+pub use query::*;
+pub use set::*;
@@ -0,0 +1,281 @@
+use super::{set::touching, Entry, ShapeId, ShapeSet};
+use crate::{
+    core::{Collision, CollisionFilter, Point, Ray, Vector},
+    nums::PrimaFloat,
+    shapes::{Aabr, AnyShape},
+    traits::{Collide, Distance, Nearest, Shape, SignedDistance},
+};
+
+/// A shape found by a [QueryWorld] query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryHit<'a, N, T> {
+    /// The shape that was hit.
+    pub id: ShapeId,
+    /// The user data attached to the shape.
+    pub data: &'a T,
+    /// How far the query travelled to reach the shape. This is in lengths of the ray's direction
+    /// for ray casts, a fraction of the velocity for shape casts and the gap to the shape for
+    /// nearest queries. Point and overlap queries hit at zero.
+    pub distance: N,
+    /// The contact with the shape. The normal points from the query towards the shape, and the
+    /// depth is negative where the shape is still some way off.
+    pub collision: Collision<N>,
+}
+
+/// Point, overlap, ray, shape cast and nearest queries over a [ShapeSet].
+///
+/// Every query takes a [CollisionFilter], whose group and mask bits are checked against the
+/// filter of each shape before it is tested. A query has no user data of its own, so the shapes'
+/// predicates are not consulted; use the data on each hit to narrow the results further. Results
+/// are ordered by distance, then by id.
+#[derive(Debug, Clone)]
+pub struct QueryWorld<N, T = ()> {
+    shapes: ShapeSet<N, T>,
+}
+
+impl<N, T> Default for QueryWorld<N, T> {
+    fn default() -> Self {
+        Self {
+            shapes: ShapeSet::default(),
+        }
+    }
+}
+
+impl<N, T> From<ShapeSet<N, T>> for QueryWorld<N, T> {
+    fn from(shapes: ShapeSet<N, T>) -> Self {
+        Self { shapes }
+    }
+}
+
+impl<N, T> QueryWorld<N, T>
+where
+    N: PrimaFloat,
+{
+    /// Creates an empty world.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The shapes in the world.
+    pub fn shapes(&self) -> &ShapeSet<N, T> {
+        &self.shapes
+    }
+
+    /// The shapes in the world, for adding, moving and removing them.
+    pub fn shapes_mut(&mut self) -> &mut ShapeSet<N, T> {
+        &mut self.shapes
+    }
+
+    /// Adds a shape that interacts with everything.
+    pub fn insert(&mut self, shape: impl Into<AnyShape<N>>, data: T) -> ShapeId {
+        self.shapes.insert(shape, data)
+    }
+
+    /// Adds a shape with the given filter.
+    pub fn insert_filtered(
+        &mut self,
+        shape: impl Into<AnyShape<N>>,
+        filter: CollisionFilter<T>,
+        data: T,
+    ) -> ShapeId {
+        self.shapes.insert_filtered(shape, filter, data)
+    }
+
+    /// Removes a shape, returning its entry if it was present.
+    pub fn remove(&mut self, id: ShapeId) -> Option<Entry<N, T>> {
+        self.shapes.remove(id)
+    }
+
+    /// Every shape containing the point, including on its boundary. The collision's depth is
+    /// how far inside the shape the point is.
+    pub fn point_query(
+        &self,
+        point: &Point<N>,
+        filter: &CollisionFilter,
+    ) -> Vec<QueryHit<'_, N, T>> {
+        let bounds = Aabr::new(*point, *point);
+        self.candidates(*filter)
+            .filter(|(_, e)| {
+                touching(e.bounds(), &bounds) && e.shape.signed_distance(point) <= N::zero()
+            })
+            .map(|(id, e)| point_hit(id, e, point))
+            .collect()
+    }
+
+    /// Every shape intersecting the given one, with the collision from the query shape to it.
+    pub fn overlap_query(
+        &self,
+        shape: &AnyShape<N>,
+        filter: &CollisionFilter,
+    ) -> Vec<QueryHit<'_, N, T>> {
+        let bounds = shape.bounding_rect();
+        self.candidates(*filter)
+            .filter(|(_, e)| touching(e.bounds(), &bounds))
+            .filter_map(|(id, e)| {
+                shape.collision(&e.shape).map(|collision| QueryHit {
+                    id,
+                    data: &e.data,
+                    distance: N::zero(),
+                    collision,
+                })
+            })
+            .collect()
+    }
+
+    /// Every shape the ray touches within `max_distance` lengths of its direction, nearest
+    /// first. Shapes containing the ray's origin are hit at zero.
+    pub fn ray_cast_all(
+        &self,
+        ray: &Ray<N>,
+        max_distance: N,
+        filter: &CollisionFilter,
+    ) -> Vec<QueryHit<'_, N, T>> {
+        let mut hits: Vec<QueryHit<'_, N, T>> = self
+            .candidates(*filter)
+            .filter(|(_, e)| ray_touches(ray, e.bounds(), max_distance))
+            .filter_map(|(id, e)| {
+                let (t, normal) = e.shape.cast_ray(ray)?;
+                if t > max_distance {
+                    return None;
+                }
+                let depth = if t == N::zero() {
+                    -e.shape.signed_distance(&ray.origin)
+                } else {
+                    N::zero()
+                };
+                Some(QueryHit {
+                    id,
+                    data: &e.data,
+                    distance: t,
+                    collision: Collision::new(ray.point_at(t), -normal, depth),
+                })
+            })
+            .collect();
+        hits.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+        hits
+    }
+
+    /// The first shape the ray touches within `max_distance` lengths of its direction.
+    pub fn ray_cast_first(
+        &self,
+        ray: &Ray<N>,
+        max_distance: N,
+        filter: &CollisionFilter,
+    ) -> Option<QueryHit<'_, N, T>> {
+        self.ray_cast_all(ray, max_distance, filter)
+            .into_iter()
+            .next()
+    }
+
+    /// The first shape hit when sweeping the given shape along a velocity. The hit's distance
+    /// is the fraction of the velocity travelled, and its collision is taken at that moment.
+    /// See [AnyShape::cast].
+    pub fn shape_cast(
+        &self,
+        shape: &AnyShape<N>,
+        velocity: &Vector<N>,
+        filter: &CollisionFilter,
+    ) -> Option<QueryHit<'_, N, T>> {
+        let start = shape.bounding_rect();
+        let end = Aabr::new(start.min + *velocity, start.max + *velocity);
+        let swept = Aabr::new(
+            Point::new(start.min.x.min(end.min.x), start.min.y.min(end.min.y)),
+            Point::new(start.max.x.max(end.max.x), start.max.y.max(end.max.y)),
+        );
+        let mut first: Option<QueryHit<'_, N, T>> = None;
+        for (id, e) in self.candidates(*filter) {
+            if !touching(e.bounds(), &swept) {
+                continue;
+            }
+            if let Some((t, collision)) = shape.cast(velocity, &e.shape) {
+                if first.as_ref().is_none_or(|hit| t < hit.distance) {
+                    first = Some(QueryHit {
+                        id,
+                        data: &e.data,
+                        distance: t,
+                        collision,
+                    });
+                }
+            }
+        }
+        first
+    }
+
+    /// The shape nearest to the point, if any lies within `max_distance` of it. Shapes
+    /// containing the point are at zero.
+    pub fn nearest(
+        &self,
+        point: &Point<N>,
+        max_distance: N,
+        filter: &CollisionFilter,
+    ) -> Option<QueryHit<'_, N, T>> {
+        let mut nearest: Option<QueryHit<'_, N, T>> = None;
+        for (id, e) in self.candidates(*filter) {
+            let reach = nearest.as_ref().map_or(max_distance, |hit| hit.distance);
+            if e.bounds().distance(point) > reach {
+                continue;
+            }
+            let d = e.shape.signed_distance(point).max(N::zero());
+            if d <= max_distance && nearest.as_ref().is_none_or(|hit| d < hit.distance) {
+                nearest = Some(point_hit(id, e, point));
+            }
+        }
+        nearest
+    }
+
+    /// The shapes whose filters let them interact with the query's.
+    fn candidates(
+        &self,
+        filter: CollisionFilter,
+    ) -> impl Iterator<Item = (ShapeId, &Entry<N, T>)> + '_ {
+        let filter = CollisionFilter::<T>::new(filter.groups, filter.mask);
+        self.shapes
+            .iter()
+            .filter(move |(_, e)| filter.interacts(&e.filter))
+    }
+}
+
+/// A hit from a point to a shape, at the nearest point of the shape.
+fn point_hit<'a, N, T>(id: ShapeId, entry: &'a Entry<N, T>, point: &Point<N>) -> QueryHit<'a, N, T>
+where
+    N: PrimaFloat,
+{
+    let sd = entry.shape.signed_distance(point);
+    let nearest = entry.shape.nearest_point(point);
+    let normal = if sd > N::zero() {
+        (nearest - *point).normalize()
+    } else {
+        // Pushing the shape against its outward normal moves its boundary past the point.
+        -entry.shape.normal(point).unwrap_or(Vector::right())
+    };
+    QueryHit {
+        id,
+        data: &entry.data,
+        distance: sd.max(N::zero()),
+        collision: Collision::new(nearest, normal, -sd),
+    }
+}
+
+/// Returns true if the ray passes through the rectangle within `max` lengths of its direction.
+fn ray_touches<N: PrimaFloat>(ray: &Ray<N>, bounds: &Aabr<N>, max: N) -> bool {
+    let (mut near, mut far) = (N::zero(), max);
+    let axes = [
+        (ray.origin.x, ray.direction.x, bounds.min.x, bounds.max.x),
+        (ray.origin.y, ray.direction.y, bounds.min.y, bounds.max.y),
+    ];
+    for (origin, direction, min, max) in axes {
+        if direction == N::zero() {
+            if origin < min || origin > max {
+                return false;
+            }
+            continue;
+        }
+        let (a, b) = ((min - origin) / direction, (max - origin) / direction);
+        near = near.max(a.min(b));
+        far = far.min(a.max(b));
+        if near > far {
+            return false;
+        }
+    }
+    true
+}
@@ -0,0 +1,230 @@
+use crate::{
+    core::CollisionFilter,
+    nums::PrimaFloat,
+    shapes::{Aabr, AnyShape},
+    traits::{Collide, Shape},
+};
+
+/// A handle to a shape stored in a [ShapeSet]. Handles of removed shapes may be reused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShapeId(pub usize);
+
+/// A shape stored in a [ShapeSet], along with its filter and user data.
+#[derive(Debug, Clone)]
+pub struct Entry<N, T> {
+    /// The shape itself.
+    pub shape: AnyShape<N>,
+    /// Which other shapes this one may interact with.
+    pub filter: CollisionFilter<T>,
+    /// Data attached by the user, such as the entity the shape belongs to.
+    pub data: T,
+    bounds: Aabr<N>,
+}
+
+impl<N, T> Entry<N, T> {
+    /// The bounding rectangle of the shape, as of when it was last set.
+    pub fn bounds(&self) -> &Aabr<N> {
+        &self.bounds
+    }
+}
+
+/// A collection of mixed shapes, each carrying a [CollisionFilter] and some user data.
+///
+/// Overlapping pairs are found with a sweep and prune over the shapes' bounding rectangles, and
+/// pairs whose filters do not allow them to interact are skipped.
+#[derive(Debug, Clone)]
+pub struct ShapeSet<N, T = ()> {
+    entries: Vec<Option<Entry<N, T>>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+impl<N, T> Default for ShapeSet<N, T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<N, T> ShapeSet<N, T>
+where
+    N: PrimaFloat,
+{
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a shape that interacts with everything.
+    pub fn insert(&mut self, shape: impl Into<AnyShape<N>>, data: T) -> ShapeId {
+        self.insert_filtered(shape, CollisionFilter::ALL, data)
+    }
+
+    /// Adds a shape with the given filter.
+    pub fn insert_filtered(
+        &mut self,
+        shape: impl Into<AnyShape<N>>,
+        filter: CollisionFilter<T>,
+        data: T,
+    ) -> ShapeId {
+        let shape = shape.into();
+        let entry = Entry {
+            bounds: shape.bounding_rect(),
+            shape,
+            filter,
+            data,
+        };
+        self.len += 1;
+        match self.free.pop() {
+            Some(index) => {
+                self.entries[index] = Some(entry);
+                ShapeId(index)
+            }
+            None => {
+                self.entries.push(Some(entry));
+                ShapeId(self.entries.len() - 1)
+            }
+        }
+    }
+
+    /// Removes a shape, returning its entry if it was present.
+    pub fn remove(&mut self, id: ShapeId) -> Option<Entry<N, T>> {
+        let entry = self.entries.get_mut(id.0)?.take()?;
+        self.free.push(id.0);
+        self.len -= 1;
+        Some(entry)
+    }
+
+    /// Returns the entry for a shape.
+    pub fn get(&self, id: ShapeId) -> Option<&Entry<N, T>> {
+        self.entries.get(id.0)?.as_ref()
+    }
+
+    /// Returns the shape with the given id.
+    pub fn shape(&self, id: ShapeId) -> Option<&AnyShape<N>> {
+        self.get(id).map(|e| &e.shape)
+    }
+
+    /// Replaces a shape, such as after it has moved, keeping its filter and data.
+    /// Returns false if there is no shape with the id.
+    pub fn set_shape(&mut self, id: ShapeId, shape: impl Into<AnyShape<N>>) -> bool {
+        match self.entries.get_mut(id.0).and_then(Option::as_mut) {
+            Some(entry) => {
+                entry.shape = shape.into();
+                entry.bounds = entry.shape.bounding_rect();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the user data of a shape.
+    pub fn data(&self, id: ShapeId) -> Option<&T> {
+        self.get(id).map(|e| &e.data)
+    }
+
+    /// Returns the user data of a shape mutably.
+    pub fn data_mut(&mut self, id: ShapeId) -> Option<&mut T> {
+        self.entries.get_mut(id.0)?.as_mut().map(|e| &mut e.data)
+    }
+
+    /// Returns the filter of a shape.
+    pub fn filter(&self, id: ShapeId) -> Option<&CollisionFilter<T>> {
+        self.get(id).map(|e| &e.filter)
+    }
+
+    /// Replaces the filter of a shape. Returns false if there is no shape with the id.
+    pub fn set_filter(&mut self, id: ShapeId, filter: CollisionFilter<T>) -> bool {
+        match self.entries.get_mut(id.0).and_then(Option::as_mut) {
+            Some(entry) => {
+                entry.filter = filter;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The number of shapes in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the set holds no shapes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes every shape.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.free.clear();
+        self.len = 0;
+    }
+
+    /// Iterates over every shape and its entry, ordered by id.
+    pub fn iter(&self) -> impl Iterator<Item = (ShapeId, &Entry<N, T>)> + '_ {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| e.as_ref().map(|e| (ShapeId(i), e)))
+    }
+
+    /// Returns true if the filters of two shapes let them interact.
+    pub fn allowed(&self, a: ShapeId, b: ShapeId) -> bool {
+        match (self.get(a), self.get(b)) {
+            (Some(a), Some(b)) => a.filter.allows(&a.data, &b.filter, &b.data),
+            _ => false,
+        }
+    }
+
+    /// Every pair of shapes that are intersecting and allowed to interact, with the lower id
+    /// first, ordered by id.
+    pub fn pairs(&self) -> Vec<(ShapeId, ShapeId)> {
+        let mut order: Vec<(ShapeId, &Entry<N, T>)> = self.iter().collect();
+        order.sort_by(|(_, a), (_, b)| a.bounds.min.x.partial_cmp(&b.bounds.min.x).unwrap());
+
+        let mut pairs = Vec::new();
+        let mut active: Vec<(ShapeId, &Entry<N, T>)> = Vec::new();
+        for (id, entry) in order {
+            active.retain(|(_, other)| other.bounds.max.x >= entry.bounds.min.x);
+            for &(other_id, other) in &active {
+                if other.bounds.min.y <= entry.bounds.max.y
+                    && entry.bounds.min.y <= other.bounds.max.y
+                    && entry.filter.allows(&entry.data, &other.filter, &other.data)
+                    && entry.shape.intersecting(&other.shape)
+                {
+                    pairs.push((id.min(other_id), id.max(other_id)));
+                }
+            }
+            active.push((id, entry));
+        }
+        pairs.sort();
+        pairs
+    }
+
+    /// Every shape intersecting the given one whose filter lets it interact with the query's.
+    ///
+    /// A query carries no user data of its own, so only the group and mask bits are compared and
+    /// the shapes' predicates are not consulted. Filter the results by their data to narrow them.
+    pub fn query(&self, shape: &AnyShape<N>, filter: &CollisionFilter) -> Vec<ShapeId> {
+        let bounds = shape.bounding_rect();
+        let filter = CollisionFilter::<T>::new(filter.groups, filter.mask);
+        self.iter()
+            .filter(|(_, e)| {
+                touching(&e.bounds, &bounds)
+                    && filter.interacts(&e.filter)
+                    && shape.intersecting(&e.shape)
+            })
+            .map(|(id, _)| id)
+            .collect()
+    }
+}
+
+/// Returns true if two rectangles overlap or share an edge.
+pub(super) fn touching<N: PrimaFloat>(a: &Aabr<N>, b: &Aabr<N>) -> bool {
+    a.min.x <= b.max.x && b.min.x <= a.max.x && a.min.y <= b.max.y && b.min.y <= a.max.y
+}
@@ -0,0 +1,231 @@
+use assert_approx_eq::assert_approx_eq;
+use prima::{
+    batch::{AabrSoA, BatchQuery, BitVec, CircleSoA},
+    core::Point,
+    shapes::{Aabr, Circle},
+    traits::{Collide, Shape},
+};
+
+/// A deterministic spread of shapes across a 100 by 100 area, including some that only touch.
+fn scatter(count: usize) -> Vec<(Point<f32>, f32)> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 40) as f32 / (1u64 << 24) as f32
+    };
+    (0..count)
+        .map(|_| {
+            (
+                Point::new(next() * 100.0, next() * 100.0),
+                1.0 + next() * 10.0,
+            )
+        })
+        .collect()
+}
+
+fn aabrs(count: usize) -> Vec<Aabr<f32>> {
+    let mut shapes: Vec<Aabr<f32>> = scatter(count)
+        .into_iter()
+        .map(|(p, r)| Aabr::new(p, Point::new(p.x + r, p.y + r * 0.5)))
+        .collect();
+    shapes.push(Aabr::new(Point::new(60.0, 50.0), Point::new(70.0, 60.0)));
+    shapes
+}
+
+fn circles(count: usize) -> Vec<Circle<f32>> {
+    let mut shapes: Vec<Circle<f32>> = scatter(count)
+        .into_iter()
+        .map(|(p, r)| Circle::new(p, r))
+        .collect();
+    shapes.push(Circle::new(Point::new(65.0, 50.0), 5.0));
+    shapes
+}
+
+#[test]
+fn bitvec_test() {
+    let mut bits = BitVec::zeros(70);
+    assert_eq!(bits.len(), 70);
+    assert!(!bits.any());
+    bits.set(3, true);
+    bits.set(69, true);
+    assert_eq!(bits.get(3), Some(true));
+    assert_eq!(bits.get(4), Some(false));
+    assert_eq!(bits.get(70), None);
+    assert_eq!(bits.count_ones(), 2);
+    assert_eq!(bits.ones().collect::<Vec<_>>(), vec![3, 69]);
+
+    bits.push(true);
+    assert_eq!(bits.len(), 71);
+    assert_eq!(bits.ones().last(), Some(70));
+
+    let all: BitVec = std::iter::repeat_n(true, 65).collect();
+    assert!(all.all());
+    assert_eq!(BitVec::from_words(vec![u64::MAX, u64::MAX], 65), all);
+    assert_eq!(
+        format!("{:?}", BitVec::from_fn(4, |i| i % 2 == 1)),
+        "BitVec[0101]"
+    );
+}
+
+#[test]
+#[should_panic]
+fn bitvec_out_of_range_test() {
+    BitVec::zeros(8).set(8, true);
+}
+
+#[test]
+fn soa_test() {
+    let shapes = aabrs(10);
+    let mut soa = AabrSoA::from(shapes.as_slice());
+    assert_eq!(soa.len(), shapes.len());
+    assert_eq!(soa.iter().collect::<Vec<_>>(), shapes);
+    assert_eq!(soa.get(shapes.len()), None);
+
+    let replacement = Aabr::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0));
+    soa.set(2, replacement);
+    assert_eq!(soa.get(2), Some(replacement));
+    assert_eq!(soa.min_x()[2], 0.0);
+
+    let shapes = circles(10);
+    let soa: CircleSoA<f32> = shapes.iter().copied().collect();
+    assert_eq!(soa.iter().collect::<Vec<_>>(), shapes);
+    assert_eq!(soa.radius()[10], 5.0);
+}
+
+#[test]
+fn intersecting_many_test() {
+    // Sizes either side of the eight lane and 64 bit word boundaries.
+    for count in [0, 7, 8, 63, 64, 100] {
+        let boxes = aabrs(count);
+        let rounds = circles(count);
+        let box_soa = AabrSoA::from(boxes.as_slice());
+        let round_soa = CircleSoA::from(rounds.as_slice());
+
+        let circle = Circle::new(Point::new(50.0, 50.0), 10.0);
+        let aabr = Aabr::new(Point::new(40.0, 30.0), Point::new(60.0, 45.0));
+        let point = Point::new(65.0, 55.0);
+
+        let expected: Vec<bool> = boxes.iter().map(|b| circle.intersecting(b)).collect();
+        assert_eq!(
+            circle
+                .intersecting_many(&box_soa)
+                .iter()
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        let expected: Vec<bool> = boxes.iter().map(|b| aabr.intersecting(b)).collect();
+        assert_eq!(
+            aabr.intersecting_many(&box_soa).iter().collect::<Vec<_>>(),
+            expected
+        );
+
+        let expected: Vec<bool> = boxes.iter().map(|b| b.contains(&point)).collect();
+        assert_eq!(
+            point.intersecting_many(&box_soa).iter().collect::<Vec<_>>(),
+            expected
+        );
+
+        let expected: Vec<bool> = rounds.iter().map(|c| circle.intersecting(c)).collect();
+        assert_eq!(
+            circle
+                .intersecting_many(&round_soa)
+                .iter()
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        let expected: Vec<bool> = rounds.iter().map(|c| aabr.intersecting(c)).collect();
+        assert_eq!(
+            aabr.intersecting_many(&round_soa)
+                .iter()
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        let expected: Vec<bool> = rounds.iter().map(|c| c.contains(&point)).collect();
+        assert_eq!(
+            point
+                .intersecting_many(&round_soa)
+                .iter()
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn touching_test() {
+    let soa = AabrSoA::from(&[Aabr::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0))][..]);
+    let touching_circle = Circle::new(Point::new(2.0, 0.5), 1.0);
+    let touching_aabr = Aabr::new(Point::new(1.0, 0.0), Point::new(2.0, 1.0));
+    assert!(touching_circle.intersecting_many(&soa).all());
+    assert!(!touching_aabr.intersecting_many(&soa).any());
+    assert!(Point::new(1.0, 1.0).intersecting_many(&soa).all());
+
+    let soa = CircleSoA::from(&[Circle::new(Point::new(0.0, 0.0), 1.0)][..]);
+    assert!(Circle::new(Point::new(3.0, 0.0), 2.0)
+        .intersecting_many(&soa)
+        .all());
+}
+
+#[test]
+fn nearest_of_test() {
+    let boxes = AabrSoA::from(
+        &[
+            Aabr::new(Point::new(10.0_f32, 0.0), Point::new(12.0, 2.0)),
+            Aabr::new(Point::new(3.0, 4.0), Point::new(5.0, 6.0)),
+            Aabr::new(Point::new(-6.0, 0.0), Point::new(-5.0, 1.0)),
+        ][..],
+    );
+    let (index, gap) = Point::new(0.0, 0.0).nearest_of(&boxes).unwrap();
+    assert_eq!(index, 1);
+    assert_approx_eq!(gap, 5.0);
+    let (index, gap) = Circle::new(Point::new(0.0, 0.0), 1.0)
+        .nearest_of(&boxes)
+        .unwrap();
+    assert_eq!(index, 1);
+    assert_approx_eq!(gap, 4.0);
+    let (index, gap) = Aabr::new(Point::new(-4.0, 0.0), Point::new(0.0, 1.0))
+        .nearest_of(&boxes)
+        .unwrap();
+    assert_eq!(index, 2);
+    assert_approx_eq!(gap, 1.0);
+
+    let rounds = CircleSoA::from(
+        &[
+            Circle::new(Point::new(10.0_f32, 0.0), 1.0),
+            Circle::new(Point::new(0.0, 4.0), 3.0),
+            Circle::new(Point::new(0.0, -5.0), 4.0),
+        ][..],
+    );
+    // Ties go to the lowest index, and overlaps have no gap.
+    let (index, gap) = Point::new(0.0, 0.0).nearest_of(&rounds).unwrap();
+    assert_eq!(index, 1);
+    assert_approx_eq!(gap, 1.0);
+    let (index, gap) = Circle::new(Point::new(0.0, 0.0), 2.0)
+        .nearest_of(&rounds)
+        .unwrap();
+    assert_eq!(index, 1);
+    assert_approx_eq!(gap, 0.0);
+    let (index, gap) = Aabr::new(Point::new(7.0, -1.0), Point::new(8.0, 1.0))
+        .nearest_of(&rounds)
+        .unwrap();
+    assert_eq!(index, 0);
+    assert_approx_eq!(gap, 1.0);
+
+    assert_eq!(
+        Point::new(0.0, 0.0).nearest_of(&CircleSoA::<f32>::new()),
+        None
+    );
+}
+
+#[test]
+fn f64_test() {
+    let boxes = [Aabr::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0)); 20];
+    let soa = AabrSoA::from(&boxes[..]);
+    let hits = Circle::new(Point::new(0.5, 0.5), 0.1_f64).intersecting_many(&soa);
+    assert_eq!(hits.count_ones(), 20);
+}
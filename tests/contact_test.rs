@@ -0,0 +1,125 @@
+use prima::{
+    core::{Collision, ContactEvent, ContactPhase, ContactTracker, Point, Vector},
+    shapes::Circle,
+    traits::Collide,
+};
+
+fn phases(events: &[ContactEvent<u32, f32>]) -> Vec<(u32, u32, ContactPhase)> {
+    events.iter().map(|e| (e.a, e.b, e.phase)).collect()
+}
+
+#[test]
+fn enter_stay_exit_test() {
+    let mut tracker: ContactTracker<u32, f32> = ContactTracker::new();
+    let events = tracker.update([(1, 2), (3, 1)]);
+    assert_eq!(
+        phases(&events),
+        vec![(1, 2, ContactPhase::Enter), (1, 3, ContactPhase::Enter)]
+    );
+    assert_eq!(tracker.len(), 2);
+    assert!(tracker.touching(&3, &1));
+
+    let events = tracker.update([(2, 1), (2, 3)]);
+    assert_eq!(
+        phases(&events),
+        vec![
+            (1, 3, ContactPhase::Exit),
+            (1, 2, ContactPhase::Stay),
+            (2, 3, ContactPhase::Enter),
+        ]
+    );
+    let contact = tracker.contact(&1, &2).unwrap();
+    assert_eq!(contact.since, 0);
+    assert_eq!(contact.frames, 2);
+    assert_eq!(tracker.frame(), 2);
+
+    let events = tracker.update([]);
+    assert_eq!(
+        phases(&events),
+        vec![(1, 2, ContactPhase::Exit), (2, 3, ContactPhase::Exit)]
+    );
+    assert!(tracker.is_empty());
+    assert!(tracker.update(std::iter::empty()).is_empty());
+}
+
+#[test]
+fn duplicate_and_self_pairs_test() {
+    let mut tracker: ContactTracker<u32, f32> = ContactTracker::new();
+    tracker.report(4, 4, None);
+    tracker.report(1, 2, None);
+    tracker.report(2, 1, None);
+    let events = tracker.end_frame();
+    assert_eq!(phases(&events), vec![(1, 2, ContactPhase::Enter)]);
+}
+
+#[test]
+fn persistent_collision_test() {
+    let mut tracker: ContactTracker<&str, f32> = ContactTracker::new();
+    let hit = Collision::new(Point::new(1.0, 0.0), Vector::new(1.0, 0.0), 0.5);
+
+    // Reported the other way round, so the normal is turned to point from "a" to "b".
+    let events = tracker.update_collisions([("b", "a", hit)]);
+    assert_eq!(events[0].a, "a");
+    assert_eq!(events[0].collision.unwrap().normal, Vector::new(-1.0, 0.0));
+
+    // Staying without a new collision keeps the last one.
+    let events = tracker.update([("a", "b")]);
+    assert_eq!(events[0].phase, ContactPhase::Stay);
+    assert_eq!(events[0].collision.unwrap().depth, 0.5);
+
+    let deeper = Collision::new(Point::new(1.0, 0.0), Vector::new(-1.0, 0.0), 0.75);
+    tracker.update_collisions([("a", "b", deeper)]);
+    assert_eq!(tracker.contact(&"b", &"a").unwrap().collision, Some(deeper));
+
+    let events = tracker.update([]);
+    assert_eq!(events[0].phase, ContactPhase::Exit);
+    assert_eq!(events[0].collision, Some(deeper));
+}
+
+#[test]
+fn remove_test() {
+    let mut tracker: ContactTracker<u32, f32> = ContactTracker::new();
+    tracker.update([(1, 2), (2, 3), (3, 4)]);
+    tracker.report(2, 4, None);
+    let events = tracker.remove(&2);
+    assert_eq!(
+        phases(&events),
+        vec![(1, 2, ContactPhase::Exit), (2, 3, ContactPhase::Exit)]
+    );
+    assert_eq!(
+        phases(&tracker.end_frame()),
+        vec![(3, 4, ContactPhase::Exit)]
+    );
+
+    tracker.update([(5, 6)]);
+    tracker.clear();
+    assert!(tracker.is_empty());
+    assert!(tracker.end_frame().is_empty());
+}
+
+#[test]
+fn trigger_volume_test() {
+    // A trigger at the origin, with a circle passing through it.
+    let trigger = Circle::new(Point::new(0.0, 0.0), 1.0);
+    let mut tracker: ContactTracker<u32, f32> = ContactTracker::new();
+    let mut log = Vec::new();
+    for x in [-3.0, -1.5, 0.0, 1.5, 3.0] {
+        let mover = Circle::new(Point::new(x, 0.0), 1.0);
+        let pairs = trigger.collision(&mover).map(|c| (0, 1, c)).into_iter();
+        log.extend(
+            tracker
+                .update_collisions(pairs)
+                .into_iter()
+                .map(|e| e.phase),
+        );
+    }
+    assert_eq!(
+        log,
+        vec![
+            ContactPhase::Enter,
+            ContactPhase::Stay,
+            ContactPhase::Stay,
+            ContactPhase::Exit
+        ]
+    );
+}
@@ -1,5 +1,7 @@
 use prima::prelude::*;
+use prima::core::{normalize_radians, normalize_radians_signed, AngleMat};
 use assert_approx_eq::assert_approx_eq;
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 
 
 const IOTA: f32 = 0.001;
@@ -8,15 +10,15 @@ const IOTA: f32 = 0.001;
 fn vector_test() {
     let v = Vector::new(1.0f32, 1.0f32);
     let r = v.as_rotation();
-    assert_eq!(r.as_degrees(), 45.0f32);
+    assert_approx_eq!(r.as_degrees(), 45.0f32, IOTA);
 
 
-    let a1 = Angle::new(0.25f32);
-    let a2 = Angle::new(1.75f32);
+    let a1 = Angle::from_half_turns(0.25f32);
+    let a2 = Angle::from_half_turns(1.75f32);
     let r = a1.rotation_to(&a2);
-    assert_eq!(r.as_degrees(), -90.0f32);
+    assert_approx_eq!(r.as_degrees(), -90.0f32, IOTA);
 
-    let r = Rotation::new(-0.5f32);
+    let r = Rotation::from_half_turns(-0.5f32);
     let v = r.as_vector();
     assert_approx_eq!(v.x, -1.0f32, IOTA);
     assert_approx_eq!(v.y, 0.0f32, IOTA);
@@ -24,8 +26,262 @@ fn vector_test() {
     let v1 = Vector::new(10.0f32, 0.0f32);
     let v2 = Vector::new(0.0f32, 1.0f32);
     let r = v1.angle_of_difference(&v2);
-    assert_eq!(r.as_degrees(), 90.0f32);
+    assert_approx_eq!(r.as_degrees(), 90.0f32, IOTA);
 
     let a3 = a1.lerp(&a2, 0.5f32);
-    assert_eq!(a3.as_degrees(), 0.0f32);
-}
\ No newline at end of file
+    assert_approx_eq!(a3.as_degrees(), 0.0f32, IOTA);
+}
+
+#[test]
+fn angle_units_test() {
+    let r = Rotation::from_degrees(90.0f32);
+    assert_eq!(r.as_radians(), FRAC_PI_2);
+    assert_eq!(Rotation::from_radians(FRAC_PI_2), r);
+    assert_eq!(Rotation::from_turns(0.25f32), r);
+    assert_eq!(Rotation::from_half_turns(0.5f32), r);
+    assert_eq!(r.as_turns(), 0.25);
+    assert_eq!(r.as_half_turns(), 0.5);
+
+    // Rotations are unbound, while angles always wrap to [0..2π).
+    let spin = Rotation::from_degrees(-450.0f32);
+    assert_approx_eq!(spin.as_degrees(), -450.0, IOTA);
+    assert_approx_eq!(Angle::from(spin).as_degrees(), 270.0, IOTA);
+    assert_approx_eq!(Angle::from_degrees(720.0f32 + 45.0).as_degrees(), 45.0, IOTA);
+    assert_approx_eq!((Angle::from_degrees(350.0f32) + Angle::from_degrees(20.0)).as_degrees(), 10.0, IOTA);
+    assert_approx_eq!((-Angle::from_degrees(90.0f32)).as_degrees(), 270.0, IOTA);
+}
+
+#[test]
+fn normalization_test() {
+    let cases: [(f32, f32, f32); 8] = [
+        (0.0, 0.0, 0.0),
+        (FRAC_PI_2, FRAC_PI_2, FRAC_PI_2),
+        (PI, PI, PI),
+        (-PI, PI, PI),
+        (3.0 * FRAC_PI_2, 3.0 * FRAC_PI_2, -FRAC_PI_2),
+        (-FRAC_PI_2, 3.0 * FRAC_PI_2, -FRAC_PI_2),
+        (5.0 * PI, PI, PI),
+        (-7.0 * FRAC_PI_4, FRAC_PI_4, FRAC_PI_4),
+    ];
+    for (input, unsigned, signed) in cases {
+        assert_approx_eq!(normalize_radians(input), unsigned, IOTA);
+        assert_approx_eq!(normalize_radians_signed(input), signed, IOTA);
+        let r = Rotation::from_radians(input);
+        assert_approx_eq!(r.normalized().as_radians(), unsigned, IOTA);
+        assert_approx_eq!(r.normalized_signed().as_radians(), signed, IOTA);
+        assert_approx_eq!(r.as_unsigned_radians(), unsigned, IOTA);
+        assert_approx_eq!(r.as_signed_radians(), signed, IOTA);
+    }
+    assert!(normalize_radians(-1e-9f32) < 2.0 * PI);
+}
+
+#[test]
+fn quadrant_test() {
+    // Every direction at 15 degree steps, covering each axis and all four quadrants.
+    for step in 0..24 {
+        let degrees = step as f32 * 15.0;
+        let (s, c) = degrees.to_radians().sin_cos();
+        // Angles run clockwise from up, so x follows the sine and y the co-sine.
+        let expected = Vector::new(s, c);
+
+        let angle = Angle::from_degrees(degrees);
+        let v = angle.as_vector();
+        assert_approx_eq!(v.x, expected.x, IOTA);
+        assert_approx_eq!(v.y, expected.y, IOTA);
+
+        for scale in [0.5f32, 1.0, 20.0] {
+            let back = (expected * scale).as_angle();
+            assert_approx_eq!(back.as_degrees(), degrees, IOTA);
+            assert_approx_eq!(Rotation::from_vector(expected * scale).as_unsigned_radians(), degrees.to_radians(), IOTA);
+        }
+
+        // Rotating up by the angle should point along it, with or without a matrix.
+        let rotated = Vector::up() * Rotation::from(angle);
+        assert_approx_eq!(rotated.x, expected.x, IOTA);
+        assert_approx_eq!(rotated.y, expected.y, IOTA);
+        let m: AngleMat<f32> = angle.into();
+        let back = Angle::from(m);
+        assert_approx_eq!(back.as_signed_radians(), angle.as_signed_radians(), IOTA);
+        let negative: AngleMat<f32> = Rotation::from_degrees(degrees - 360.0).into();
+        assert_approx_eq!(negative.m00, m.m00, IOTA);
+        assert_approx_eq!(negative.m10, m.m10, IOTA);
+        assert_approx_eq!(negative.m01, m.m01, IOTA);
+        assert_approx_eq!(negative.m11, m.m11, IOTA);
+    }
+
+    // Axis aligned matrices are exact, in both directions.
+    for degrees in [-270.0f32, -180.0, -90.0, 0.0, 90.0, 180.0, 270.0, 360.0] {
+        let r = Rotation::from_degrees(degrees);
+        assert!(r.is_axis_aligned());
+        let v = Vector::new(2.0f32, 3.0) * r;
+        let (s, c) = degrees.to_radians().sin_cos();
+        assert_eq!(v, Vector::new((2.0 * c + 3.0 * s).round(), (3.0 * c - 2.0 * s).round()));
+    }
+    assert!(!Rotation::from_degrees(45.0f32).is_axis_aligned());
+    assert_eq!(Vector::<f32>::zero().as_rotation(), Rotation::zero());
+}
+
+#[test]
+fn shortest_arc_test() {
+    let cases: [(f32, f32, f32); 6] = [
+        (10.0, 350.0, -20.0),
+        (350.0, 10.0, 20.0),
+        (90.0, 270.0, 180.0),
+        (0.0, 90.0, 90.0),
+        (270.0, 0.0, 90.0),
+        (180.0, 90.0, -90.0),
+    ];
+    for (from, to, delta) in cases {
+        let (a, b) = (Angle::from_degrees(from), Angle::from_degrees(to));
+        assert_approx_eq!(a.rotation_to(&b).as_degrees(), delta, IOTA);
+        // A half turn is the same arc in both directions, so compare modulo a full turn.
+        let back = a.rotation_from(&b).as_radians() + delta.to_radians();
+        assert_approx_eq!(normalize_radians_signed(back), 0.0, IOTA);
+        let halfway = a.lerp(&b, 0.5);
+        let expected = Angle::from_degrees(from + delta / 2.0);
+        assert_approx_eq!(halfway.rotation_to(&expected).as_degrees(), 0.0, IOTA);
+    }
+
+    // Rotations interpolate linearly rather than along the shortest arc.
+    let spin = Rotation::zero().lerp(&Rotation::from_turns(2.0f32), 0.5);
+    assert_approx_eq!(spin.as_turns(), 1.0, IOTA);
+    let short = Rotation::zero().lerp_shortest(&Rotation::from_degrees(270.0f32), 0.5);
+    assert_approx_eq!(short.as_degrees(), -45.0, IOTA);
+}
+
+#[test]
+fn vector_math_test() {
+    let v = Vector::new(3.0f32, 4.0);
+    assert_eq!(v.try_normalize(), Some(Vector::new(0.6, 0.8)));
+    assert_eq!(Vector::<f32>::zero().try_normalize(), None);
+    assert_eq!(v.distance_to(&Vector::new(0.0, 0.0)), 5.0);
+
+    // Projection and rejection split a vector into parallel and perpendicular parts.
+    let onto = Vector::new(2.0f32, 0.0);
+    assert_eq!(v.project_onto(&onto), Vector::new(3.0, 0.0));
+    assert_eq!(v.reject_from(&onto), Vector::new(0.0, 4.0));
+    assert_eq!(v.project_onto(&Vector::zero()), Vector::zero());
+
+    let down_right = Vector::new(1.0f32, -1.0);
+    assert_eq!(down_right.reflect(&Vector::up()), Vector::new(1.0, 1.0));
+    let incoming = down_right.normalize();
+    let through = incoming.refract(&Vector::up(), 1.0).unwrap();
+    assert_approx_eq!(through.x, incoming.x, IOTA);
+    assert_approx_eq!(through.y, incoming.y, IOTA);
+    let bent = incoming.refract(&Vector::up(), 0.5).unwrap();
+    assert_approx_eq!(bent.magnitude(), 1.0, IOTA);
+    assert!(bent.x < incoming.x && bent.y < 0.0);
+    assert_eq!(incoming.refract(&Vector::up(), 2.0), None);
+
+    assert_eq!(v.clamp_length(0.0, 2.5), Vector::new(1.5, 2.0));
+    assert_eq!(v.clamp_length(10.0, 20.0), Vector::new(6.0, 8.0));
+    assert_eq!(v.clamp_length(1.0, 6.0), v);
+
+    let w = Vector::new(-1.5f32, 7.25);
+    assert_eq!(w.abs(), Vector::new(1.5, 7.25));
+    assert_eq!(w.floor(), Vector::new(-2.0, 7.0));
+    assert_eq!(v.min(&w), Vector::new(-1.5, 4.0));
+    assert_eq!(v.max(&w), Vector::new(3.0, 7.25));
+    assert_eq!(Vector::new(1, 9).max(&Vector::new(4, 2)), Vector::new(4, 9));
+    assert_eq!(2.0 * v, v * 2.0);
+    assert_eq!(0.5f64 * Vector::new(2.0f64, 4.0), Vector::new(1.0, 2.0));
+}
+
+#[test]
+fn vector_interpolation_test() {
+    // Lerp moves in a straight line towards other.
+    let a = Vector::new(0.0f32, 2.0);
+    let b = Vector::new(4.0f32, 0.0);
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert_eq!(a.lerp(b, 0.5), Vector::new(2.0, 1.0));
+
+    // Angles between are signed, clockwise positive.
+    assert_approx_eq!(Vector::<f32>::up().angle_between(&Vector::right()).as_degrees(), 90.0f32, IOTA);
+    assert_approx_eq!(Vector::<f32>::up().angle_between(&Vector::left()).as_degrees(), -90.0f32, IOTA);
+    assert_approx_eq!(Vector::right().angle_between(&Vector::new(1.0f32, 1.0)).as_degrees(), -45.0, IOTA);
+    assert_approx_eq!(Vector::<f32>::up().angle_between(&Vector::down()).as_degrees().abs(), 180.0, IOTA);
+
+    // Slerp turns the direction and lerps the length.
+    let halfway = a.slerp(b, 0.5);
+    assert_approx_eq!(halfway.magnitude(), 3.0, IOTA);
+    assert_approx_eq!(halfway.as_angle().as_degrees(), 45.0, IOTA);
+    let end = a.slerp(b, 1.0);
+    assert_approx_eq!(end.x, b.x, IOTA);
+    assert_approx_eq!(end.y, b.y, IOTA);
+    assert_eq!(Vector::zero().slerp(b, 0.5), Vector::new(2.0, 0.0));
+}
+
+#[test]
+fn operator_test() {
+    // Affine arithmetic: points move by vectors and their difference is a vector.
+    let p = Point::new(5.0f32, 3.0);
+    let v = Vector::new(1.0f32, 2.0);
+    assert_eq!(p + v, Point::new(6.0, 5.0));
+    assert_eq!(p - v, Point::new(4.0, 1.0));
+    assert_eq!((p + v) - p, v);
+    let mut q = p;
+    q += v;
+    q -= v * 2.0;
+    assert_eq!(q, p - v);
+
+    // Scalar and component-wise arithmetic works for integers as well as floats.
+    let mut i = Vector::new(7, -4);
+    assert_eq!(i * 2, Vector::new(14, -8));
+    assert_eq!(3 * i, Vector::new(21, -12));
+    assert_eq!(i / 2, Vector::new(3, -2));
+    assert_eq!(i % 3, Vector::new(1, -1));
+    assert_eq!(i * Vector::new(2, 3), Vector::new(14, -12));
+    assert_eq!(i / Vector::new(7, 2), Vector::new(1, -2));
+    assert_eq!(Point::new(9, 10) % Point::new(4, 3), Point::new(1, 1));
+    i *= 2;
+    i /= Vector::new(2, 4);
+    i %= 5;
+    assert_eq!(i, Vector::new(2, -2));
+    let mut e = Extent::new(4.0f32, 6.0);
+    e *= Extent::new(0.5, 2.0);
+    e /= 2.0;
+    assert_eq!(e, Extent::new(1.0, 6.0));
+    assert_eq!(e + Extent::new(1.0, 1.0) - Extent::new(0.5, 0.5), Extent::new(1.5, 6.5));
+
+    let vectors = [Vector::new(1, 2), Vector::new(3, 4), Vector::new(-1, 0)];
+    assert_eq!(vectors.iter().sum::<Vector<i32>>(), Vector::new(3, 6));
+    assert_eq!(vectors.into_iter().sum::<Vector<i32>>(), Vector::new(3, 6));
+    assert_eq!(
+        [Extent::new(1, 2), Extent::new(3, 4)].into_iter().sum::<Extent<i32>>(),
+        Extent::new(4, 6)
+    );
+
+    let mut p = Point::new(1, 2);
+    assert_eq!((p[0], p[1]), (1, 2));
+    p[1] = 5;
+    assert_eq!(p.y, 5);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_range_test() {
+    let _ = Vector::new(1, 2)[2];
+}
+
+#[test]
+fn conversion_test() {
+    use std::collections::HashSet;
+
+    assert_eq!(Point::from([1, 2]), Point::new(1, 2));
+    assert_eq!(Vector::from((3.0f32, 4.0)), Vector::new(3.0, 4.0));
+    let array: [i32; 2] = Extent::new(5, 6).into();
+    assert_eq!(array, [5, 6]);
+    let tuple: (i32, i32) = Point::new(7, 8).into();
+    assert_eq!(tuple, (7, 8));
+
+    assert_eq!(Point::<i32>::default(), Point::zero());
+    assert_eq!(Extent::<f32>::default(), Extent::zero());
+    let set: HashSet<Point<i32>> = [Point::new(1, 1), Point::new(1, 1), Point::new(2, 1)].into_iter().collect();
+    assert_eq!(set.len(), 2);
+
+    assert_eq!(Point::new(3, -4).cast::<f32>(), Some(Point::new(3.0f32, -4.0)));
+    assert_eq!(Vector::new(2.9f64, -1.5).cast::<i32>(), Some(Vector::new(2, -1)));
+    assert_eq!(Vector::new(-1i32, 0).cast::<u8>(), None);
+    assert_eq!(Extent::new(f32::NAN, 1.0).cast::<i64>(), None);
+}
@@ -0,0 +1,190 @@
+use prima::{
+    core::CollisionFilter,
+    prelude::*,
+    world::{ShapeId, ShapeSet},
+};
+
+const PLAYER: u32 = 1;
+const ENEMY: u32 = 1 << 1;
+const BULLET: u32 = 1 << 2;
+
+#[test]
+fn filter_bits_test() {
+    let player: CollisionFilter = CollisionFilter::new(PLAYER, ENEMY | BULLET);
+    let enemy = CollisionFilter::new(ENEMY, PLAYER | ENEMY);
+    let bullet = CollisionFilter::new(BULLET, PLAYER);
+
+    assert!(player.interacts(&enemy));
+    assert!(enemy.interacts(&enemy));
+    assert!(player.interacts(&bullet));
+    // Interaction needs both sides to mask the other, and enemies do not mask bullets.
+    assert!(!enemy.interacts(&bullet));
+    assert!(!bullet.interacts(&bullet));
+    assert!(CollisionFilter::<()>::ALL.interacts(&player));
+    assert!(!CollisionFilter::<()>::NONE.interacts(&CollisionFilter::ALL));
+}
+
+/// Data is the id of the owner, and shapes ignore anything with the same owner as them.
+fn different_owners(own: &u32, other: &u32) -> bool {
+    own != other
+}
+
+#[test]
+fn filter_predicate_test() {
+    // One filter is shared by the shapes of every owner.
+    let filter = CollisionFilter::ALL.with_predicate(different_owners);
+    let plain = CollisionFilter::ALL;
+
+    assert!(filter.allows(&7, &filter, &3));
+    assert!(filter.allows(&3, &filter, &7));
+    assert!(!filter.allows(&7, &filter, &7));
+    assert!(!filter.allows(&3, &filter, &3));
+    // Either side's predicate can refuse the pair.
+    assert!(!filter.allows(&7, &plain, &7));
+    assert!(!plain.allows(&7, &filter, &7));
+    assert!(plain.allows(&7, &plain, &7));
+    assert!(filter.accepts(&7, &1));
+    assert!(!filter.accepts(&1, &1));
+}
+
+#[test]
+fn shape_set_pairs_test() {
+    let mut set: ShapeSet<f32, u32> = ShapeSet::new();
+    let player = set.insert_filtered(
+        Circle::new(Point::new(0.0, 0.0), 1.0),
+        CollisionFilter::new(PLAYER, ENEMY | BULLET),
+        0,
+    );
+    let enemy = set.insert_filtered(
+        Aabr::new(Point::new(0.5, -1.0), Point::new(2.5, 1.0)),
+        CollisionFilter::new(ENEMY, PLAYER | ENEMY),
+        1,
+    );
+    let bullets = CollisionFilter::new(BULLET, PLAYER | ENEMY).with_predicate(different_owners);
+    let bullet = set.insert_filtered(
+        Capsule::new(Line::new(Point::new(1.0, 0.0), Point::new(2.0, 0.0)), 0.1),
+        bullets,
+        0,
+    );
+    let far = set.insert(Circle::new(Point::new(100.0, 0.0), 1.0), 2);
+
+    // The bullet overlaps everything nearby, but enemies ignore bullets and it ignores its owner.
+    assert_eq!(set.pairs(), vec![(player, enemy)]);
+    assert!(!set.allowed(player, bullet));
+    assert!(!set.allowed(enemy, bullet));
+
+    set.set_filter(enemy, CollisionFilter::new(ENEMY, PLAYER | BULLET));
+    assert_eq!(set.pairs(), vec![(player, enemy), (enemy, bullet)]);
+
+    // A bullet fired by the enemy shares the same filter, but is free to hit the player.
+    let enemy_bullet = set.insert_filtered(
+        Capsule::new(Line::new(Point::new(-0.5, 0.0), Point::new(0.5, 0.0)), 0.1),
+        bullets,
+        1,
+    );
+    assert!(set.allowed(player, enemy_bullet));
+    assert!(!set.allowed(enemy, enemy_bullet));
+
+    set.set_shape(far, Circle::new(Point::new(2.0, 2.0), 1.0));
+    assert_eq!(
+        set.pairs(),
+        vec![
+            (player, enemy),
+            (player, enemy_bullet),
+            (enemy, bullet),
+            (enemy, far)
+        ]
+    );
+}
+
+#[test]
+fn shape_set_query_test() {
+    let mut set: ShapeSet<f32> = ShapeSet::new();
+    let a = set.insert_filtered(
+        Circle::new(Point::new(0.0, 0.0), 1.0),
+        CollisionFilter::new(PLAYER, u32::MAX),
+        (),
+    );
+    let b = set.insert_filtered(
+        Obr::new(
+            Point::new(1.5, 0.0),
+            Extent::new(1.0, 4.0),
+            Angle::from_radians(0.3),
+        ),
+        CollisionFilter::new(ENEMY, u32::MAX),
+        (),
+    );
+    let probe: AnyShape<f32> = Aabr::new(Point::new(0.5, -0.5), Point::new(1.5, 0.5)).into();
+
+    assert_eq!(set.query(&probe, &CollisionFilter::ALL), vec![a, b]);
+    assert_eq!(
+        set.query(&probe, &CollisionFilter::new(u32::MAX, ENEMY)),
+        vec![b]
+    );
+    assert!(set.query(&probe, &CollisionFilter::NONE).is_empty());
+}
+
+#[test]
+fn shape_set_reuse_test() {
+    let mut set: ShapeSet<f64, &str> = ShapeSet::new();
+    let a = set.insert(Circle::new(Point::new(0.0, 0.0), 1.0), "a");
+    let b = set.insert(Circle::new(Point::new(1.0, 0.0), 1.0), "b");
+    assert_eq!(set.len(), 2);
+
+    assert_eq!(set.remove(a).map(|e| e.data), Some("a"));
+    assert!(set.remove(a).is_none());
+    assert!(set.pairs().is_empty());
+
+    let c = set.insert(Circle::new(Point::new(-1.0, 0.0), 1.0), "c");
+    assert_eq!(c, ShapeId(0));
+    assert_eq!(set.data(c), Some(&"c"));
+    assert_eq!(set.pairs(), vec![(c, b)]);
+    assert_eq!(set.iter().count(), 2);
+}
+
+#[test]
+fn any_shape_test() {
+    let circle: AnyShape<f64> = Circle::new(Point::new(0.0, 0.0), 1.0).into();
+    let aabr: AnyShape<f64> = Aabr::new(Point::new(1.5, -1.0), Point::new(3.5, 1.0)).into();
+    let polygon: AnyShape<f64> = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 4.0),
+        Point::new(0.0, 4.0),
+    ])
+    .into();
+
+    assert!(!circle.intersecting(&aabr));
+    assert!((circle.distance(&aabr) - 0.5).abs() < 1e-9);
+
+    let collision = circle.collision(&polygon).unwrap();
+    assert!(circle.intersecting(&polygon));
+    assert!(collision.depth > 0.0);
+
+    // A small rectangle deep inside the polygon is pushed out through the nearest edge.
+    let inner: AnyShape<f64> = Aabr::new(Point::new(3.0, 1.0), Point::new(3.5, 3.0)).into();
+    let collision = polygon.collision(&inner).unwrap();
+    assert_eq!(collision.normal, Vector::new(1.0, 0.0));
+    assert!((collision.depth - 1.0).abs() < 1e-9);
+    assert!(polygon.enveloping(&inner));
+    assert!(inner.enveloped_by(&polygon));
+    assert!(!polygon.enveloping(&circle));
+
+    // An empty polygon covers nothing, so never touches anything.
+    let empty: AnyShape<f64> = Polygon::new(vec![]).into();
+    for shape in [&circle, &aabr, &polygon, &empty] {
+        assert!(empty.collision(shape).is_none());
+        assert!(shape.collision(&empty).is_none());
+        assert!(!empty.intersecting(shape));
+        assert!(!shape.intersecting(&empty));
+        assert_eq!(empty.distance(shape), f64::INFINITY);
+        assert!(empty.cast(&Vector::new(10.0, 0.0), shape).is_none());
+    }
+
+    let mut set: ShapeSet<f64> = ShapeSet::new();
+    let a = set.insert(circle.clone(), ());
+    set.insert(empty.clone(), ());
+    assert!(set.pairs().is_empty());
+    assert_eq!(set.query(&circle, &CollisionFilter::ALL), vec![a]);
+    assert!(set.query(&empty, &CollisionFilter::ALL).is_empty());
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "gis")]
+
+use prima::{
+    core::*,
+    formats::{read_features, write_features, Feature, GeoError, Geometry, Region},
+    shapes::*,
+};
+
+fn square(min: f64, max: f64) -> Polygon<f64> {
+    Polygon::new(vec![
+        Point::new(min, min),
+        Point::new(max, min),
+        Point::new(max, max),
+        Point::new(min, max),
+    ])
+}
+
+#[test]
+fn wkt_write_test() {
+    assert_eq!(Geometry::from(Point::new(1.5f64, -2.0)).to_wkt(), "POINT (1.5 -2)");
+    assert_eq!(
+        Geometry::from(Line::new(Point::new(0.0f32, 0.0), Point::new(0.1, 2.0))).to_wkt(),
+        "LINESTRING (0 0, 0.1 2)"
+    );
+    // Holes are wound clockwise and every ring is closed.
+    let region = Region::with_holes(square(0.0, 4.0), vec![square(1.0, 2.0)]);
+    assert_eq!(
+        Geometry::from(region).to_wkt(),
+        "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 2, 2 2, 2 1, 1 1, 1 2))"
+    );
+    assert_eq!(
+        Geometry::from(vec![Region::new(square(0.0, 1.0))]).to_wkt(),
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 1, 0 0)))"
+    );
+    assert_eq!(Geometry::<f64>::MultiPolygon(Vec::new()).to_wkt(), "MULTIPOLYGON EMPTY");
+}
+
+#[test]
+fn wkt_read_test() {
+    let geometries = [
+        Geometry::from(Point::new(3.0f64, 4.0)),
+        Geometry::from(Polyline::new(vec![Point::new(0.0, 0.0), Point::new(1e-3, 2.5)])),
+        Geometry::from(Region::with_holes(square(0.0, 4.0), vec![square(1.0, 2.0)])),
+        Geometry::from(vec![Region::new(square(0.0, 1.0)), Region::new(square(2.0, 3.0))]),
+    ];
+    for geometry in geometries.iter() {
+        let read: Geometry<f64> = Geometry::from_wkt(&geometry.to_wkt()).unwrap();
+        assert_eq!(read.to_wkt(), geometry.to_wkt());
+    }
+
+    let read: Geometry<f64> = Geometry::from_wkt("  linestring(0 0,1 1 )").unwrap();
+    assert_eq!(read, Geometry::from(Polyline::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)])));
+    assert_eq!(
+        Geometry::<f64>::from_wkt("LINESTRING EMPTY").unwrap(),
+        Geometry::LineString(Polyline::new(Vec::new()))
+    );
+}
+
+#[test]
+fn wkt_error_test() {
+    let error = |wkt: &str| match Geometry::<f64>::from_wkt(wkt) {
+        Err(GeoError::Wkt { message, column }) => (message, column),
+        other => panic!("unexpected result {:?}", other),
+    };
+    assert_eq!(error("POINT (1 x)"), ("expected a number".to_string(), 10));
+    assert_eq!(error("POINT Z (1 2 3)"), ("unsupported dimension `Z`".to_string(), 7));
+    assert_eq!(error("POINT (1 2 3)"), ("only two dimensional coordinates are supported".to_string(), 12));
+    assert_eq!(error("CIRCLE (1 2)"), ("unsupported geometry type `CIRCLE`".to_string(), 1));
+    assert_eq!(error("POINT (1 2"), ("expected `)`".to_string(), 11));
+    assert_eq!(error("POINT (1 2) x"), ("unexpected text after geometry".to_string(), 13));
+}
+
+#[test]
+fn geojson_test() {
+    let region = Region::with_holes(square(0.0, 4.0), vec![square(1.0, 2.0)]);
+    let geometry = Geometry::from(region.clone());
+    let json = geometry.to_geojson();
+    assert_eq!(json["type"], "Polygon");
+    assert_eq!(json["coordinates"][0][4], serde_json::json!([0.0, 0.0]));
+    assert_eq!(Geometry::<f64>::from_geojson(&json.to_string()).unwrap().to_wkt(), geometry.to_wkt());
+
+    // f32 values are written as they display, not widened.
+    let point = Geometry::from(Point::new(0.1f32, 2.0)).to_geojson();
+    assert_eq!(point.to_string(), r#"{"coordinates":[0.1,2.0],"type":"Point"}"#);
+
+    let features = vec![
+        Feature::new(region).with_property("name", "plaza"),
+        Feature::new(Point::new(1.0, 1.0)).with_property("height", 3),
+    ];
+    let read: Vec<Feature<f64>> = read_features(&write_features(&features)).unwrap();
+    assert_eq!(read.len(), 2);
+    assert_eq!(read[0].properties["name"], "plaza");
+    assert_eq!(read[1].geometry, Geometry::Point(Point::new(1.0, 1.0)));
+
+    // Bare geometries and third coordinates are accepted.
+    let bare: Vec<Feature<f64>> = read_features(r#"{"type":"Point","coordinates":[1,2,3]}"#).unwrap();
+    assert_eq!(bare[0].geometry, Geometry::Point(Point::new(1.0, 2.0)));
+
+    assert!(matches!(
+        Geometry::<f64>::from_geojson(r#"{"type":"GeometryCollection","geometries":[]}"#),
+        Err(GeoError::Invalid(_))
+    ));
+    assert!(matches!(Geometry::<f64>::from_geojson("[1,"), Err(GeoError::Json(_))));
+}
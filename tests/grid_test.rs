@@ -0,0 +1,229 @@
+use assert_approx_eq::assert_approx_eq;
+use prima::{
+    core::{Angle, Extent, Line, Point, Polyline, Ray, Vector},
+    grid::{bresenham, flood_fill, Cell, Connectivity, Grid, RasterMode, ScalarField},
+    shapes::{Aabr, Circle, Obr, Polygon},
+    traits::{Distance, Shape},
+};
+
+fn cells(list: &[(i32, i32)]) -> Vec<Cell> {
+    list.iter().map(|&(x, y)| Cell::new(x, y)).collect()
+}
+
+#[test]
+fn cell_iteration_test() {
+    let range = Aabr::new(Cell::new(-1, 0), Cell::new(1, 2));
+    let all: Vec<Cell> = range.cells().collect();
+    assert_eq!(all, cells(&[(-1, 0), (0, 0), (-1, 1), (0, 1)]));
+    assert_eq!(range.cells().len(), 4);
+    assert_eq!(Aabr::new(Cell::new(0, 0), Cell::new(0, 5)).cells().count(), 0);
+
+    let grid = Grid::new(Point::new(10.0f32, 0.0), Extent::new(2.0, 4.0));
+    assert_eq!(grid.cell_at(&Point::new(9.0, 3.9)), Cell::new(-1, 0));
+    assert_eq!(grid.cell_at(&Point::new(12.0, 4.0)), Cell::new(1, 1));
+    assert_eq!(grid.cell_center(Cell::new(1, 1)), Point::new(13.0, 6.0));
+    assert_eq!(grid.cell_rect(Cell::new(0, -1)), Aabr::new(Point::new(10.0, -4.0), Point::new(12.0, 0.0)));
+    let area = Aabr::new(Point::new(10.5, 0.0), Point::new(14.0, 4.5));
+    assert_eq!(grid.cells_overlapping(&area), Aabr::new(Cell::new(0, 0), Cell::new(2, 2)));
+}
+
+#[test]
+fn neighbour_test() {
+    let four: Vec<Cell> = Connectivity::Four.neighbours(Cell::new(2, 2)).collect();
+    assert_eq!(four, cells(&[(2, 3), (3, 2), (2, 1), (1, 2)]));
+    let eight: Vec<Cell> = Connectivity::Eight.neighbours(Cell::zero()).collect();
+    assert_eq!(eight.len(), 8);
+    assert!(eight.contains(&Cell::new(-1, -1)) && !eight.contains(&Cell::zero()));
+}
+
+#[test]
+fn flood_fill_test() {
+    // A wall along x = 2 with a diagonal gap that only eight-way movement can slip through.
+    let wall = |c: Cell| c.x == 2 && c.y != 3 || c == Cell::new(3, 3);
+    let bounds = Aabr::new(Cell::new(0, 0), Cell::new(5, 5));
+    let four = flood_fill(Cell::zero(), &bounds, Connectivity::Four, |c| !wall(c));
+    assert_eq!(four.len(), 11);
+    assert_eq!(four[0], Cell::zero());
+    assert!(four.iter().all(|c| c.x < 2 || *c == Cell::new(2, 3)));
+    let eight = flood_fill(Cell::zero(), &bounds, Connectivity::Eight, |c| !wall(c));
+    assert_eq!(eight.len(), 25 - 5);
+    assert!(flood_fill(Cell::new(2, 0), &bounds, Connectivity::Four, |c| !wall(c)).is_empty());
+    assert!(flood_fill(Cell::new(9, 0), &bounds, Connectivity::Four, |_| true).is_empty());
+}
+
+#[test]
+fn traversal_test() {
+    let grid = Grid::unit();
+    let line = Line::new(Point::new(0.5f32, 0.5), Point::new(3.5, 1.5));
+    // The line crosses the corner at (2, 1) exactly, stepping along x first.
+    let visited: Vec<Cell> = grid.traverse_line(&line).collect();
+    assert_eq!(visited, cells(&[(0, 0), (1, 0), (2, 0), (2, 1), (3, 1)]));
+
+    let backwards: Vec<Cell> = grid.traverse_line(&Line::new(line.end, line.start)).collect();
+    assert_eq!(backwards, cells(&[(3, 1), (2, 1), (1, 1), (1, 0), (0, 0)]));
+
+    let shallow = Line::new(Point::new(0.5f32, 0.2), Point::new(2.5, 1.1));
+    let visited: Vec<Cell> = grid.traverse_line(&shallow).collect();
+    assert_eq!(visited, cells(&[(0, 0), (1, 0), (2, 0), (2, 1)]));
+
+    let vertical: Vec<Cell> = grid
+        .traverse_line(&Line::new(Point::new(0.5, -0.5), Point::new(0.5, 2.0)))
+        .collect();
+    assert_eq!(vertical, cells(&[(0, -1), (0, 0), (0, 1)]));
+
+    let still: Vec<Cell> = grid.traverse_line(&Line::new(Point::new(0.5, 0.5), Point::new(0.5, 0.5))).collect();
+    assert_eq!(still, cells(&[(0, 0)]));
+
+    // Rays stop after travelling the given world distance, on a grid of larger cells.
+    let big = Grid::new(Point::zero(), Extent::new(2.0f64, 2.0));
+    let ray = Ray::new(Point::new(1.0, 1.0), Vector::new(-10.0, 0.0));
+    let visited: Vec<Cell> = big.traverse_ray(&ray, 4.5).collect();
+    assert_eq!(visited, cells(&[(0, 0), (-1, 0), (-2, 0)]));
+}
+
+#[test]
+fn bresenham_test() {
+    let line: Vec<Cell> = bresenham(Cell::new(0, 0), Cell::new(4, 2)).collect();
+    assert_eq!(line, cells(&[(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]));
+    let steep: Vec<Cell> = bresenham(Cell::new(1, 3), Cell::new(0, -1)).collect();
+    assert_eq!(steep.len(), 5);
+    assert_eq!(steep.first(), Some(&Cell::new(1, 3)));
+    assert_eq!(steep.last(), Some(&Cell::new(0, -1)));
+    assert_eq!(bresenham(Cell::new(2, 2), Cell::new(2, 2)).count(), 1);
+}
+
+#[test]
+fn rasterize_test() {
+    let grid = Grid::unit();
+
+    // An axis-aligned box on cell boundaries covers the same cells in both modes.
+    let aabr = Aabr::new(Point::new(0.0f32, 0.0), Point::new(2.0, 2.0));
+    let block = cells(&[(0, 0), (1, 0), (0, 1), (1, 1)]);
+    assert_eq!(grid.rasterize(&aabr, RasterMode::Center), block);
+    assert_eq!(grid.rasterize(&aabr, RasterMode::Conservative), block);
+
+    // A small box off the cell centers is only found conservatively.
+    let small = Aabr::new(Point::new(0.1f32, 0.1), Point::new(0.4, 1.2));
+    assert!(grid.rasterize(&small, RasterMode::Center).is_empty());
+    assert_eq!(grid.rasterize(&small, RasterMode::Conservative), cells(&[(0, 0), (0, 1)]));
+
+    let circle = Circle::new(Point::new(0.0f32, 0.0), 1.0);
+    assert_eq!(grid.rasterize(&circle, RasterMode::Center), cells(&[(-1, -1), (0, -1), (-1, 0), (0, 0)]));
+    let conservative = grid.rasterize(&circle, RasterMode::Conservative);
+    assert_eq!(conservative, cells(&[(-1, -1), (0, -1), (-1, 0), (0, 0)]));
+    let wide = grid.rasterize(&Circle::new(Point::new(0.5f32, 0.5), 1.2), RasterMode::Conservative);
+    assert_eq!(wide.len(), 9);
+    assert_eq!(grid.rasterize(&Circle::new(Point::new(0.5f32, 0.5), 1.2), RasterMode::Center).len(), 5);
+
+    // A diamond covers its middle row fully and only the center of the outer rows.
+    let diamond = Obr::new(Point::new(0.5f32, 0.5), Extent::new(2.0, 2.0), Angle::from_degrees(45.0));
+    assert_eq!(
+        grid.rasterize(&diamond, RasterMode::Center),
+        cells(&[(0, -1), (-1, 0), (0, 0), (1, 0), (0, 1)])
+    );
+    assert_eq!(grid.rasterize(&diamond, RasterMode::Conservative).len(), 9);
+
+    // A concave U shape leaves its notch empty.
+    let u = Polygon::new(vec![
+        Point::new(0.0f32, 0.0),
+        Point::new(3.0, 0.0),
+        Point::new(3.0, 3.0),
+        Point::new(2.0, 3.0),
+        Point::new(2.0, 1.0),
+        Point::new(1.0, 1.0),
+        Point::new(1.0, 3.0),
+        Point::new(0.0, 3.0),
+    ]);
+    let expected = cells(&[(0, 0), (1, 0), (2, 0), (0, 1), (2, 1), (0, 2), (2, 2)]);
+    assert_eq!(grid.rasterize(&u, RasterMode::Center), expected);
+    assert_eq!(grid.rasterize(&u, RasterMode::Conservative), expected);
+}
+
+#[test]
+fn marching_squares_test() {
+    let grid = Grid::unit();
+
+    // A single raised sample makes a diamond through the midpoints of its edges.
+    let mut values = vec![0.0f32; 9];
+    values[4] = 1.0;
+    let field = ScalarField::new(3, 3, values);
+    let contours = field.contours(0.5, &grid);
+    assert_eq!(contours.len(), 1);
+    let diamond = &contours[0].exterior;
+    assert!(contours[0].holes.is_empty());
+    assert_eq!(diamond.len(), 4);
+    assert!(!diamond.is_clockwise());
+    for p in [Point::new(1.5, 1.0), Point::new(1.0, 1.5), Point::new(0.5, 1.0), Point::new(1.0, 0.5)] {
+        assert!(diamond.points.contains(&p), "{:?} missing from {:?}", p, diamond.points);
+    }
+    assert_approx_eq!(diamond.volume(), 0.5, 0.0001);
+
+    // Crossings are interpolated linearly along each edge.
+    let ramp = ScalarField::from_fn(3, 2, |x, _| x as f64);
+    let lines = ramp.isolines(0.25, &Grid::new(Point::zero(), Extent::new(2.0, 1.0)));
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].points, vec![Point::new(0.5, 1.0), Point::new(0.5, 0.0)]);
+
+    // A region touching the border is closed along it, while its isoline stays open.
+    let closed = ramp.contours(1.5, &Grid::unit());
+    assert_eq!(closed.len(), 1);
+    assert_approx_eq!(closed[0].volume(), 0.5, 0.0001);
+    assert!(!closed[0].exterior.is_clockwise());
+
+    // A ring of high samples around a low center makes an outline with a clockwise hole.
+    let ring = ScalarField::from_fn(5, 5, |x, y| {
+        let edge = x == 0 || y == 0 || x == 4 || y == 4;
+        let center = x == 2 && y == 2;
+        if edge || center { 0.0 } else { 1.0f32 }
+    });
+    let contours = ring.contours(0.5, &grid);
+    assert_eq!(contours.len(), 1);
+    let ring = &contours[0];
+    assert!(!ring.exterior.is_clockwise());
+    assert_eq!(ring.holes.len(), 1);
+    assert!(ring.holes[0].is_clockwise());
+    assert!(ring.contains(&Point::new(1.0, 1.0)));
+    assert!(!ring.contains(&Point::new(2.0, 2.0)));
+    assert_approx_eq!(ring.volume(), ring.exterior.volume() - 0.5, 0.0001);
+
+    // An island inside a hole is a contour of its own, and the hole stays with the outer ring.
+    let target = ScalarField::from_fn(7, 7, |x, y| {
+        let ring = (x as i32 - 3).abs().max((y as i32 - 3).abs());
+        if ring % 2 == 0 { 1.0f32 } else { 0.0 }
+    });
+    let mut contours = target.contours(0.5, &grid);
+    contours.sort_by(|a, b| a.volume().partial_cmp(&b.volume()).unwrap());
+    assert_eq!(contours.len(), 2);
+    let (island, outer) = (&contours[0], &contours[1]);
+    assert!(island.holes.is_empty());
+    assert!(island.contains(&Point::new(3.0, 3.0)));
+    assert_eq!(outer.holes.len(), 1);
+    assert!(outer.contains(&Point::new(1.0, 1.0)));
+    assert!(!outer.contains(&Point::new(3.0, 3.0)));
+    assert!(!outer.contains(&Point::new(2.0, 2.0)));
+}
+
+#[test]
+fn marching_squares_saddle_test() {
+    // Diagonal high corners, with the center deciding whether they join.
+    let saddle = |center_high: bool| {
+        let high = if center_high { 1.0f32 } else { 0.6 };
+        ScalarField::new(2, 2, vec![high, 0.0, 0.0, high])
+    };
+    let grid = Grid::unit();
+    let joined = saddle(true).isolines(0.45, &grid);
+    let split = saddle(false).isolines(0.45, &grid);
+    assert_eq!(joined.len(), 2);
+    assert_eq!(split.len(), 2);
+
+    // When joined, the lines cut off the low corners; otherwise they cut off the high ones.
+    let cuts_corner = |lines: &[Polyline<f32>], corner: Point<f32>| {
+        lines.iter().any(|l| l.points.iter().all(|p| p.distance(&corner) < 0.75))
+    };
+    assert!(cuts_corner(&joined, Point::new(1.0, 0.0)) && cuts_corner(&joined, Point::new(0.0, 1.0)));
+    assert!(cuts_corner(&split, Point::new(0.0, 0.0)) && cuts_corner(&split, Point::new(1.0, 1.0)));
+
+    // The closed contours agree: one connected region or two separate ones.
+    assert_eq!(saddle(true).contours(0.45, &grid).len(), 1);
+    assert_eq!(saddle(false).contours(0.45, &grid).len(), 2);
+}
@@ -1,5 +1,5 @@
 use assert_approx_eq::assert_approx_eq;
-use prima::{core::*, shapes::*, traits::*};
+use prima::{core::*, curves::*, shapes::*, traits::*};
 
 const IOTA: f32 = 0.001;
 
@@ -52,7 +52,8 @@ fn circle_test() {
     let b: Circle<f32> = Circle::new(Point::new(10.0, 10.0), 3.5);
     let c: Circle<f32> = Circle::new(Point::new(14.0, 10.0), 1.0);
     let ds = a.distance_squared(&b);
-    assert_eq!(ds, 106.75f32);
+    assert_approx_eq!(ds, (128f32.sqrt() - 6.5).powi(2), IOTA);
+    assert_eq!(b.distance_squared(&c), 0.0);
     assert!(!a.intersecting(&b));
     assert!(a.collision(&c).is_none());
     assert!(b.intersecting(&c));
@@ -76,7 +77,7 @@ fn obr_test() {
         Extent::new(2.0, 2.0),
         Angle::from_degrees(45.0f32),
     );
-    assert_eq!(a.rotation.as_radians(), 0.25f32);
+    assert_approx_eq!(a.rotation.as_radians(), std::f32::consts::FRAC_PI_4, IOTA);
     let v = a.vertices();
     assert_approx_eq!(v[0].x, 0.985, IOTA);
     assert_approx_eq!(v[0].y, 2.4, IOTA);
@@ -100,3 +101,333 @@ fn aabr_obr_test() {
     b.translate(&Vector::new(-2.0, 0.0));
     assert!(a.intersecting(&b));
 }
+
+#[test]
+fn polygon_test() {
+    let square: Polygon<f32> = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 4.0),
+        Point::new(0.0, 4.0),
+    ]);
+    assert!(!square.is_clockwise());
+    assert!(square.is_convex());
+    assert_approx_eq!(square.volume(), 16.0, IOTA);
+    assert_approx_eq!(square.circumference(), 16.0, IOTA);
+    assert_eq!(square.centroid(), Point::new(2.0, 2.0));
+    assert!(square.contains(&Point::new(1.0, 3.0)));
+    assert!(!square.contains(&Point::new(5.0, 3.0)));
+    assert_eq!(square.nearest_point(&Point::new(6.0, 2.0)), Point::new(4.0, 2.0));
+}
+
+#[test]
+fn polyline_test() {
+    let path: Polyline<f32> = Polyline::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 3.0),
+    ]);
+    assert_approx_eq!(path.magnitude(), 7.0, IOTA);
+    assert_eq!(path.point_at(2.0), Point::new(2.0, 0.0));
+    assert_eq!(path.point_at(5.0), Point::new(4.0, 1.0));
+    assert_eq!(path.point_at(100.0), Point::new(4.0, 3.0));
+    assert_eq!(path.tangent_at(5.0), Vector::new(0.0, 1.0));
+    assert_eq!(path.normal_at(1.0), Vector::new(0.0, 1.0));
+    assert_eq!(path.nearest_point(&Point::new(6.0, 2.0)), Point::new(4.0, 2.0));
+    assert_approx_eq!(path.distance(&Point::new(2.0, -1.0)), 1.0, IOTA);
+
+    let offset = path.offset(1.0);
+    assert_eq!(offset.points[0], Point::new(0.0, 1.0));
+    assert_approx_eq!(offset.points[1].x, 3.0, IOTA);
+    assert_approx_eq!(offset.points[1].y, 1.0, IOTA);
+    assert_eq!(offset.points[2], Point::new(3.0, 3.0));
+
+    let stroke = path.stroke(2.0);
+    assert_eq!(stroke.len(), 6);
+    assert!(stroke.contains(&Point::new(2.0, 0.5)));
+    assert!(!stroke.contains(&Point::new(2.0, 1.5)));
+
+    let noisy: Polyline<f32> = Polyline::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.05),
+        Point::new(2.0, -0.05),
+        Point::new(3.0, 0.0),
+        Point::new(3.0, 3.0),
+    ]);
+    let simple = noisy.simplify(0.1);
+    assert_eq!(simple.points.len(), 3);
+    assert_eq!(simple.points[1], Point::new(3.0, 0.0));
+}
+
+#[test]
+fn bezier_test() {
+    let curve: Bezier<f32> = Bezier::quadratic(
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 4.0),
+        Point::new(4.0, 0.0),
+    );
+    assert_eq!(curve.point_at(0.5), Point::new(2.0, 2.0));
+    assert_eq!(curve.derivative_at(0.5), Vector::new(4.0, 0.0));
+    let bb = curve.bounding_rect();
+    assert_approx_eq!(bb.max.y, 2.0, IOTA);
+    assert_approx_eq!(bb.max.x, 4.0, IOTA);
+
+    let (a, b) = curve.split(0.5);
+    assert_eq!(a.end(), Point::new(2.0, 2.0));
+    assert_eq!(b.start(), Point::new(2.0, 2.0));
+    assert_approx_eq!(a.magnitude() + b.magnitude(), curve.magnitude(), IOTA);
+
+    let straight: Bezier<f32> = Bezier::cubic(
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(9.0, 0.0),
+        Point::new(10.0, 0.0),
+    );
+    assert_approx_eq!(straight.magnitude(), 10.0, IOTA);
+    let p = straight.point_at_distance(5.0);
+    assert_approx_eq!(p.x, 5.0, IOTA);
+
+    let n = curve.nearest_point(&Point::new(2.0, 5.0));
+    assert_approx_eq!(n.x, 2.0, IOTA);
+    assert_approx_eq!(n.y, 2.0, IOTA);
+
+    let flat = curve.flatten(0.01);
+    assert!(flat.points.len() > 2);
+    for p in flat.points.iter() {
+        assert!(curve.distance(p) < 0.01);
+    }
+
+    let empty: Bezier<f32> = Bezier::new(vec![]);
+    assert_eq!(empty.start(), Point::zero());
+    assert_eq!(empty.end(), Point::zero());
+    assert!(empty.flatten(0.01).points.is_empty());
+    assert_eq!(empty.magnitude(), 0.0);
+
+    let single: Bezier<f32> = Bezier::new(vec![Point::new(1.0, 2.0)]);
+    assert_eq!(single.start(), Point::new(1.0, 2.0));
+    assert_eq!(single.end(), Point::new(1.0, 2.0));
+    assert_eq!(single.flatten(0.01).points, vec![Point::new(1.0, 2.0)]);
+    assert_eq!(single.magnitude(), 0.0);
+}
+
+#[test]
+fn catmull_rom_test() {
+    let spline: CatmullRom<f32> = CatmullRom::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 2.0),
+        Point::new(4.0, 0.0),
+        Point::new(6.0, 2.0),
+    ]);
+    assert_eq!(spline.point_at(0.0), Point::new(0.0, 0.0));
+    let mid = spline.point_at(1.0 / 3.0);
+    assert_approx_eq!(mid.x, 2.0, IOTA);
+    assert_approx_eq!(mid.y, 2.0, IOTA);
+    assert_eq!(spline.point_at(1.0), Point::new(6.0, 2.0));
+
+    let bb = spline.bounding_rect();
+    assert!(bb.min.x <= 0.0 && bb.max.x >= 6.0);
+    assert!(spline.flatten(0.01).points.len() > 4);
+    assert!(spline.distance(&Point::new(4.0, 0.0)) < IOTA);
+
+    let (a, b) = spline.split(1);
+    assert_eq!(a.points.len(), 2);
+    assert_eq!(b.points.len(), 3);
+
+    // Both halves of a split still follow the original spline.
+    let spline: CatmullRom<f32> = CatmullRom::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 2.0),
+        Point::new(3.0, -1.0),
+        Point::new(4.0, 5.0),
+    ]);
+    let (left, right) = spline.split(2);
+    assert_eq!(left.points.len(), 3);
+    assert_eq!(right.points.len(), 2);
+    for step in 0..=10 {
+        let t = step as f32 / 10.0;
+        let on_left = left.point_at(t);
+        let expected = spline.point_at(t * 2.0 / 3.0);
+        assert_approx_eq!(on_left.x, expected.x, IOTA);
+        assert_approx_eq!(on_left.y, expected.y, IOTA);
+        let on_right = right.point_at(t);
+        let expected = spline.point_at(2.0 / 3.0 + t / 3.0);
+        assert_approx_eq!(on_right.x, expected.x, IOTA);
+        assert_approx_eq!(on_right.y, expected.y, IOTA);
+    }
+
+    let empty: CatmullRom<f32> = CatmullRom::new(vec![]);
+    let (a, b) = empty.split(0);
+    assert!(a.points.is_empty() && b.points.is_empty());
+    assert!(empty.flatten(0.01).points.is_empty());
+}
+
+#[test]
+fn minkowski_test() {
+    let a: Aabr<f32> = Aabr::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+    let b: Aabr<f32> = Aabr::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0));
+    let sum = a.minkowski_sum(&b);
+    assert_eq!(sum.len(), 4);
+    assert_approx_eq!(sum.volume(), 16.0, IOTA);
+    assert_eq!(sum.bounding_rect(), Aabr::new(Point::new(-1.0, -1.0), Point::new(3.0, 3.0)));
+
+    // The difference contains the origin only when the shapes overlap.
+    assert!(a.minkowski_difference(&b).contains(&Point::zero()));
+    let far: Aabr<f32> = Aabr::new(Point::new(5.0, 5.0), Point::new(6.0, 6.0));
+    assert!(!a.minkowski_difference(&far).contains(&Point::zero()));
+
+    let circle: Circle<f32> = Circle::new(Point::new(0.0, 0.0), 1.0);
+    let rounded = a.minkowski_sum(&circle);
+    assert_approx_eq!(rounded.volume(), 4.0 + 8.0 + std::f32::consts::PI, IOTA);
+    assert!(rounded.contains(&Point::new(-0.9, 1.0)));
+    assert!(!rounded.contains(&Point::new(-0.9, -0.9)));
+    assert_eq!(a.inflate(1.0), rounded);
+
+    let line: Line<f32> = Line::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0));
+    let capsule = line.minkowski_sum(&circle);
+    assert!(capsule.contains(&Point::new(2.0, 0.9)));
+    assert!(capsule.contains(&Point::new(-0.9, 0.0)));
+    assert!(!capsule.contains(&Point::new(2.0, 1.1)));
+    assert_approx_eq!(capsule.volume(), 8.0 + std::f32::consts::PI, IOTA);
+
+    let other: Circle<f32> = Circle::new(Point::new(3.0, 0.0), 2.0);
+    assert_eq!(circle.minkowski_sum(&other), Circle::new(Point::new(3.0, 0.0), 3.0));
+    assert_eq!(circle.minkowski_difference(&other), Circle::new(Point::new(-3.0, 0.0), 3.0));
+
+    // Circles on the left give the same shapes as on the right.
+    assert_eq!(circle.minkowski_sum(&a), rounded);
+    assert_eq!(circle.minkowski_sum(&capsule), capsule.minkowski_sum(&circle));
+    let distant = Capsule::new(Line::new(Point::new(5.0, 0.0), Point::new(9.0, 0.0)), 1.0);
+    let far_capsule = circle.minkowski_difference(&distant);
+    assert_eq!(far_capsule.radius, 2.0);
+    assert!(!far_capsule.contains(&Point::zero()));
+
+    // Rounded shapes combine their cores and add their radii.
+    let capsules = capsule.minkowski_sum(&capsule);
+    assert_eq!(capsules.radius, 2.0);
+    assert!(capsules.contains(&Point::new(8.0, 1.9)));
+    assert!(!capsules.contains(&Point::new(8.0, 2.1)));
+    let boxed = rounded.minkowski_sum(&capsule);
+    assert_eq!(boxed.radius, 2.0);
+    assert_approx_eq!(boxed.polygon.volume(), 12.0, IOTA);
+    assert_eq!(a.minkowski_sum(&capsule), capsule.minkowski_sum(&a));
+    assert_eq!(line.minkowski_sum(&rounded).radius, 1.0);
+    assert!(rounded.minkowski_difference(&rounded).contains(&Point::zero()));
+    assert!(!capsule.minkowski_difference(&far_capsule).contains(&Point::zero()));
+    assert!(circle.minkowski_difference(&rounded).contains(&Point::zero()));
+}
+
+#[test]
+fn matrix_test() {
+    let v: Vector<f32> = Vector::new(1.0, 0.5);
+    let r = Rotation::from_degrees(30.0);
+
+    // Matrices rotate the same way as rotations do.
+    let a = v * r;
+    let b = Mat2::from_rotation(r) * v;
+    assert_approx_eq!(a.x, b.x, IOTA);
+    assert_approx_eq!(a.y, b.y, IOTA);
+    let m: Mat2<f32> = r.to_matrix().into();
+    assert_eq!(m, Mat2::from_rotation(r));
+    assert_approx_eq!(m.determinant(), 1.0, IOTA);
+    assert_eq!(m.transpose().transpose(), m);
+
+    let shear: Mat2<f32> = Mat2::from_shear(2.0, 0.0);
+    assert_eq!(shear * Vector::new(1.0, 1.0), Vector::new(3.0, 1.0));
+    let scale: Mat2<f32> = Mat2::from_scale(Vector::new(2.0, 4.0));
+    assert_eq!(scale.determinant(), 8.0);
+    assert_eq!(scale.inverse().unwrap() * scale, Mat2::identity());
+    assert_eq!(Mat2::<f32>::zero().inverse(), None);
+
+    // Scale, then rotate a quarter turn clockwise, then translate.
+    let t: Mat3<f32> = Mat3::from_scale_rotation_translation(
+        Vector::new(2.0, 2.0),
+        Rotation::from_degrees(90.0),
+        Vector::new(10.0, 0.0),
+    );
+    let p = t * Point::new(0.0, 1.0);
+    assert_approx_eq!(p.x, 12.0, IOTA);
+    assert_approx_eq!(p.y, 0.0, IOTA);
+    let w = t * Vector::new(0.0, 1.0);
+    assert_approx_eq!(w.x, 2.0, IOTA);
+    assert_approx_eq!(t.determinant(), 4.0, IOTA);
+
+    let back = t.inverse().unwrap() * p;
+    assert_approx_eq!(back.x, 0.0, IOTA);
+    assert_approx_eq!(back.y, 1.0, IOTA);
+    let identity = t * t.inverse().unwrap();
+    for (a, b) in [(identity.m00, 1.0), (identity.m10, 0.0), (identity.m20, 0.0), (identity.m11, 1.0), (identity.m21, 0.0)] {
+        assert_approx_eq!(a, b, IOTA);
+    }
+    assert_eq!(Mat3::from_translation(Vector::new(1.0, 2.0)).translation(), Vector::new(1.0f32, 2.0));
+    assert_eq!(Mat3::from(Mat2::from_shear(1.0f32, 0.0)).mat2(), Mat2::from_shear(1.0, 0.0));
+    assert_eq!(Mat3::<f32>::from(r.to_matrix()), Mat3::from_rotation(r));
+}
+
+#[test]
+fn signed_distance_test() {
+    let p = |x: f32, y: f32| Point::new(x, y);
+
+    let circle = Circle::new(p(1.0, 1.0), 2.0);
+    assert_approx_eq!(circle.signed_distance(&p(1.0, 1.0)), -2.0, IOTA);
+    assert_approx_eq!(circle.signed_distance(&p(1.0, 5.0)), 2.0, IOTA);
+    assert_eq!(circle.normal(&p(4.0, 1.0)), Some(Vector::right()));
+    assert_eq!(circle.normal(&p(1.0, 1.0)), None);
+
+    let aabr = Aabr::new(p(0.0, 0.0), p(4.0, 2.0));
+    assert_approx_eq!(aabr.signed_distance(&p(2.0, 1.0)), -1.0, IOTA);
+    assert_approx_eq!(aabr.signed_distance(&p(3.5, 1.0)), -0.5, IOTA);
+    assert_approx_eq!(aabr.signed_distance(&p(2.0, 5.0)), 3.0, IOTA);
+    assert_approx_eq!(aabr.signed_distance(&p(7.0, 6.0)), 5.0, IOTA);
+    let n = aabr.normal(&p(5.0, 1.0)).unwrap();
+    assert_approx_eq!(n.x, 1.0, IOTA);
+    assert_approx_eq!(n.y, 0.0, IOTA);
+
+    // An orientated box matches its polygon everywhere.
+    let obr = Obr::new(p(1.0, -1.0), Extent::new(3.0, 1.0), Angle::from_degrees(30.0));
+    let polygon = Polygon::new(obr.vertices());
+    for q in [p(1.0, -1.0), p(2.0, 0.0), p(-3.0, 2.0), p(1.5, -1.2), p(4.0, -4.0)] {
+        assert_approx_eq!(obr.signed_distance(&q), polygon.signed_distance(&q), IOTA);
+    }
+    assert_approx_eq!(obr.signed_distance(&p(1.0, -1.0)), -0.5, IOTA);
+
+    // A concave L shape, measured inside the notch and the arms.
+    let l = Polygon::new(vec![p(0.0, 0.0), p(3.0, 0.0), p(3.0, 1.0), p(1.0, 1.0), p(1.0, 3.0), p(0.0, 3.0)]);
+    assert_approx_eq!(l.signed_distance(&p(2.0, 0.5)), -0.5, IOTA);
+    assert_approx_eq!(l.signed_distance(&p(2.0, 2.0)), 1.0, IOTA);
+
+    let line = Line::new(p(0.0, 0.0), p(4.0, 0.0));
+    let capsule = Capsule::new(line, 0.5);
+    assert_approx_eq!(line.signed_distance(&p(2.0, 0.0)), 0.0, IOTA);
+    for q in [p(2.0, 0.0), p(-1.0, 1.0), p(2.0, 0.3)] {
+        assert_approx_eq!(line.offset(0.5).signed_distance(&q), capsule.signed_distance(&q), IOTA);
+    }
+    let rounded = Polygon::new(aabr.vertices()).inflate(1.0);
+    assert_approx_eq!(rounded.signed_distance(&p(2.0, 4.0)), 1.0, IOTA);
+}
+
+#[test]
+fn sdf_combinator_test() {
+    let p = |x: f32, y: f32| Point::new(x, y);
+    let a = Circle::new(p(0.0, 0.0), 1.0);
+    let b = Circle::new(p(1.5, 0.0), 1.0);
+
+    let union = a.union(b);
+    assert_approx_eq!(union.signed_distance(&p(-1.0, 0.0)), 0.0, IOTA);
+    assert_approx_eq!(union.signed_distance(&p(2.5, 0.0)), 0.0, IOTA);
+    let both = a.intersection(&b);
+    assert!(both.signed_distance(&p(0.75, 0.0)) < 0.0);
+    assert!(both.signed_distance(&p(-0.5, 0.0)) > 0.0);
+
+    // The smooth union bulges past the plain union only near the seam.
+    let smooth = a.smooth_union(b, 0.5);
+    let seam = p(0.75, 0.7);
+    assert!(smooth.signed_distance(&seam) < union.signed_distance(&seam));
+    assert_approx_eq!(smooth.signed_distance(&p(-3.0, 0.0)), union.signed_distance(&p(-3.0, 0.0)), IOTA);
+    assert_eq!(prima::sdf::smooth_min(1.0f32, 2.0, 0.0), 1.0);
+
+    let grown = union.offset(0.5);
+    assert_approx_eq!(grown.signed_distance(&p(-1.5, 0.0)), 0.0, IOTA);
+    let n = grown.normal(&p(-3.0, 0.0)).unwrap();
+    assert_approx_eq!(n.x, -1.0, 0.01);
+    assert_approx_eq!(n.y, 0.0, 0.01);
+}
@@ -0,0 +1,79 @@
+#[allow(unused_imports)]
+use prima::prelude::*;
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_test() {
+    let p: glam::Vec2 = Point::new(1.0f32, 2.0).into();
+    assert_eq!(p, glam::Vec2::new(1.0, 2.0));
+    let v: Vector<f64> = glam::DVec2::new(3.0, 4.0).into();
+    assert_eq!(v, Vector::new(3.0, 4.0));
+    let e: glam::Vec2 = Extent::new(5.0f32, 6.0).into();
+    assert_eq!(e, glam::Vec2::new(5.0, 6.0));
+
+    // Rotating with either matrix should give the same result.
+    let r = Rotation::from_degrees(30.0f32);
+    let m: glam::Mat2 = r.into();
+    let a = Vector::new(1.0f32, 0.5) * r;
+    let b = m * glam::Vec2::new(1.0, 0.5);
+    assert!((a.x - b.x).abs() < 0.0001 && (a.y - b.y).abs() < 0.0001);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_test() {
+    let p: nalgebra::Point2<f32> = Point::new(1.0f32, 2.0).into();
+    assert_eq!(p, nalgebra::Point2::new(1.0, 2.0));
+    let back: Point<f32> = p.into();
+    assert_eq!(back, Point::new(1.0, 2.0));
+
+    let r = Rotation::from_degrees(90.0f64);
+    let n: nalgebra::Rotation2<f64> = r.into();
+    let v = n * nalgebra::Vector2::new(0.0, 1.0);
+    // A positive prima rotation is clockwise, so up becomes right.
+    assert!((v.x - 1.0).abs() < 0.0001 && v.y.abs() < 0.0001);
+    let r2: Rotation<f64> = n.into();
+    assert!((r2.as_degrees() - 90.0).abs() < 0.0001);
+
+    let m: nalgebra::Matrix2<f64> = r.to_matrix().into();
+    let prima_v = Vector::new(0.0, 1.0) * r;
+    let nalgebra_v = m * nalgebra::Vector2::new(0.0, 1.0);
+    assert!((prima_v.x - nalgebra_v.x).abs() < 0.0001);
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint_test() {
+    let p: mint::Point2<i32> = Point::new(1, 2).into();
+    assert_eq!(p, mint::Point2 { x: 1, y: 2 });
+    let m: mint::ColumnMatrix2<f32> = Rotation::from_degrees(45.0f32).to_matrix().into();
+    let back: prima::core::AngleMat<f32> = m.into();
+    assert_eq!(back.m10, Rotation::from_degrees(45.0f32).to_matrix().m10);
+}
+
+#[cfg(feature = "vek")]
+#[test]
+fn vek_test() {
+    let aabr = Aabr::new(Point::new(0.0f32, 1.0), Point::new(2.0, 3.0));
+    let v: vek::Aabr<f32> = aabr.into();
+    assert_eq!(v.max, vek::Vec2::new(2.0, 3.0));
+    let back: Aabr<f32> = v.into();
+    assert_eq!(back, aabr);
+
+    let disk: vek::Disk<f32, f32> = Circle::new(Point::new(1.0, 1.0), 2.0).into();
+    assert_eq!(disk.radius, 2.0);
+}
+
+#[cfg(feature = "parry2d")]
+#[test]
+fn parry2d_test() {
+    let aabr = Aabr::new(Point::new(0.0f32, 1.0), Point::new(2.0, 3.0));
+    let aabb: parry2d::bounding_volume::Aabb = aabr.into();
+    let back: Aabr<f32> = aabb.into();
+    assert_eq!(back, aabr);
+
+    let circle = Circle::new(Point::new(1.0f32, 1.0), 2.0);
+    let sphere: parry2d::bounding_volume::BoundingSphere = circle.into();
+    let back: Circle<f32> = sphere.into();
+    assert_eq!(back, circle);
+}
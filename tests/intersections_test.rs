@@ -0,0 +1,106 @@
+use assert_approx_eq::assert_approx_eq;
+use prima::prelude::*;
+
+#[test]
+fn circle_circle_intersections_test() {
+    let a = Circle::new(Point::new(0.0, 0.0), 5.0);
+    let b = Circle::new(Point::new(8.0, 0.0), 5.0);
+    let points = a.intersections(&b);
+    assert_eq!(points.len(), 2);
+    // The first point is to the left of the line from a to b.
+    assert_approx_eq!(points[0].x, 4.0_f64);
+    assert_approx_eq!(points[0].y, 3.0_f64);
+    assert_approx_eq!(points[1].x, 4.0_f64);
+    assert_approx_eq!(points[1].y, -3.0_f64);
+
+    let touching = Circle::new(Point::new(10.0, 0.0), 5.0);
+    assert_eq!(a.intersections(&touching), vec![Point::new(5.0, 0.0)]);
+
+    let inner = Circle::new(Point::new(1.0, 0.0), 1.0);
+    assert!(a.intersections(&inner).is_empty());
+    assert!(a.intersections(&a).is_empty());
+    assert!(a
+        .intersections(&Circle::new(Point::new(20.0, 0.0), 1.0))
+        .is_empty());
+}
+
+#[test]
+fn circle_line_intersections_test() {
+    let circle = Circle::new(Point::new(0.0, 0.0), 2.0);
+    let through = Line::new(Point::new(-5.0, 0.0), Point::new(5.0, 0.0));
+    assert_eq!(
+        through.intersections(&circle),
+        vec![Point::new(-2.0, 0.0), Point::new(2.0, 0.0)]
+    );
+    assert_eq!(
+        circle.intersections(&through),
+        through.intersections(&circle)
+    );
+
+    // Lines that end inside the circle only cross it once.
+    let half = Line::new(Point::new(0.0, 0.0), Point::new(0.0, -5.0));
+    assert_eq!(half.intersections(&circle), vec![Point::new(0.0, -2.0)]);
+
+    let tangent = Line::new(Point::new(-5.0, 2.0), Point::new(5.0, 2.0));
+    assert_eq!(tangent.intersections(&circle), vec![Point::new(0.0, 2.0)]);
+
+    let inside = Line::new(Point::new(-1.0, 0.0), Point::new(1.0, 0.0));
+    assert!(inside.intersections(&circle).is_empty());
+}
+
+#[test]
+fn line_rect_intersections_test() {
+    let aabr = Aabr::new(Point::new(0.0, 0.0), Point::new(4.0, 2.0));
+    let line = Line::new(Point::new(5.0, 1.0), Point::new(-1.0, 1.0));
+    assert_eq!(
+        line.intersections(&aabr),
+        vec![Point::new(4.0, 1.0), Point::new(0.0, 1.0)]
+    );
+    assert_eq!(aabr.intersections(&line), line.intersections(&aabr));
+
+    // A line through a corner crosses there once, not once per edge.
+    let diagonal = Line::new(Point::new(-1.0, -1.0), Point::new(3.0, 3.0));
+    assert_eq!(
+        diagonal.intersections(&aabr),
+        vec![Point::new(0.0, 0.0), Point::new(2.0, 2.0)]
+    );
+
+    let obr = Obr::new(
+        Point::new(0.0, 0.0),
+        Extent::new(2.0, 2.0),
+        Angle::from_degrees(45.0),
+    );
+    let across = Line::new(Point::new(-3.0, 0.0), Point::new(3.0, 0.0));
+    let points = across.intersections(&obr);
+    assert_eq!(points.len(), 2);
+    assert_approx_eq!(points[0].x, -(2.0_f64.sqrt()));
+    assert_approx_eq!(points[1].x, 2.0_f64.sqrt());
+    assert_eq!(obr.intersections(&across), points);
+}
+
+#[test]
+fn rect_rect_intersections_test() {
+    let a = Aabr::new(Point::new(0.0, 0.0), Point::new(4.0, 4.0));
+    let b = Aabr::new(Point::new(2.0, 1.0), Point::new(6.0, 3.0));
+    let points = a.intersections(&b);
+    assert_eq!(points.len(), 2);
+    assert!(points.contains(&Point::new(4.0, 1.0)));
+    assert!(points.contains(&Point::new(4.0, 3.0)));
+
+    let inside = Aabr::new(Point::new(1.0, 1.0), Point::new(2.0, 2.0));
+    assert!(a.intersections(&inside).is_empty());
+
+    // Two squares turned against each other cross eight times.
+    let square: Obr<f64> = Obr::new(Point::new(0.0, 0.0), Extent::new(2.0, 2.0), Angle::zero());
+    let turned = Obr::new(
+        Point::new(0.0, 0.0),
+        Extent::new(2.0, 2.0),
+        Angle::from_degrees(45.0),
+    );
+    let points = square.intersections(&turned);
+    assert_eq!(points.len(), 8);
+    for p in points {
+        assert!(square.signed_distance(&p).abs() < 1e-9);
+        assert!(turned.signed_distance(&p).abs() < 1e-9);
+    }
+}
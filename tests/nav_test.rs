@@ -0,0 +1,141 @@
+use assert_approx_eq::assert_approx_eq;
+use prima::{nav::*, prelude::*};
+
+const IOTA: f32 = 0.01;
+
+fn room() -> Polygon<f32> {
+    Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(20.0, 0.0),
+        Point::new(20.0, 10.0),
+        Point::new(0.0, 10.0),
+    ])
+}
+
+#[test]
+fn open_room_test() {
+    let mesh = NavMesh::build(&room(), &[], 0.0);
+    assert!(!mesh.triangles.is_empty());
+    let path = mesh
+        .find_path(Point::new(1.0, 1.0), Point::new(19.0, 9.0))
+        .unwrap();
+    assert_eq!(path.points.len(), 2);
+    assert!(mesh.find_path(Point::new(-1.0, 1.0), Point::new(5.0, 5.0)).is_none());
+}
+
+#[test]
+fn obstacle_test() {
+    // A wall down the middle of the room, leaving a gap at the top.
+    let wall = Aabr::new(Point::new(9.0, -1.0), Point::new(11.0, 8.0));
+    let mesh = NavMesh::build(&room(), &[wall.into()], 0.0);
+
+    let start = Point::new(2.0, 2.0);
+    let end = Point::new(18.0, 2.0);
+    assert!(mesh.triangle_at(&Point::new(10.0, 4.0)).is_none());
+
+    let path = mesh.find_path(start, end).unwrap();
+    assert_eq!(path.points.len(), 4);
+    assert_approx_eq!(path.points[1].x, 9.0, IOTA);
+    assert_approx_eq!(path.points[1].y, 8.0, IOTA);
+    assert_approx_eq!(path.points[2].x, 11.0, IOTA);
+    assert_approx_eq!(path.points[2].y, 8.0, IOTA);
+    let expected = 2.0 * Point::new(2.0, 2.0).distance(&Point::new(9.0, 8.0)) + 2.0;
+    assert_approx_eq!(path.magnitude(), expected, IOTA);
+}
+
+#[test]
+fn agent_radius_test() {
+    let pillar = Circle::new(Point::new(10.0, 5.0), 2.0);
+    let mesh = NavMesh::build(&room(), &[pillar.into()], 1.0);
+    // Too close to the wall of the room for the agent to stand.
+    assert!(mesh.triangle_at(&Point::new(0.5, 5.0)).is_none());
+
+    let path = mesh
+        .find_path(Point::new(2.0, 5.0), Point::new(18.0, 5.0))
+        .unwrap();
+    assert!(path.points.len() > 2);
+    for segment in path.segments() {
+        assert!(segment.distance(&pillar.center) >= 2.99);
+    }
+}
+
+#[test]
+fn sharp_obstacle_test() {
+    // A thin spike pointing into the room, with a tip far sharper than the miter limit allows.
+    let spike = Polygon::new(vec![
+        Point::new(2.0, 4.8),
+        Point::new(16.0, 5.0),
+        Point::new(2.0, 5.2),
+    ]);
+    let mesh = NavMesh::build(&room(), &[spike.clone().into()], 1.0);
+    assert!(mesh.triangle_at(&Point::new(12.0, 5.8)).is_none());
+    for i in 0..mesh.triangles.len() {
+        let [a, b, c] = mesh.triangle_points(i);
+        for edge in [Line::new(a, b), Line::new(b, c), Line::new(c, a)] {
+            for step in 0..=20 {
+                let p = edge.start + (edge.end - edge.start) * (step as f32 / 20.0);
+                assert!(spike.distance(&p) >= 1.0 - IOTA);
+            }
+        }
+    }
+
+    let path = mesh
+        .find_path(Point::new(10.0, 8.0), Point::new(10.0, 2.0))
+        .unwrap();
+    for segment in path.segments() {
+        assert!(segment.distance(&Point::new(16.0, 5.0)) >= 1.0 - IOTA);
+    }
+}
+
+#[test]
+fn split_limit_test() {
+    // A long, thin wall with blocks close to its middle on both sides, so its edges have to be
+    // split before the mesh follows them.
+    let obstacles: Vec<Obstacle<f32>> = vec![
+        Aabr::new(Point::new(2.0, 5.0), Point::new(18.0, 5.1)).into(),
+        Aabr::new(Point::new(9.5, 5.5), Point::new(10.5, 6.5)).into(),
+        Aabr::new(Point::new(9.5, 3.5), Point::new(10.5, 4.5)).into(),
+    ];
+    let mesh = NavMesh::build(&room(), &obstacles, 0.0);
+    assert!(mesh.conforming);
+    assert!(mesh.triangle_at(&Point::new(6.0, 5.05)).is_none());
+
+    let mesh = NavMesh::build_with_split_limit(&room(), &obstacles, 0.0, 0);
+    assert!(!mesh.conforming);
+    let mesh = NavMesh::build_with_split_limit(&room(), &[], 0.0, 0);
+    assert!(mesh.conforming);
+}
+
+#[test]
+fn visibility_test() {
+    let observer = Point::new(0.0f32, 0.0);
+    let wall = Aabr::new(Point::new(2.0, -1.0), Point::new(3.0, 1.0));
+    let occluders: Vec<Occluder<f32>> = vec![wall.into()];
+
+    let open = visibility_polygon(observer, &[], 10.0);
+    assert!(!open.is_clockwise());
+    assert_approx_eq!(open.volume(), std::f32::consts::PI * 100.0, 1.0);
+
+    let seen = visibility_polygon(observer, &occluders, 10.0);
+    assert!(!seen.is_clockwise());
+    assert!(seen.contains(&Point::new(1.0, 0.0)));
+    assert!(!seen.contains(&Point::new(5.0, 0.0)));
+    assert!(seen.contains(&Point::new(0.0, 5.0)));
+    assert!(seen.contains(&Point::new(-5.0, 0.0)));
+    assert!(!seen.contains(&Point::new(0.0, 11.0)));
+
+    let cone = ViewCone::new(Angle::from_degrees(0.0), Angle::from_degrees(90.0), 10.0);
+    let seen = visibility_cone(observer, &occluders, &cone);
+    assert_eq!(seen.points[0], observer);
+    assert!(seen.contains(&Point::new(1.0, 1.0)));
+    assert!(!seen.contains(&Point::new(-1.0, 1.0)));
+    assert!(!seen.contains(&Point::new(5.0, 0.2)));
+    assert!(seen.contains(&Point::new(5.0, 3.0)));
+
+    // Points just inside the shadow of a round occluder stay hidden.
+    let pillar = Circle::new(Point::new(5.0, 0.0), 1.0);
+    let seen = visibility_polygon(observer, &[pillar.into()], 10.0);
+    let angle = 11.45_f32.to_radians();
+    assert!(!seen.contains(&Point::new(9.0 * angle.cos(), 9.0 * angle.sin())));
+    assert!(seen.contains(&Point::new(5.0, 2.0)));
+}
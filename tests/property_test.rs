@@ -0,0 +1,277 @@
+use prima::{core::Ray, prelude::*};
+use proptest::prelude::*;
+
+/// Tolerance for comparing distances between shapes within a few thousand units of the origin.
+const EPSILON: f64 = 1e-6;
+
+/// Checks that a pairwise test gives the same answer in both directions.
+macro_rules! symmetric {
+    ($name:ident, $a:ty, $b:ty) => {
+        proptest! {
+            #[test]
+            fn $name(a in any::<$a>(), b in any::<$b>()) {
+                prop_assert_eq!(a.intersecting(&b), b.intersecting(&a));
+            }
+        }
+    };
+}
+
+symmetric!(circle_circle_symmetry, Circle<f64>, Circle<f64>);
+symmetric!(circle_aabr_symmetry, Circle<f64>, Aabr<f64>);
+symmetric!(aabr_aabr_symmetry, Aabr<f64>, Aabr<f64>);
+symmetric!(aabr_obr_symmetry, Aabr<f64>, Obr<f64>);
+symmetric!(obr_obr_symmetry, Obr<f64>, Obr<f64>);
+symmetric!(any_shape_symmetry, AnyShape<f64>, AnyShape<f64>);
+
+/// Checks that shapes are intersecting exactly when the distance between them is zero.
+macro_rules! distance_zero {
+    ($name:ident, $a:ty, $b:ty) => {
+        proptest! {
+            #[test]
+            fn $name(a in any::<$a>(), b in any::<$b>()) {
+                let distance = a.distance(&b);
+                prop_assert!(distance >= 0.0, "negative distance {}", distance);
+                if a.intersecting(&b) {
+                    prop_assert!(distance <= EPSILON, "intersecting at distance {}", distance);
+                } else {
+                    prop_assert!(distance > 0.0, "apart at distance {}", distance);
+                }
+            }
+        }
+    };
+}
+
+distance_zero!(circle_circle_distance, Circle<f64>, Circle<f64>);
+distance_zero!(circle_aabr_distance, Circle<f64>, Aabr<f64>);
+distance_zero!(aabr_circle_distance, Aabr<f64>, Circle<f64>);
+distance_zero!(aabr_aabr_distance, Aabr<f64>, Aabr<f64>);
+distance_zero!(any_shape_distance, AnyShape<f64>, AnyShape<f64>);
+
+/// Checks that the nearest point of a shape to any point lies inside the shape or on its
+/// boundary, and is no further away than the boundary is.
+macro_rules! nearest_on_shape {
+    ($name:ident, $shape:ty) => {
+        proptest! {
+            #[test]
+            fn $name(shape in any::<$shape>(), point in any::<Point<f64>>()) {
+                let nearest = shape.nearest_point(&point);
+                prop_assert!(shape.signed_distance(&nearest) <= EPSILON);
+                let gap = shape.signed_distance(&point).abs();
+                prop_assert!(nearest.distance(&point) <= gap + EPSILON);
+            }
+        }
+    };
+}
+
+nearest_on_shape!(aabr_nearest, Aabr<f64>);
+nearest_on_shape!(circle_nearest, Circle<f64>);
+nearest_on_shape!(obr_nearest, Obr<f64>);
+nearest_on_shape!(polygon_nearest, Polygon<f64>);
+nearest_on_shape!(capsule_nearest, Capsule<f64>);
+nearest_on_shape!(rounded_polygon_nearest, RoundedPolygon<f64>);
+
+/// Checks that a shape enveloping another is also intersecting it.
+macro_rules! enveloping_intersects {
+    ($name:ident, $a:ty, $b:ty) => {
+        proptest! {
+            #[test]
+            fn $name(a in any::<$a>(), b in any::<$b>()) {
+                if a.enveloping(&b) {
+                    prop_assert!(a.intersecting(&b));
+                }
+                if a.enveloped_by(&b) {
+                    prop_assert!(a.intersecting(&b));
+                }
+            }
+        }
+    };
+}
+
+enveloping_intersects!(circle_circle_enveloping, Circle<f64>, Circle<f64>);
+enveloping_intersects!(circle_aabr_enveloping, Circle<f64>, Aabr<f64>);
+enveloping_intersects!(aabr_circle_enveloping, Aabr<f64>, Circle<f64>);
+enveloping_intersects!(aabr_aabr_enveloping, Aabr<f64>, Aabr<f64>);
+enveloping_intersects!(aabr_obr_enveloping, Aabr<f64>, Obr<f64>);
+enveloping_intersects!(circle_obr_enveloping, Circle<f64>, Obr<f64>);
+enveloping_intersects!(any_shape_enveloping, AnyShape<f64>, AnyShape<f64>);
+
+proptest! {
+    #[test]
+    fn enveloping_self(aabr in any::<Aabr<f64>>(), circle in any::<Circle<f64>>()) {
+        prop_assert!(aabr.enveloping(&aabr));
+        prop_assert!(circle.enveloping(&circle));
+    }
+
+    #[test]
+    fn any_shape_matches_circles(a in any::<Circle<f64>>(), b in any::<Circle<f64>>()) {
+        let (any_a, any_b) = (AnyShape::from(a), AnyShape::from(b));
+        prop_assert_eq!(any_a.intersecting(&any_b), a.intersecting(&b));
+        prop_assert!((any_a.distance(&any_b) - a.distance(&b)).abs() <= EPSILON);
+    }
+
+    #[test]
+    fn enveloping_grown(circle in any::<Circle<f64>>(), aabr in any::<Aabr<f64>>()) {
+        // A rectangle envelops its inscribed circle, and a circle is enveloped by its bounds.
+        let inscribed = Circle::new(aabr.position(), aabr.width().min(aabr.height()) / 2.0);
+        prop_assert!(aabr.enveloping(&inscribed));
+        prop_assert!(circle.bounding_rect().enveloping(&circle));
+    }
+}
+
+/// Checks that rotating two shapes about the same point keeps them the same distance apart.
+macro_rules! rotation_preserves_distance {
+    ($name:ident, $shape:ty) => {
+        proptest! {
+            #[test]
+            fn $name(
+                shape in any::<$shape>(),
+                point in any::<Point<f64>>(),
+                pivot in any::<Point<f64>>(),
+                rotation in any::<Rotation<f64>>(),
+            ) {
+                let before = shape.signed_distance(&point);
+                let mut rotated = shape.clone();
+                rotated.rotate_around(pivot, rotation);
+                let after = rotated.signed_distance(&point.rotate_around(pivot, rotation));
+                prop_assert!((before - after).abs() <= 1e-6 * before.abs().max(1.0), "{} became {}", before, after);
+                prop_assert!((shape.position().distance(&pivot) - rotated.position().distance(&pivot)).abs() <= 1e-6 * 4000.0);
+            }
+        }
+    };
+}
+
+rotation_preserves_distance!(circle_rotation, Circle<f64>);
+rotation_preserves_distance!(polygon_rotation, Polygon<f64>);
+rotation_preserves_distance!(capsule_rotation, Capsule<f64>);
+rotation_preserves_distance!(rounded_polygon_rotation, RoundedPolygon<f64>);
+
+proptest! {
+    #[test]
+    fn point_rotation(a in any::<Point<f64>>(), b in any::<Point<f64>>(), pivot in any::<Point<f64>>(), rotation in any::<Rotation<f64>>()) {
+        let before = a.distance(&b);
+        let after = a.rotate_around(pivot, rotation).distance(&b.rotate_around(pivot, rotation));
+        prop_assert!((before - after).abs() <= 1e-9 * 4000.0);
+    }
+}
+
+/// Checks that the bounding rectangle and circle of a flat shape contain all of its vertices.
+macro_rules! bounds_contain_vertices {
+    ($name:ident, $shape:ty) => {
+        proptest! {
+            #[test]
+            fn $name(shape in any::<$shape>()) {
+                let rect = shape.bounding_rect();
+                let circle = shape.bounding_circle();
+                for v in shape.vertices() {
+                    prop_assert!(rect.contains(&v), "{:?} outside {:?}", v, rect);
+                    prop_assert!(circle.signed_distance(&v) <= EPSILON, "{:?} outside {:?}", v, circle);
+                }
+            }
+        }
+    };
+}
+
+bounds_contain_vertices!(aabr_bounds, Aabr<f64>);
+bounds_contain_vertices!(obr_bounds, Obr<f64>);
+bounds_contain_vertices!(polygon_bounds, Polygon<f64>);
+
+proptest! {
+    #[test]
+    fn obr_contains_vertices(obr in any::<Obr<f64>>()) {
+        // Vertices sit on the boundary, so are contained up to rounding.
+        for v in obr.vertices() {
+            prop_assert!(obr.signed_distance(&v).abs() <= EPSILON);
+        }
+        prop_assert!(obr.contains(&obr.center));
+    }
+
+    #[test]
+    fn obr_contains_matches_signed_distance(obr in any::<Obr<f64>>(), point in any::<Point<f64>>()) {
+        let sd = obr.signed_distance(&point);
+        if sd.abs() > EPSILON {
+            prop_assert_eq!(obr.contains(&point), sd < 0.0);
+        }
+    }
+
+    #[test]
+    fn f32_shapes_are_finite(circle in any::<Circle<f32>>(), obr in any::<Obr<f32>>(), polygon in any::<Polygon<f32>>()) {
+        prop_assert!(circle.volume().is_finite());
+        prop_assert!(obr.vertices().iter().all(|v| v.x.is_finite() && v.y.is_finite()));
+        prop_assert!(polygon.volume() > 0.0);
+    }
+
+    #[test]
+    fn integer_aabrs_are_ordered(aabr in any::<Aabr<i32>>()) {
+        prop_assert!(aabr.min.x < aabr.max.x && aabr.min.y < aabr.max.y);
+    }
+}
+
+fn convex(shape: &AnyShape<f64>) -> bool {
+    match shape {
+        AnyShape::Polygon(polygon) => polygon.is_convex(),
+        _ => true,
+    }
+}
+
+proptest! {
+    #[test]
+    fn ray_cast_hits_boundary(shape in any::<AnyShape<f64>>(), ray in any::<Ray<f64>>()) {
+        if let Some((t, normal)) = shape.cast_ray(&ray) {
+            prop_assert!(t >= 0.0);
+            if t > 0.0 {
+                prop_assert!(shape.signed_distance(&ray.point_at(t)).abs() <= EPSILON * 1000.0);
+                prop_assert!(normal.dot(&ray.direction) <= EPSILON);
+            }
+        } else {
+            // A missed shape stays apart from every sample along the ray.
+            for i in 0..=20 {
+                let t = i as f64 * 200.0;
+                prop_assert!(shape.signed_distance(&ray.point_at(t)) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn shape_cast_stops_at_contact(
+        a in any::<AnyShape<f64>>(),
+        b in any::<AnyShape<f64>>(),
+        velocity in any::<Vector<f64>>(),
+    ) {
+        if let Some((t, collision)) = a.cast(&velocity, &b) {
+            prop_assert!((0.0..=1.0).contains(&t));
+            let mut moved = a.clone();
+            moved.translate(&(velocity * t));
+            prop_assert!(moved.distance(&b) <= 1e-3, "{} apart at {}", moved.distance(&b), t);
+            prop_assert!(collision.depth >= 0.0);
+        } else if convex(&a) && convex(&b) {
+            // Casts are only exact for convex shapes, which can't be reached around.
+            for i in 0..=10 {
+                let mut moved = a.clone();
+                moved.translate(&(velocity * (i as f64 / 10.0)));
+                prop_assert!(!moved.intersecting(&b), "missed a hit at {}", i);
+            }
+        }
+    }
+}
+
+/// Checks that every crossing point found lies on the boundaries of both objects.
+macro_rules! intersections_on_boundaries {
+    ($name:ident, $a:ty, $b:ty) => {
+        proptest! {
+            #[test]
+            fn $name(a in any::<$a>(), b in any::<$b>()) {
+                for p in a.intersections(&b) {
+                    prop_assert!(a.signed_distance(&p).abs() <= 1e-6, "{:?} off {:?}", p, a);
+                    prop_assert!(b.signed_distance(&p).abs() <= 1e-6, "{:?} off {:?}", p, b);
+                }
+            }
+        }
+    };
+}
+
+intersections_on_boundaries!(circle_circle_intersections, Circle<f64>, Circle<f64>);
+intersections_on_boundaries!(line_circle_intersections, Line<f64>, Circle<f64>);
+intersections_on_boundaries!(line_aabr_intersections, Line<f64>, Aabr<f64>);
+intersections_on_boundaries!(line_obr_intersections, Line<f64>, Obr<f64>);
+intersections_on_boundaries!(aabr_aabr_intersections, Aabr<f64>, Aabr<f64>);
+intersections_on_boundaries!(obr_obr_intersections, Obr<f64>, Obr<f64>);
@@ -0,0 +1,200 @@
+use assert_approx_eq::assert_approx_eq;
+use prima::{
+    core::{CollisionFilter, Ray},
+    prelude::*,
+    world::QueryWorld,
+};
+
+const WALL: u32 = 1;
+const PICKUP: u32 = 1 << 1;
+
+/// A wall to the right of the origin, a pickup above it and a rounded crate further right.
+fn world() -> QueryWorld<f64, &'static str> {
+    let mut world = QueryWorld::new();
+    world.insert_filtered(
+        Aabr::new(Point::new(2.0, -1.0), Point::new(3.0, 1.0)),
+        CollisionFilter::new(WALL, u32::MAX),
+        "wall",
+    );
+    world.insert_filtered(
+        Circle::new(Point::new(0.0, 3.0), 1.0),
+        CollisionFilter::new(PICKUP, u32::MAX),
+        "pickup",
+    );
+    world.insert(
+        RoundedPolygon::new(
+            Polygon::new(vec![
+                Point::new(6.0, -1.0),
+                Point::new(8.0, -1.0),
+                Point::new(8.0, 1.0),
+                Point::new(6.0, 1.0),
+            ]),
+            0.5,
+        ),
+        "crate",
+    );
+    world
+}
+
+#[test]
+fn point_query_test() {
+    let world = world();
+    let hits = world.point_query(&Point::new(2.25, 0.0), &CollisionFilter::ALL);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(*hits[0].data, "wall");
+    // The point is nearest the left edge, so the wall would be pushed right to free it.
+    assert_approx_eq!(hits[0].collision.depth, 0.25);
+    assert_approx_eq!(hits[0].collision.normal.x, 1.0, 1e-6);
+
+    assert!(world
+        .point_query(
+            &Point::new(2.25, 0.0),
+            &CollisionFilter::new(u32::MAX, PICKUP)
+        )
+        .is_empty());
+    assert!(world
+        .point_query(&Point::new(4.0, 0.0), &CollisionFilter::ALL)
+        .is_empty());
+
+    // Queries have no data to offer a predicate, so only the bits decide.
+    let mut world = world;
+    world.insert_filtered(
+        Circle::new(Point::new(4.0, 0.0), 0.5),
+        CollisionFilter::ALL.with_predicate(|_, _| false),
+        "shy",
+    );
+    let hits = world.point_query(&Point::new(4.0, 0.0), &CollisionFilter::ALL);
+    assert_eq!(
+        hits.iter().map(|h| *h.data).collect::<Vec<_>>(),
+        vec!["shy"]
+    );
+}
+
+#[test]
+fn overlap_query_test() {
+    let world = world();
+    let probe: AnyShape<f64> = Circle::new(Point::new(1.0, 1.5), 1.2).into();
+    let hits = world.overlap_query(&probe, &CollisionFilter::ALL);
+    let names: Vec<&str> = hits.iter().map(|h| *h.data).collect();
+    assert_eq!(names, vec!["wall", "pickup"]);
+    assert!(hits.iter().all(|h| h.collision.depth > 0.0));
+
+    let walls = world.overlap_query(&probe, &CollisionFilter::new(u32::MAX, WALL));
+    assert_eq!(walls.len(), 1);
+}
+
+#[test]
+fn ray_cast_test() {
+    let world = world();
+    let ray = Ray::new(Point::new(0.0, 0.0), Vector::new(1.0, 0.0));
+
+    let hits = world.ray_cast_all(&ray, 100.0, &CollisionFilter::ALL);
+    let names: Vec<&str> = hits.iter().map(|h| *h.data).collect();
+    assert_eq!(names, vec!["wall", "crate"]);
+    assert_approx_eq!(hits[0].distance, 2.0);
+    assert_approx_eq!(hits[1].distance, 5.5);
+    assert_eq!(hits[0].collision.point, Point::new(2.0, 0.0));
+    assert_eq!(hits[0].collision.normal, Vector::new(1.0, 0.0));
+
+    let first = world.ray_cast_first(&ray, 100.0, &CollisionFilter::new(u32::MAX, !WALL));
+    assert_eq!(first.map(|h| *h.data), Some("crate"));
+    assert!(world
+        .ray_cast_first(&ray, 1.5, &CollisionFilter::ALL)
+        .is_none());
+
+    // The rounded corner of the crate is hit on its arc.
+    let corner = Ray::new(
+        Point::new(4.0, 1.0 + 0.5_f64.sqrt() * 0.5),
+        Vector::new(1.0, 0.0),
+    );
+    let hit = world
+        .ray_cast_first(&corner, 100.0, &CollisionFilter::ALL)
+        .unwrap();
+    assert_approx_eq!(hit.distance, 2.0 - 0.5_f64.sqrt() * 0.5);
+
+    // Rays starting inside a shape hit it straight away.
+    let inside = Ray::new(Point::new(0.0, 3.0), Vector::new(0.0, 1.0));
+    let hit = world
+        .ray_cast_first(&inside, 100.0, &CollisionFilter::ALL)
+        .unwrap();
+    assert_eq!(*hit.data, "pickup");
+    assert_eq!(hit.distance, 0.0);
+}
+
+#[test]
+fn shape_cast_test() {
+    let world = world();
+    let ball: AnyShape<f64> = Circle::new(Point::new(0.0, 0.0), 1.0).into();
+
+    let hit = world
+        .shape_cast(&ball, &Vector::new(10.0, 0.0), &CollisionFilter::ALL)
+        .unwrap();
+    assert_eq!(*hit.data, "wall");
+    assert_approx_eq!(hit.distance, 0.1, 1e-6);
+    assert_approx_eq!(hit.collision.point.x, 2.0, 1e-6);
+    assert_approx_eq!(hit.collision.normal.x, 1.0, 1e-6);
+
+    let hit = world
+        .shape_cast(
+            &ball,
+            &Vector::new(10.0, 0.0),
+            &CollisionFilter::new(u32::MAX, !WALL),
+        )
+        .unwrap();
+    assert_eq!(*hit.data, "crate");
+    assert_approx_eq!(hit.distance, 0.45, 1e-6);
+
+    assert!(world
+        .shape_cast(&ball, &Vector::new(-10.0, 0.0), &CollisionFilter::ALL)
+        .is_none());
+    assert!(world
+        .shape_cast(&ball, &Vector::new(0.5, 0.0), &CollisionFilter::ALL)
+        .is_none());
+}
+
+#[test]
+fn nearest_test() {
+    let world = world();
+    let hit = world
+        .nearest(&Point::new(4.0, 0.0), 10.0, &CollisionFilter::ALL)
+        .unwrap();
+    assert_eq!(*hit.data, "wall");
+    assert_approx_eq!(hit.distance, 1.0);
+    assert_eq!(hit.collision.point, Point::new(3.0, 0.0));
+    assert_approx_eq!(hit.collision.depth, -1.0);
+
+    let hit = world
+        .nearest(
+            &Point::new(4.0, 0.0),
+            10.0,
+            &CollisionFilter::new(u32::MAX, !WALL),
+        )
+        .unwrap();
+    assert_eq!(*hit.data, "crate");
+    assert_approx_eq!(hit.distance, 1.5);
+
+    assert!(world
+        .nearest(&Point::new(4.0, 0.0), 0.5, &CollisionFilter::ALL)
+        .is_none());
+}
+
+#[test]
+fn empty_shape_query_test() {
+    // An empty polygon covers nothing, so no query ever finds it.
+    let mut world = world();
+    world.insert(Polygon::new(vec![]), "empty");
+    let origin = Point::new(0.0, 0.0);
+    let probe: AnyShape<f64> = Circle::new(origin, 100.0).into();
+    let ray = Ray::new(origin, Vector::new(1.0, 0.0));
+
+    assert!(world.point_query(&origin, &CollisionFilter::ALL).is_empty());
+    assert_eq!(world.overlap_query(&probe, &CollisionFilter::ALL).len(), 3);
+    assert_eq!(
+        world.ray_cast_all(&ray, 100.0, &CollisionFilter::ALL).len(),
+        2
+    );
+    let hit = world
+        .nearest(&origin, f64::MAX, &CollisionFilter::ALL)
+        .unwrap();
+    assert_eq!(*hit.data, "wall");
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "rand_core")]
+
+use prima::{
+    core::{Angle, Extent, Point, Polyline},
+    sampling::{poisson_disk, random_unit, sample_rejection, SampleInside, SamplePerimeter},
+    shapes::{Aabr, Circle, Obr, Polygon},
+    traits::{Distance, Flat, Shape},
+};
+use rand_pcg::Pcg64;
+
+fn rng() -> Pcg64 {
+    Pcg64::new(0xcafe_f00d_d15e_a5e5, 0x0a02_bdbf_7bb3_c0a7_ac28_fa16_a64a_bf96)
+}
+
+#[test]
+fn random_unit_test() {
+    let mut rng = rng();
+    let samples: Vec<f32> = (0..1000).map(|_| random_unit(&mut rng)).collect();
+    assert!(samples.iter().all(|s| (0.0..1.0).contains(s)));
+    let mean = samples.iter().sum::<f32>() / 1000.0;
+    assert!((mean - 0.5).abs() < 0.05);
+}
+
+#[test]
+fn exact_sampling_test() {
+    let mut rng = rng();
+    let aabr = Aabr::new(Point::new(-1.0f64, 2.0), Point::new(3.0, 4.0));
+    let circle = Circle::new(Point::new(5.0f64, 5.0), 2.0);
+    let obr = Obr::new(Point::new(0.0f64, 0.0), Extent::new(4.0, 1.0), Angle::from_degrees(30.0));
+    let obr_polygon = Polygon::new(obr.vertices());
+
+    let mut inner = 0;
+    for _ in 0..2000 {
+        assert!(aabr.contains(&aabr.sample_inside(&mut rng)));
+        let p = circle.sample_inside(&mut rng);
+        assert!(circle.contains(&p));
+        // Half the area of a circle lies within 1/√2 of its radius.
+        if p.distance(&circle.center) < 2.0 / 2f64.sqrt() {
+            inner += 1;
+        }
+        assert!(obr_polygon.contains(&obr.sample_inside(&mut rng)));
+    }
+    assert!((inner as f64 / 2000.0 - 0.5).abs() < 0.05, "{} inner samples", inner);
+}
+
+#[test]
+fn rejection_sampling_test() {
+    let mut rng = rng();
+    let triangle = Polygon::new(vec![Point::new(0.0f32, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 4.0)]);
+    for _ in 0..100 {
+        let p = sample_rejection(&triangle, &mut rng, 100).unwrap();
+        assert!(triangle.contains(&p));
+    }
+    assert!(sample_rejection(&triangle, &mut rng, 0).is_none());
+}
+
+#[test]
+fn perimeter_sampling_test() {
+    let mut rng = rng();
+    let aabr = Aabr::new(Point::new(0.0f32, 0.0), Point::new(3.0, 1.0));
+    let mut long_sides = 0;
+    for _ in 0..1000 {
+        let p = aabr.sample_perimeter(&mut rng).unwrap();
+        let on_x = p.x == 0.0 || p.x == 3.0;
+        let on_y = p.y == 0.0 || p.y == 1.0;
+        assert!(on_x || on_y, "{:?} is not on the outline", p);
+        if on_y && !on_x {
+            long_sides += 1;
+        }
+    }
+    // The long sides make up three quarters of the outline.
+    assert!((long_sides as f32 / 1000.0 - 0.75).abs() < 0.05);
+
+    let circle = Circle::new(Point::new(1.0f32, 1.0), 2.0);
+    let p = circle.sample_perimeter(&mut rng).unwrap();
+    assert!((p.distance(&circle.center) - 2.0).abs() < 0.001);
+
+    let path = Polyline::new(vec![Point::new(0.0f32, 0.0), Point::new(0.0, 5.0)]);
+    let p = path.sample_perimeter(&mut rng).unwrap();
+    assert!(p.x == 0.0 && (0.0..=5.0).contains(&p.y));
+    assert!(Polyline::<f32>::new(vec![Point::zero()]).sample_perimeter(&mut rng).is_none());
+}
+
+#[test]
+fn poisson_disk_test() {
+    let mut rng = rng();
+    let area = Aabr::new(Point::new(0.0f64, 0.0), Point::new(20.0, 10.0));
+    let points = poisson_disk(&area, 1.0, &mut rng);
+    // A maximal packing at this spacing holds far more points than a sparse scatter would.
+    assert!(points.len() > 100, "only {} points", points.len());
+    for (i, a) in points.iter().enumerate() {
+        assert!(area.contains(a));
+        for b in points.iter().skip(i + 1) {
+            assert!(a.distance(b) >= 1.0);
+        }
+    }
+
+    let circle = Circle::new(Point::new(0.0f64, 0.0), 5.0);
+    let points = poisson_disk(&circle, 2.0, &mut rng);
+    assert!(points.len() > 5);
+    assert!(points.iter().all(|p| circle.contains(p)));
+    assert!(poisson_disk(&circle, 0.0, &mut rng).is_empty());
+}
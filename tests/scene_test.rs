@@ -0,0 +1,103 @@
+#![cfg(feature = "scene")]
+
+use prima::{
+    core::*,
+    formats::{Layer, Scene, SceneError, SceneItem, SceneShape, Transform},
+    shapes::*,
+};
+
+fn sample() -> Scene<f64> {
+    let mut walls = Layer::new("walls").with_tag("static");
+    walls.push(SceneItem::new(Aabr::new(Point::new(0.0, 0.0), Point::new(2.0, 1.0))).with_name("crate"));
+    walls.push(
+        SceneItem::new(Line::new(Point::new(0.0, 0.0), Point::new(0.0, 10.0)))
+            .with_transform(Transform::translation(Vector::new(5.0, 0.0))),
+    );
+    let mut props = Layer::new("props");
+    props.push(SceneItem::new(Circle::new(Point::new(1.0, 1.0), 0.5)).with_tag("pickup"));
+    props.push(SceneItem::new(Polyline::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)])));
+    props.push(SceneItem::new(Obr::new(
+        Point::new(3.0, 3.0),
+        Extent::new(2.0, 1.0),
+        Angle::from_degrees(45.0),
+    )));
+
+    let mut scene = Scene::new();
+    scene.add_layer(walls);
+    scene.add_layer(props);
+    scene
+}
+
+#[test]
+fn scene_round_trip_test() {
+    let scene = sample();
+    let loaded = Scene::<f64>::from_json(&scene.to_json()).unwrap();
+    assert_eq!(loaded.layers.len(), 2);
+    assert_eq!(loaded.layer("walls").unwrap().item("crate"), scene.layer("walls").unwrap().item("crate"));
+    assert_eq!(loaded.layer("props").unwrap().items.len(), 3);
+
+    let path = std::env::temp_dir().join("prima_scene_test.json");
+    scene.save(&path).unwrap();
+    let from_file = Scene::<f64>::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(from_file.to_json(), scene.to_json());
+}
+
+#[test]
+fn scene_query_test() {
+    let scene = sample();
+    // Both wall items inherit the layer's tag, plus the tagged circle.
+    assert_eq!(scene.tagged("static").count(), 2);
+    assert_eq!(scene.tagged("pickup").count(), 1);
+
+    let line = &scene.layer("walls").unwrap().items[1];
+    match line.world_shape() {
+        SceneShape::Line(l) => assert_eq!(l.start, Point::new(5.0, 0.0)),
+        _ => panic!("expected a line"),
+    }
+
+    // Rotating an aabr turns it into an obr.
+    let item = SceneItem::new(Aabr::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0)))
+        .with_transform(Transform::new(Vector::new(1.0, 0.0), Rotation::from_degrees(45.0), 2.0));
+    match item.world_shape() {
+        SceneShape::Obr(obr) => {
+            assert_eq!(obr.center, Point::new(1.0, 0.0));
+            assert_eq!(obr.extent, Extent::new(4.0, 4.0));
+        }
+        _ => panic!("expected an obr"),
+    }
+}
+
+#[test]
+fn scene_error_test() {
+    let json = r#"{
+  "version": 1,
+  "layers": [{
+    "name": "props",
+    "items": [
+      { "shape": { "type": "circle", "center": { "x": 0.0, "y": 0.0 }, "radius": -1.0 } }
+    ]
+  }]
+}"#;
+    match Scene::<f64>::from_json(json) {
+        Err(SceneError::Invalid { message, line, .. }) => {
+            assert_eq!(message, "circle radius is negative");
+            // The error is raised once the whole item has been read.
+            assert_eq!(line, 7);
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    match Scene::<f64>::from_json("{\n  \"version\": 1,\n  \"layers\": [,]\n}") {
+        Err(SceneError::Syntax { line, column, .. }) => assert_eq!((line, column), (3, 14)),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    let duplicate = r#"{ "version": 1, "layers": [{ "name": "a" }, { "name": "a" }] }"#;
+    assert!(matches!(
+        Scene::<f64>::from_json(duplicate),
+        Err(SceneError::Invalid { .. })
+    ));
+    let future = r#"{ "version": 99 }"#;
+    assert!(Scene::<f64>::from_json(future).unwrap_err().to_string().contains("newer"));
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "serde")]
+
+use prima::{
+    core::*,
+    curves::*,
+    nav::{NavMesh, Obstacle, Occluder, ViewCone},
+    shapes::*,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fmt::Debug;
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: Serialize + DeserializeOwned,
+{
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+fn assert_round_trip<T>(value: T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    assert_eq!(round_trip(&value), value);
+}
+
+#[test]
+fn core_round_trip_test() {
+    let a = Point::new(1.0f32, 2.0);
+    let b = Point::new(-3.5f32, 4.0);
+    assert_round_trip(a);
+    assert_round_trip(Vector::new(0.5f64, -0.25));
+    assert_round_trip(Extent::new(2.0f32, 3.0));
+    assert_round_trip(Line::new(a, b));
+    assert_round_trip(Ray::new(a, Vector::new(0.0, 1.0)));
+    assert_round_trip(AxisLine::new(-1.0f32, 1.0));
+    assert_round_trip(Collision::new(a, Vector::new(1.0, 0.0), 0.5));
+    assert_round_trip(Polyline::new(vec![a, b, Point::new(0.0, 0.0)]));
+    assert_round_trip(Angle::from_degrees(90.0f64));
+    assert_round_trip(Rotation::from_degrees(-450.0f64));
+
+    let m = AngleMat::new(1.0f32, 2.0, 3.0, 4.0);
+    let n = round_trip(&m);
+    assert_eq!((n.m00, n.m10, n.m01, n.m11), (1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn shape_round_trip_test() {
+    let square = Polygon::new(vec![
+        Point::new(0.0f64, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 1.0),
+    ]);
+    assert_round_trip(Aabr::new(Point::new(0.0f64, 0.0), Point::new(2.0, 1.0)));
+    assert_round_trip(Circle::new(Point::new(1.0f64, 1.0), 2.0));
+    assert_round_trip(Obr::new(
+        Point::new(1.0f64, 1.0),
+        Extent::new(2.0, 1.0),
+        Angle::from_degrees(45.0),
+    ));
+    assert_round_trip(Capsule::new(
+        Line::new(Point::new(0.0f64, 0.0), Point::new(3.0, 0.0)),
+        0.5,
+    ));
+    assert_round_trip(RoundedPolygon::new(square.clone(), 0.25));
+    assert_round_trip(square);
+}
+
+#[test]
+fn curve_round_trip_test() {
+    let points = vec![
+        Point::new(0.0f32, 0.0),
+        Point::new(1.0, 2.0),
+        Point::new(3.0, 2.0),
+        Point::new(4.0, 0.0),
+    ];
+    assert_round_trip(Bezier::new(points.clone()));
+    assert_round_trip(CatmullRom::new(points));
+}
+
+#[test]
+fn nav_round_trip_test() {
+    let boundary = Polygon::new(vec![
+        Point::new(0.0f64, 0.0),
+        Point::new(10.0, 0.0),
+        Point::new(10.0, 10.0),
+        Point::new(0.0, 10.0),
+    ]);
+    let obstacle: Obstacle<f64> = Circle::new(Point::new(5.0, 5.0), 1.0).into();
+    assert_round_trip(obstacle.clone());
+    assert_round_trip(Occluder::from(Line::new(Point::new(0.0f64, 0.0), Point::new(1.0, 1.0))));
+    assert_round_trip(ViewCone::new(Angle::from_degrees(0.0f64), Angle::from_degrees(90.0), 5.0));
+
+    let mesh = NavMesh::build(&boundary, &[obstacle], 0.0);
+    let copy = round_trip(&mesh);
+    assert_eq!(copy.vertices, mesh.vertices);
+    assert_eq!(copy.triangles, mesh.triangles);
+}
+
+#[test]
+fn representation_test() {
+    let json = serde_json::to_string(&Extent::new(2.0f32, 3.0)).unwrap();
+    assert_eq!(json, r#"{"width":2.0,"height":3.0}"#);
+    let json = serde_json::to_string(&Point::new(1.0f32, 2.0)).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":2.0}"#);
+
+    // Angles default to degrees, and are clamped when read back in.
+    let json = serde_json::to_string(&Angle::from_degrees(90.0f64)).unwrap();
+    assert_eq!(json, "90.0");
+    let angle: Angle<f64> = serde_json::from_str("450.0").unwrap();
+    assert!((angle.as_degrees() - 90.0).abs() < 1e-9);
+    let rotation: Rotation<f64> = serde_json::from_str("-450.0").unwrap();
+    assert!((rotation.as_degrees() + 450.0).abs() < 1e-9);
+}
+
+#[derive(Serialize, Deserialize)]
+struct Turret {
+    #[serde(with = "prima::serde_angle::radians")]
+    facing: Angle<f64>,
+    #[serde(with = "prima::serde_angle::half_turns")]
+    spin: Rotation<f64>,
+}
+
+#[test]
+fn angle_unit_test() {
+    let turret = Turret {
+        facing: Angle::from_degrees(180.0),
+        spin: Rotation::from_degrees(-90.0),
+    };
+    let json = serde_json::to_string(&turret).unwrap();
+    assert_eq!(json, format!(r#"{{"facing":{:?},"spin":-0.5}}"#, std::f64::consts::PI));
+
+    let back: Turret = serde_json::from_str(&json).unwrap();
+    assert!((back.facing.as_degrees() - 180.0).abs() < 1e-9);
+    assert!((back.spin.as_degrees() + 90.0).abs() < 1e-9);
+}
@@ -0,0 +1,173 @@
+#![cfg(feature = "tiled")]
+
+use prima::{
+    core::*,
+    formats::{TiledError, TiledMap, TiledShape, ELLIPSE_SIDES},
+    shapes::*,
+    traits::Flat,
+};
+
+const TMJ: &str = r#"{
+  "orientation": "orthogonal",
+  "width": 10, "height": 10, "tilewidth": 16, "tileheight": 16,
+  "layers": [
+    { "type": "tilelayer", "name": "ground", "data": [] },
+    { "type": "objectgroup", "name": "objects", "objects": [
+      { "id": 1, "name": "wall", "type": "solid", "x": 16, "y": 32, "width": 32, "height": 16,
+        "properties": [
+          { "name": "material", "type": "string", "value": "stone" },
+          { "name": "health", "type": "int", "value": 5 }
+        ] },
+      { "id": 2, "name": "door", "x": 0, "y": 0, "width": 20, "height": 10, "rotation": 30 },
+      { "id": 3, "name": "well", "x": 64, "y": 64, "width": 16, "height": 16, "ellipse": true },
+      { "id": 4, "name": "pond", "x": 64, "y": 96, "width": 32, "height": 16, "ellipse": true },
+      { "id": 5, "name": "spawn", "x": 80, "y": 80, "point": true, "visible": false },
+      { "id": 6, "name": "roof", "x": 100, "y": 100,
+        "polygon": [{ "x": 0, "y": 0 }, { "x": 10, "y": 0 }, { "x": 0, "y": 10 }] },
+      { "id": 7, "name": "road", "x": 0, "y": 80, "polyline": [{ "x": 0, "y": 0 }, { "x": 50, "y": 0 }] },
+      { "id": 8, "name": "label", "x": 0, "y": 0, "width": 10, "height": 10, "text": { "text": "hello" } }
+    ] },
+    { "type": "group", "name": "decor", "offsetx": 4, "offsety": 2, "layers": [
+      { "type": "objectgroup", "name": "tiles", "objects": [
+        { "id": 9, "name": "barrel", "gid": 1, "x": 0, "y": 160, "width": 16, "height": 16 }
+      ] }
+    ] }
+  ]
+}"#;
+
+const TMX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="orthogonal" width="10" height="10" tilewidth="16" tileheight="16">
+ <layer id="1" name="ground" width="10" height="10"/>
+ <objectgroup id="2" name="objects">
+  <object id="1" name="wall" type="solid" x="16" y="32" width="32" height="16">
+   <properties>
+    <property name="material" value="stone"/>
+    <property name="health" type="int" value="5"/>
+   </properties>
+  </object>
+  <object id="2" name="door" x="0" y="0" width="20" height="10" rotation="30"/>
+  <object id="3" name="well" x="64" y="64" width="16" height="16"><ellipse/></object>
+  <object id="4" name="pond" x="64" y="96" width="32" height="16"><ellipse/></object>
+  <object id="5" name="spawn" x="80" y="80" visible="0"><point/></object>
+  <object id="6" name="roof" x="100" y="100"><polygon points="0,0 10,0 0,10"/></object>
+  <object id="7" name="road" x="0" y="80"><polyline points="0,0 50,0"/></object>
+  <object id="8" name="label" x="0" y="0" width="10" height="10"><text>hello</text></object>
+ </objectgroup>
+ <group id="3" name="decor" offsetx="4" offsety="2">
+  <objectgroup id="4" name="tiles">
+   <object id="9" name="barrel" gid="1" x="0" y="160" width="16" height="16"/>
+  </objectgroup>
+ </group>
+</map>"#;
+
+fn close(a: Point<f64>, b: Point<f64>) -> bool {
+    (a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9
+}
+
+#[test]
+fn tiled_shapes_test() {
+    let map: TiledMap<f64> = TiledMap::from_tmj(TMJ).unwrap();
+    assert_eq!(map.pixel_size(), Extent::new(160.0, 160.0));
+    assert_eq!(map.layers.len(), 2);
+    let objects = map.layer("objects").unwrap();
+    // The text object has no shape.
+    assert_eq!(objects.objects.len(), 7);
+
+    let wall = objects.object("wall").unwrap();
+    assert_eq!(wall.class, "solid");
+    assert_eq!(wall.properties["material"], "stone");
+    assert_eq!(wall.properties["health"], "5");
+    assert_eq!(
+        wall.shape,
+        TiledShape::Aabr(Aabr::new(Point::new(16.0, 112.0), Point::new(48.0, 128.0)))
+    );
+
+    // The door hangs from its top-left corner, turned clockwise by 30 degrees.
+    match &objects.object("door").unwrap().shape {
+        TiledShape::Obr(obr) => {
+            assert!((obr.rotation.as_degrees() - 30.0).abs() < 1e-9);
+            let vertices = obr.vertices();
+            let (s, c) = 30f64.to_radians().sin_cos();
+            for corner in [
+                Point::new(0.0, 160.0),
+                Point::new(20.0 * c, 160.0 - 20.0 * s),
+                Point::new(-10.0 * s, 160.0 - 10.0 * c),
+            ] {
+                assert!(vertices.iter().any(|v| close(*v, corner)), "missing {:?}", corner);
+            }
+        }
+        other => panic!("expected an obr, got {:?}", other),
+    }
+
+    assert_eq!(
+        objects.object("well").unwrap().shape,
+        TiledShape::Circle(Circle::new(Point::new(72.0, 88.0), 8.0))
+    );
+    match &objects.object("pond").unwrap().shape {
+        TiledShape::Polygon(polygon) => {
+            assert_eq!(polygon.len(), ELLIPSE_SIDES);
+            assert!(!polygon.is_clockwise());
+            assert!(polygon.points.iter().any(|p| close(*p, Point::new(96.0, 56.0))));
+        }
+        other => panic!("expected a polygon, got {:?}", other),
+    }
+
+    let spawn = objects.object("spawn").unwrap();
+    assert!(!spawn.visible);
+    assert_eq!(spawn.shape, TiledShape::Point(Point::new(80.0, 80.0)));
+    assert_eq!(
+        objects.object("roof").unwrap().shape,
+        TiledShape::Polygon(Polygon::new(vec![
+            Point::new(100.0, 60.0),
+            Point::new(110.0, 60.0),
+            Point::new(100.0, 50.0),
+        ]))
+    );
+    assert_eq!(
+        objects.object("road").unwrap().shape,
+        TiledShape::Polyline(Polyline::new(vec![Point::new(0.0, 80.0), Point::new(50.0, 80.0)]))
+    );
+
+    // Tile objects sit on their origin, and pick up the offset of their group.
+    let barrel = map.layer("tiles").unwrap().object("barrel").unwrap();
+    assert_eq!(
+        barrel.shape,
+        TiledShape::Aabr(Aabr::new(Point::new(4.0, -2.0), Point::new(20.0, 14.0)))
+    );
+}
+
+#[test]
+fn tiled_formats_agree_test() {
+    let json: TiledMap<f64> = TiledMap::from_tmj(TMJ).unwrap();
+    let xml: TiledMap<f64> = TiledMap::from_tmx(TMX).unwrap();
+    assert_eq!(json, xml);
+
+    let path = std::env::temp_dir().join("prima_tiled_test.tmx");
+    std::fs::write(&path, TMX).unwrap();
+    let loaded: TiledMap<f32> = TiledMap::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.objects().count(), 8);
+}
+
+#[test]
+fn tiled_error_test() {
+    let bad = TMX.replace(r#"x="16""#, r#"x="sixteen""#);
+    match TiledMap::<f64>::from_tmx(&bad) {
+        Err(TiledError::Invalid(message)) => assert_eq!(message, "invalid value `sixteen` for `x` at 5:3"),
+        other => panic!("unexpected result {:?}", other),
+    }
+    let iso = TMJ.replace("orthogonal", "isometric");
+    assert!(matches!(TiledMap::<f64>::from_tmj(&iso), Err(TiledError::Invalid(_))));
+    assert!(matches!(TiledMap::<f64>::from_tmj("{"), Err(TiledError::Json(_))));
+}
+
+#[test]
+fn tiled_huge_map_test() {
+    // The size in pixels must not overflow, however large the map.
+    let huge = TMJ.replace(
+        r#""height": 10, "tilewidth": 16, "tileheight": 16"#,
+        r#""height": 4000000000, "tilewidth": 16, "tileheight": 4000000000"#,
+    );
+    let map = TiledMap::<f64>::from_tmj(&huge).unwrap();
+    assert_eq!(map.pixel_size().height(), 16e18);
+}